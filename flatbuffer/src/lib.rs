@@ -113,17 +113,18 @@ pub const ENUM_MIN_IMAGE_DATA: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_IMAGE_DATA: u8 = 3;
+pub const ENUM_MAX_IMAGE_DATA: u8 = 4;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_IMAGE_DATA: [ImageData; 4] = [
+pub const ENUM_VALUES_IMAGE_DATA: [ImageData; 5] = [
     ImageData::NONE,
     ImageData::ImageName,
     ImageData::RGBA,
     ImageData::Color,
+    ImageData::Gray,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -135,11 +136,17 @@ impl ImageData {
     pub const ImageName: Self = Self(1);
     pub const RGBA: Self = Self(2);
     pub const Color: Self = Self(3);
+    pub const Gray: Self = Self(4);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 3;
-    pub const ENUM_VALUES: &'static [Self] =
-        &[Self::NONE, Self::ImageName, Self::RGBA, Self::Color];
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::ImageName,
+        Self::RGBA,
+        Self::Color,
+        Self::Gray,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
@@ -147,6 +154,7 @@ impl ImageData {
             Self::ImageName => Some("ImageName"),
             Self::RGBA => Some("RGBA"),
             Self::Color => Some("Color"),
+            Self::Gray => Some("Gray"),
             _ => None,
         }
     }
@@ -205,6 +213,299 @@ impl<'a> flatbuffers::Verifiable for ImageData {
 impl flatbuffers::SimpleToVerifyInSlice for ImageData {}
 pub struct ImageDataUnionTableOffset {}
 
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_WRAP_MODE: i8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_WRAP_MODE: i8 = 2;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_WRAP_MODE: [WrapMode; 3] = [
+    WrapMode::Repeat,
+    WrapMode::MirroredRepeat,
+    WrapMode::ClampToEdge,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct WrapMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl WrapMode {
+    pub const Repeat: Self = Self(0);
+    pub const MirroredRepeat: Self = Self(1);
+    pub const ClampToEdge: Self = Self(2);
+
+    pub const ENUM_MIN: i8 = 0;
+    pub const ENUM_MAX: i8 = 2;
+    pub const ENUM_VALUES: &'static [Self] =
+        &[Self::Repeat, Self::MirroredRepeat, Self::ClampToEdge];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Repeat => Some("Repeat"),
+            Self::MirroredRepeat => Some("MirroredRepeat"),
+            Self::ClampToEdge => Some("ClampToEdge"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for WrapMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for WrapMode {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for WrapMode {
+    type Output = WrapMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for WrapMode {
+    type Scalar = i8;
+    #[inline]
+    fn to_little_endian(self) -> i8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: i8) -> Self {
+        let b = i8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for WrapMode {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        i8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for WrapMode {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_TEXTURE_FILTER: i8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_TEXTURE_FILTER: i8 = 2;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TEXTURE_FILTER: [TextureFilter; 3] = [
+    TextureFilter::Unset,
+    TextureFilter::Nearest,
+    TextureFilter::Linear,
+];
+
+/// Unset when the file leaves the filter up to the renderer
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TextureFilter(pub i8);
+#[allow(non_upper_case_globals)]
+impl TextureFilter {
+    pub const Unset: Self = Self(0);
+    pub const Nearest: Self = Self(1);
+    pub const Linear: Self = Self(2);
+
+    pub const ENUM_MIN: i8 = 0;
+    pub const ENUM_MAX: i8 = 2;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Unset, Self::Nearest, Self::Linear];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Unset => Some("Unset"),
+            Self::Nearest => Some("Nearest"),
+            Self::Linear => Some("Linear"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for TextureFilter {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for TextureFilter {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for TextureFilter {
+    type Output = TextureFilter;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for TextureFilter {
+    type Scalar = i8;
+    #[inline]
+    fn to_little_endian(self) -> i8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: i8) -> Self {
+        let b = i8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for TextureFilter {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        i8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TextureFilter {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_IMAGE_ENCODING: i8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_IMAGE_ENCODING: i8 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_IMAGE_ENCODING: [ImageEncoding; 4] = [
+    ImageEncoding::Raw,
+    ImageEncoding::Png,
+    ImageEncoding::Qoi,
+    ImageEncoding::Zstd,
+];
+
+/// How the bytes of RGBA/Gray image data are stored
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ImageEncoding(pub i8);
+#[allow(non_upper_case_globals)]
+impl ImageEncoding {
+    /// The texels as they are
+    pub const Raw: Self = Self(0);
+    pub const Png: Self = Self(1);
+    pub const Qoi: Self = Self(2);
+    /// The raw texels compressed with zstd
+    pub const Zstd: Self = Self(3);
+
+    pub const ENUM_MIN: i8 = 0;
+    pub const ENUM_MAX: i8 = 3;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Raw, Self::Png, Self::Qoi, Self::Zstd];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Raw => Some("Raw"),
+            Self::Png => Some("Png"),
+            Self::Qoi => Some("Qoi"),
+            Self::Zstd => Some("Zstd"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for ImageEncoding {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for ImageEncoding {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for ImageEncoding {
+    type Output = ImageEncoding;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for ImageEncoding {
+    type Scalar = i8;
+    #[inline]
+    fn to_little_endian(self) -> i8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: i8) -> Self {
+        let b = i8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ImageEncoding {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        i8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ImageEncoding {}
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
@@ -397,42 +698,46 @@ impl flatbuffers::SimpleToVerifyInSlice for AnimationTargetTypes {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MIN_OBJECT: u8 = 0;
+pub const ENUM_MIN_OUTLINE_WIDTH_MODE: i8 = 0;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OBJECT: u8 = 2;
+pub const ENUM_MAX_OUTLINE_WIDTH_MODE: i8 = 2;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OBJECT: [Object; 3] = [Object::NONE, Object::Mesh, Object::Empties];
+pub const ENUM_VALUES_OUTLINE_WIDTH_MODE: [OutlineWidthMode; 3] = [
+    OutlineWidthMode::None,
+    OutlineWidthMode::World,
+    OutlineWidthMode::Screen,
+];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(transparent)]
-pub struct Object(pub u8);
+pub struct OutlineWidthMode(pub i8);
 #[allow(non_upper_case_globals)]
-impl Object {
-    pub const NONE: Self = Self(0);
-    pub const Mesh: Self = Self(1);
-    pub const Empties: Self = Self(2);
+impl OutlineWidthMode {
+    pub const None: Self = Self(0);
+    pub const World: Self = Self(1);
+    pub const Screen: Self = Self(2);
 
-    pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 2;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::Mesh, Self::Empties];
+    pub const ENUM_MIN: i8 = 0;
+    pub const ENUM_MAX: i8 = 2;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::None, Self::World, Self::Screen];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
-            Self::NONE => Some("NONE"),
-            Self::Mesh => Some("Mesh"),
-            Self::Empties => Some("Empties"),
+            Self::None => Some("None"),
+            Self::World => Some("World"),
+            Self::Screen => Some("Screen"),
             _ => None,
         }
     }
 }
-impl core::fmt::Debug for Object {
+impl core::fmt::Debug for OutlineWidthMode {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         if let Some(name) = self.variant_name() {
             f.write_str(name)
@@ -441,32 +746,123 @@ impl core::fmt::Debug for Object {
         }
     }
 }
-impl<'a> flatbuffers::Follow<'a> for Object {
+impl<'a> flatbuffers::Follow<'a> for OutlineWidthMode {
     type Inner = Self;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
         Self(b)
     }
 }
 
-impl flatbuffers::Push for Object {
-    type Output = Object;
+impl flatbuffers::Push for OutlineWidthMode {
+    type Output = OutlineWidthMode;
     #[inline]
     unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
-        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
     }
 }
 
-impl flatbuffers::EndianScalar for Object {
-    type Scalar = u8;
+impl flatbuffers::EndianScalar for OutlineWidthMode {
+    type Scalar = i8;
     #[inline]
-    fn to_little_endian(self) -> u8 {
+    fn to_little_endian(self) -> i8 {
         self.0.to_le()
     }
     #[inline]
     #[allow(clippy::wrong_self_convention)]
-    fn from_little_endian(v: u8) -> Self {
+    fn from_little_endian(v: i8) -> Self {
+        let b = i8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for OutlineWidthMode {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        i8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for OutlineWidthMode {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_OBJECT: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_OBJECT: u8 = 2;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_OBJECT: [Object; 3] = [Object::NONE, Object::Mesh, Object::Empties];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Object(pub u8);
+#[allow(non_upper_case_globals)]
+impl Object {
+    pub const NONE: Self = Self(0);
+    pub const Mesh: Self = Self(1);
+    pub const Empties: Self = Self(2);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 2;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::NONE, Self::Mesh, Self::Empties];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::Mesh => Some("Mesh"),
+            Self::Empties => Some("Empties"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for Object {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for Object {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for Object {
+    type Output = Object;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for Object {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
         let b = u8::from_le(v);
         Self(b)
     }
@@ -943,6 +1339,211 @@ impl<'a> Transform {
     }
 }
 
+// struct BoundingBox, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct BoundingBox(pub [u8; 24]);
+impl Default for BoundingBox {
+    fn default() -> Self {
+        Self([0; 24])
+    }
+}
+impl core::fmt::Debug for BoundingBox {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("BoundingBox")
+            .field("min", &self.min())
+            .field("max", &self.max())
+            .finish()
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for BoundingBox {}
+impl<'a> flatbuffers::Follow<'a> for BoundingBox {
+    type Inner = &'a BoundingBox;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        <&'a BoundingBox>::follow(buf, loc)
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a BoundingBox {
+    type Inner = &'a BoundingBox;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        flatbuffers::follow_cast_ref::<BoundingBox>(buf, loc)
+    }
+}
+impl<'b> flatbuffers::Push for BoundingBox {
+    type Output = BoundingBox;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(
+            self as *const BoundingBox as *const u8,
+            <Self as flatbuffers::Push>::size(),
+        );
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for BoundingBox {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.in_buffer::<Self>(pos)
+    }
+}
+
+impl<'a> BoundingBox {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(min: &Vector3, max: &Vector3) -> Self {
+        let mut s = Self([0; 24]);
+        s.set_min(min);
+        s.set_max(max);
+        s
+    }
+
+    pub fn min(&self) -> &Vector3 {
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid struct in this slot
+        unsafe { &*(self.0[0..].as_ptr() as *const Vector3) }
+    }
+
+    #[allow(clippy::identity_op)]
+    pub fn set_min(&mut self, x: &Vector3) {
+        self.0[0..0 + 12].copy_from_slice(&x.0)
+    }
+
+    pub fn max(&self) -> &Vector3 {
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid struct in this slot
+        unsafe { &*(self.0[12..].as_ptr() as *const Vector3) }
+    }
+
+    #[allow(clippy::identity_op)]
+    pub fn set_max(&mut self, x: &Vector3) {
+        self.0[12..12 + 12].copy_from_slice(&x.0)
+    }
+}
+
+// struct BoundingSphere, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct BoundingSphere(pub [u8; 16]);
+impl Default for BoundingSphere {
+    fn default() -> Self {
+        Self([0; 16])
+    }
+}
+impl core::fmt::Debug for BoundingSphere {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("BoundingSphere")
+            .field("center", &self.center())
+            .field("radius", &self.radius())
+            .finish()
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for BoundingSphere {}
+impl<'a> flatbuffers::Follow<'a> for BoundingSphere {
+    type Inner = &'a BoundingSphere;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        <&'a BoundingSphere>::follow(buf, loc)
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a BoundingSphere {
+    type Inner = &'a BoundingSphere;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        flatbuffers::follow_cast_ref::<BoundingSphere>(buf, loc)
+    }
+}
+impl<'b> flatbuffers::Push for BoundingSphere {
+    type Output = BoundingSphere;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(
+            self as *const BoundingSphere as *const u8,
+            <Self as flatbuffers::Push>::size(),
+        );
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for BoundingSphere {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.in_buffer::<Self>(pos)
+    }
+}
+
+impl<'a> BoundingSphere {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(center: &Vector3, radius: f32) -> Self {
+        let mut s = Self([0; 16]);
+        s.set_center(center);
+        s.set_radius(radius);
+        s
+    }
+
+    pub fn center(&self) -> &Vector3 {
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid struct in this slot
+        unsafe { &*(self.0[0..].as_ptr() as *const Vector3) }
+    }
+
+    #[allow(clippy::identity_op)]
+    pub fn set_center(&mut self, x: &Vector3) {
+        self.0[0..0 + 12].copy_from_slice(&x.0)
+    }
+
+    pub fn radius(&self) -> f32 {
+        let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        EndianScalar::from_little_endian(unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.0[12..].as_ptr(),
+                mem.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+            );
+            mem.assume_init()
+        })
+    }
+
+    pub fn set_radius(&mut self, x: f32) {
+        let x_le = x.to_little_endian();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                &x_le as *const _ as *const u8,
+                self.0[12..].as_mut_ptr(),
+                core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+            );
+        }
+    }
+}
+
 // struct RGBAColor, aligned to 4
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
@@ -1233,39 +1834,267 @@ impl<'a> GlobalNodeIdentifier {
     }
 }
 
-// struct InterpolationTargets, aligned to 1
+// struct Sampler, aligned to 1
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq)]
-pub struct InterpolationTargets(pub [u8; 4]);
-impl Default for InterpolationTargets {
+pub struct Sampler(pub [u8; 5]);
+impl Default for Sampler {
     fn default() -> Self {
-        Self([0; 4])
+        Self([0; 5])
     }
 }
-impl core::fmt::Debug for InterpolationTargets {
+impl core::fmt::Debug for Sampler {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        f.debug_struct("InterpolationTargets")
-            .field("translation", &self.translation())
-            .field("rotation", &self.rotation())
-            .field("scale", &self.scale())
-            .field("weights", &self.weights())
+        f.debug_struct("Sampler")
+            .field("wrap_s", &self.wrap_s())
+            .field("wrap_t", &self.wrap_t())
+            .field("mag_filter", &self.mag_filter())
+            .field("min_filter", &self.min_filter())
+            .field("mipmap_filter", &self.mipmap_filter())
             .finish()
     }
 }
 
-impl flatbuffers::SimpleToVerifyInSlice for InterpolationTargets {}
-impl<'a> flatbuffers::Follow<'a> for InterpolationTargets {
-    type Inner = &'a InterpolationTargets;
+impl flatbuffers::SimpleToVerifyInSlice for Sampler {}
+impl<'a> flatbuffers::Follow<'a> for Sampler {
+    type Inner = &'a Sampler;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        <&'a InterpolationTargets>::follow(buf, loc)
+        <&'a Sampler>::follow(buf, loc)
     }
 }
-impl<'a> flatbuffers::Follow<'a> for &'a InterpolationTargets {
-    type Inner = &'a InterpolationTargets;
+impl<'a> flatbuffers::Follow<'a> for &'a Sampler {
+    type Inner = &'a Sampler;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        flatbuffers::follow_cast_ref::<InterpolationTargets>(buf, loc)
+        flatbuffers::follow_cast_ref::<Sampler>(buf, loc)
+    }
+}
+impl<'b> flatbuffers::Push for Sampler {
+    type Output = Sampler;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(
+            self as *const Sampler as *const u8,
+            <Self as flatbuffers::Push>::size(),
+        );
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(1)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Sampler {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.in_buffer::<Self>(pos)
+    }
+}
+
+impl<'a> Sampler {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        wrap_s: WrapMode,
+        wrap_t: WrapMode,
+        mag_filter: TextureFilter,
+        min_filter: TextureFilter,
+        mipmap_filter: TextureFilter,
+    ) -> Self {
+        let mut s = Self([0; 5]);
+        s.set_wrap_s(wrap_s);
+        s.set_wrap_t(wrap_t);
+        s.set_mag_filter(mag_filter);
+        s.set_min_filter(min_filter);
+        s.set_mipmap_filter(mipmap_filter);
+        s
+    }
+
+    pub fn wrap_s(&self) -> WrapMode {
+        let mut mem = core::mem::MaybeUninit::<<WrapMode as EndianScalar>::Scalar>::uninit();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        EndianScalar::from_little_endian(unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.0[0..].as_ptr(),
+                mem.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<<WrapMode as EndianScalar>::Scalar>(),
+            );
+            mem.assume_init()
+        })
+    }
+
+    pub fn set_wrap_s(&mut self, x: WrapMode) {
+        let x_le = x.to_little_endian();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                &x_le as *const _ as *const u8,
+                self.0[0..].as_mut_ptr(),
+                core::mem::size_of::<<WrapMode as EndianScalar>::Scalar>(),
+            );
+        }
+    }
+
+    pub fn wrap_t(&self) -> WrapMode {
+        let mut mem = core::mem::MaybeUninit::<<WrapMode as EndianScalar>::Scalar>::uninit();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        EndianScalar::from_little_endian(unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.0[1..].as_ptr(),
+                mem.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<<WrapMode as EndianScalar>::Scalar>(),
+            );
+            mem.assume_init()
+        })
+    }
+
+    pub fn set_wrap_t(&mut self, x: WrapMode) {
+        let x_le = x.to_little_endian();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                &x_le as *const _ as *const u8,
+                self.0[1..].as_mut_ptr(),
+                core::mem::size_of::<<WrapMode as EndianScalar>::Scalar>(),
+            );
+        }
+    }
+
+    pub fn mag_filter(&self) -> TextureFilter {
+        let mut mem = core::mem::MaybeUninit::<<TextureFilter as EndianScalar>::Scalar>::uninit();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        EndianScalar::from_little_endian(unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.0[2..].as_ptr(),
+                mem.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<<TextureFilter as EndianScalar>::Scalar>(),
+            );
+            mem.assume_init()
+        })
+    }
+
+    pub fn set_mag_filter(&mut self, x: TextureFilter) {
+        let x_le = x.to_little_endian();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                &x_le as *const _ as *const u8,
+                self.0[2..].as_mut_ptr(),
+                core::mem::size_of::<<TextureFilter as EndianScalar>::Scalar>(),
+            );
+        }
+    }
+
+    pub fn min_filter(&self) -> TextureFilter {
+        let mut mem = core::mem::MaybeUninit::<<TextureFilter as EndianScalar>::Scalar>::uninit();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        EndianScalar::from_little_endian(unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.0[3..].as_ptr(),
+                mem.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<<TextureFilter as EndianScalar>::Scalar>(),
+            );
+            mem.assume_init()
+        })
+    }
+
+    pub fn set_min_filter(&mut self, x: TextureFilter) {
+        let x_le = x.to_little_endian();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                &x_le as *const _ as *const u8,
+                self.0[3..].as_mut_ptr(),
+                core::mem::size_of::<<TextureFilter as EndianScalar>::Scalar>(),
+            );
+        }
+    }
+
+    /// Filter between mip levels, Unset when mipmaps shouldn't be used
+    pub fn mipmap_filter(&self) -> TextureFilter {
+        let mut mem = core::mem::MaybeUninit::<<TextureFilter as EndianScalar>::Scalar>::uninit();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        EndianScalar::from_little_endian(unsafe {
+            core::ptr::copy_nonoverlapping(
+                self.0[4..].as_ptr(),
+                mem.as_mut_ptr() as *mut u8,
+                core::mem::size_of::<<TextureFilter as EndianScalar>::Scalar>(),
+            );
+            mem.assume_init()
+        })
+    }
+
+    pub fn set_mipmap_filter(&mut self, x: TextureFilter) {
+        let x_le = x.to_little_endian();
+        // Safety:
+        // Created from a valid Table for this object
+        // Which contains a valid value in this slot
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                &x_le as *const _ as *const u8,
+                self.0[4..].as_mut_ptr(),
+                core::mem::size_of::<<TextureFilter as EndianScalar>::Scalar>(),
+            );
+        }
+    }
+}
+
+// struct InterpolationTargets, aligned to 1
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct InterpolationTargets(pub [u8; 4]);
+impl Default for InterpolationTargets {
+    fn default() -> Self {
+        Self([0; 4])
+    }
+}
+impl core::fmt::Debug for InterpolationTargets {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("InterpolationTargets")
+            .field("translation", &self.translation())
+            .field("rotation", &self.rotation())
+            .field("scale", &self.scale())
+            .field("weights", &self.weights())
+            .finish()
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for InterpolationTargets {}
+impl<'a> flatbuffers::Follow<'a> for InterpolationTargets {
+    type Inner = &'a InterpolationTargets;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        <&'a InterpolationTargets>::follow(buf, loc)
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a InterpolationTargets {
+    type Inner = &'a InterpolationTargets;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        flatbuffers::follow_cast_ref::<InterpolationTargets>(buf, loc)
     }
 }
 impl<'b> flatbuffers::Push for InterpolationTargets {
@@ -2022,15 +2851,15 @@ impl core::fmt::Debug for RGBAColorData<'_> {
         ds.finish()
     }
 }
-pub enum ImageOffset {}
+pub enum GrayImageDataOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Image<'a> {
+pub struct GrayImageData<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Image<'a> {
-    type Inner = Image<'a>;
+impl<'a> flatbuffers::Follow<'a> for GrayImageData<'a> {
+    type Inner = GrayImageData<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2039,127 +2868,172 @@ impl<'a> flatbuffers::Follow<'a> for Image<'a> {
     }
 }
 
-impl<'a> Image<'a> {
-    pub const VT_NAME: flatbuffers::VOffsetT = 4;
-    pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_DATA: flatbuffers::VOffsetT = 8;
-    pub const VT_WIDTH: flatbuffers::VOffsetT = 10;
-    pub const VT_HEIGHT: flatbuffers::VOffsetT = 12;
+impl<'a> GrayImageData<'a> {
+    pub const VT_DATA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Image { _tab: table }
+        GrayImageData { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ImageArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Image<'bldr>> {
-        let mut builder = ImageBuilder::new(_fbb);
-        builder.add_height(args.height);
-        builder.add_width(args.width);
+        args: &'args GrayImageDataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<GrayImageData<'bldr>> {
+        let mut builder = GrayImageDataBuilder::new(_fbb);
         if let Some(x) = args.data {
             builder.add_data(x);
         }
-        if let Some(x) = args.name {
-            builder.add_name(x);
-        }
-        builder.add_data_type(args.data_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn name(&self) -> Option<&'a str> {
+    pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(Image::VT_NAME, None)
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                    GrayImageData::VT_DATA,
+                    None,
+                )
         }
     }
+}
+
+impl flatbuffers::Verifiable for GrayImageData<'_> {
     #[inline]
-    pub fn data_type(&self) -> ImageData {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<ImageData>(Image::VT_DATA_TYPE, Some(ImageData::NONE))
-                .unwrap()
-        }
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
+                "data",
+                Self::VT_DATA,
+                false,
+            )?
+            .finish();
+        Ok(())
     }
+}
+pub struct GrayImageDataArgs<'a> {
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for GrayImageDataArgs<'a> {
     #[inline]
-    pub fn data(&self) -> Option<flatbuffers::Table<'a>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Image::VT_DATA, None)
-        }
+    fn default() -> Self {
+        GrayImageDataArgs { data: None }
     }
+}
+
+pub struct GrayImageDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GrayImageDataBuilder<'a, 'b, A> {
     #[inline]
-    pub fn width(&self) -> u32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<u32>(Image::VT_WIDTH, Some(0)).unwrap() }
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(GrayImageData::VT_DATA, data);
     }
     #[inline]
-    pub fn height(&self) -> u32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<u32>(Image::VT_HEIGHT, Some(0)).unwrap() }
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> GrayImageDataBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        GrayImageDataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
     }
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_image_name(&self) -> Option<ImageName<'a>> {
-        if self.data_type() == ImageData::ImageName {
-            self.data().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { ImageName::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn finish(self) -> flatbuffers::WIPOffset<GrayImageData<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for GrayImageData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("GrayImageData");
+        ds.field("data", &self.data());
+        ds.finish()
+    }
+}
+pub enum MipLevelOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// A smaller version of an image, stored with the same layout and encoding as the data of the image
+pub struct MipLevel<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MipLevel<'a> {
+    type Inner = MipLevel<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
         }
     }
+}
+
+impl<'a> MipLevel<'a> {
+    pub const VT_WIDTH: flatbuffers::VOffsetT = 4;
+    pub const VT_HEIGHT: flatbuffers::VOffsetT = 6;
+    pub const VT_DATA: flatbuffers::VOffsetT = 8;
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_rgba(&self) -> Option<RGBAImageData<'a>> {
-        if self.data_type() == ImageData::RGBA {
-            self.data().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { RGBAImageData::init_from_table(t) }
-            })
-        } else {
-            None
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MipLevel { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args MipLevelArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MipLevel<'bldr>> {
+        let mut builder = MipLevelBuilder::new(_fbb);
+        if let Some(x) = args.data {
+            builder.add_data(x);
         }
+        builder.add_height(args.height);
+        builder.add_width(args.width);
+        builder.finish()
     }
 
     #[inline]
-    #[allow(non_snake_case)]
-    pub fn data_as_color(&self) -> Option<RGBAColorData<'a>> {
-        if self.data_type() == ImageData::Color {
-            self.data().map(|t| {
-                // Safety:
-                // Created from a valid Table for this object
-                // Which contains a valid union in this slot
-                unsafe { RGBAColorData::init_from_table(t) }
-            })
-        } else {
-            None
+    pub fn width(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(MipLevel::VT_WIDTH, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn height(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(MipLevel::VT_HEIGHT, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn data(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                    MipLevel::VT_DATA,
+                    None,
+                )
         }
     }
 }
 
-impl flatbuffers::Verifiable for Image<'_> {
+impl flatbuffers::Verifiable for MipLevel<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2167,156 +3041,84 @@ impl flatbuffers::Verifiable for Image<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-            .visit_union::<ImageData, _>(
-                "data_type",
-                Self::VT_DATA_TYPE,
+            .visit_field::<u32>("width", Self::VT_WIDTH, false)?
+            .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>(
                 "data",
                 Self::VT_DATA,
                 false,
-                |key, v, pos| match key {
-                    ImageData::ImageName => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<ImageName>>(
-                            "ImageData::ImageName",
-                            pos,
-                        ),
-                    ImageData::RGBA => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<RGBAImageData>>(
-                            "ImageData::RGBA",
-                            pos,
-                        ),
-                    ImageData::Color => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<RGBAColorData>>(
-                            "ImageData::Color",
-                            pos,
-                        ),
-                    _ => Ok(()),
-                },
             )?
-            .visit_field::<u32>("width", Self::VT_WIDTH, false)?
-            .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ImageArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub data_type: ImageData,
-    pub data: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct MipLevelArgs<'a> {
     pub width: u32,
     pub height: u32,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
 }
-impl<'a> Default for ImageArgs<'a> {
+impl<'a> Default for MipLevelArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ImageArgs {
-            name: None,
-            data_type: ImageData::NONE,
-            data: None,
+        MipLevelArgs {
             width: 0,
             height: 0,
+            data: None,
         }
     }
 }
 
-pub struct ImageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MipLevelBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ImageBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MipLevelBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Image::VT_NAME, name);
+    pub fn add_width(&mut self, width: u32) {
+        self.fbb_.push_slot::<u32>(MipLevel::VT_WIDTH, width, 0);
     }
     #[inline]
-    pub fn add_data_type(&mut self, data_type: ImageData) {
-        self.fbb_
-            .push_slot::<ImageData>(Image::VT_DATA_TYPE, data_type, ImageData::NONE);
+    pub fn add_height(&mut self, height: u32) {
+        self.fbb_.push_slot::<u32>(MipLevel::VT_HEIGHT, height, 0);
     }
     #[inline]
-    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Image::VT_DATA, data);
-    }
-    #[inline]
-    pub fn add_width(&mut self, width: u32) {
-        self.fbb_.push_slot::<u32>(Image::VT_WIDTH, width, 0);
-    }
-    #[inline]
-    pub fn add_height(&mut self, height: u32) {
-        self.fbb_.push_slot::<u32>(Image::VT_HEIGHT, height, 0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MipLevel::VT_DATA, data);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ImageBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MipLevelBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ImageBuilder {
+        MipLevelBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Image<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MipLevel<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Image<'_> {
+impl core::fmt::Debug for MipLevel<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Image");
-        ds.field("name", &self.name());
-        ds.field("data_type", &self.data_type());
-        match self.data_type() {
-            ImageData::ImageName => {
-                if let Some(x) = self.data_as_image_name() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            ImageData::RGBA => {
-                if let Some(x) = self.data_as_rgba() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            ImageData::Color => {
-                if let Some(x) = self.data_as_color() {
-                    ds.field("data", &x)
-                } else {
-                    ds.field(
-                        "data",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("data", &x)
-            }
-        };
+        let mut ds = f.debug_struct("MipLevel");
         ds.field("width", &self.width());
         ds.field("height", &self.height());
+        ds.field("data", &self.data());
         ds.finish()
     }
 }
-pub enum AnimationValuesOffset {}
+pub enum ImageOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AnimationValues<'a> {
+pub struct Image<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AnimationValues<'a> {
-    type Inner = AnimationValues<'a>;
+impl<'a> flatbuffers::Follow<'a> for Image<'a> {
+    type Inner = Image<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2325,77 +3127,215 @@ impl<'a> flatbuffers::Follow<'a> for AnimationValues<'a> {
     }
 }
 
-impl<'a> AnimationValues<'a> {
-    pub const VT_TRANSLATION: flatbuffers::VOffsetT = 4;
-    pub const VT_ROTATION: flatbuffers::VOffsetT = 6;
-    pub const VT_SCALE: flatbuffers::VOffsetT = 8;
-    pub const VT_WEIGHTS: flatbuffers::VOffsetT = 10;
+impl<'a> Image<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_DATA_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_DATA: flatbuffers::VOffsetT = 8;
+    pub const VT_WIDTH: flatbuffers::VOffsetT = 10;
+    pub const VT_HEIGHT: flatbuffers::VOffsetT = 12;
+    pub const VT_FACTOR_APPLIED: flatbuffers::VOffsetT = 14;
+    pub const VT_PREMULTIPLIED: flatbuffers::VOffsetT = 16;
+    pub const VT_SAMPLER: flatbuffers::VOffsetT = 18;
+    pub const VT_ENCODING: flatbuffers::VOffsetT = 20;
+    pub const VT_MIP_LEVELS: flatbuffers::VOffsetT = 22;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        AnimationValues { _tab: table }
+        Image { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args AnimationValuesArgs<'args>,
-    ) -> flatbuffers::WIPOffset<AnimationValues<'bldr>> {
-        let mut builder = AnimationValuesBuilder::new(_fbb);
-        if let Some(x) = args.weights {
-            builder.add_weights(x);
+        args: &'args ImageArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Image<'bldr>> {
+        let mut builder = ImageBuilder::new(_fbb);
+        if let Some(x) = args.mip_levels {
+            builder.add_mip_levels(x);
         }
-        if let Some(x) = args.scale {
-            builder.add_scale(x);
+        if let Some(x) = args.sampler {
+            builder.add_sampler(x);
         }
-        if let Some(x) = args.rotation {
-            builder.add_rotation(x);
+        builder.add_height(args.height);
+        builder.add_width(args.width);
+        if let Some(x) = args.data {
+            builder.add_data(x);
         }
-        if let Some(x) = args.translation {
-            builder.add_translation(x);
+        if let Some(x) = args.name {
+            builder.add_name(x);
         }
+        builder.add_encoding(args.encoding);
+        builder.add_premultiplied(args.premultiplied);
+        builder.add_factor_applied(args.factor_applied);
+        builder.add_data_type(args.data_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn translation(&self) -> Option<&'a Vector3> {
+    pub fn name(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<Vector3>(AnimationValues::VT_TRANSLATION, None)
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Image::VT_NAME, None)
         }
     }
     #[inline]
-    pub fn rotation(&self) -> Option<&'a Vector4> {
+    pub fn data_type(&self) -> ImageData {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<Vector4>(AnimationValues::VT_ROTATION, None) }
-    }
-    #[inline]
-    pub fn scale(&self) -> Option<&'a Vector3> {
+        unsafe {
+            self._tab
+                .get::<ImageData>(Image::VT_DATA_TYPE, Some(ImageData::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn data(&self) -> Option<flatbuffers::Table<'a>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<Vector3>(AnimationValues::VT_SCALE, None) }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Image::VT_DATA, None)
+        }
     }
     #[inline]
-    pub fn weights(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    pub fn width(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(Image::VT_WIDTH, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn height(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(Image::VT_HEIGHT, Some(0)).unwrap() }
+    }
+    /// The base color factor of the material is already multiplied into the data
+    #[inline]
+    pub fn factor_applied(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                    AnimationValues::VT_WEIGHTS,
-                    None,
-                )
+                .get::<bool>(Image::VT_FACTOR_APPLIED, Some(false))
+                .unwrap()
+        }
+    }
+    /// The color of the data is multiplied by its alpha
+    #[inline]
+    pub fn premultiplied(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(Image::VT_PREMULTIPLIED, Some(false))
+                .unwrap()
+        }
+    }
+    /// How the texture is sampled, missing for colors
+    #[inline]
+    pub fn sampler(&self) -> Option<&'a Sampler> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Sampler>(Image::VT_SAMPLER, None) }
+    }
+    /// Encoding of the RGBA/Gray data, `decode_image_data` turns it back into raw texels
+    #[inline]
+    pub fn encoding(&self) -> ImageEncoding {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ImageEncoding>(Image::VT_ENCODING, Some(ImageEncoding::Raw))
+                .unwrap()
+        }
+    }
+    /// The levels after the image itself, each one half the size of the previous one down to 1x1.
+    /// Empty unless they were generated for embedded RGBA/Gray data.
+    #[inline]
+    pub fn mip_levels(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MipLevel<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MipLevel>>,
+            >>(Image::VT_MIP_LEVELS, None)
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_image_name(&self) -> Option<ImageName<'a>> {
+        if self.data_type() == ImageData::ImageName {
+            self.data().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ImageName::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_rgba(&self) -> Option<RGBAImageData<'a>> {
+        if self.data_type() == ImageData::RGBA {
+            self.data().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { RGBAImageData::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_color(&self) -> Option<RGBAColorData<'a>> {
+        if self.data_type() == ImageData::Color {
+            self.data().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { RGBAColorData::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn data_as_gray(&self) -> Option<GrayImageData<'a>> {
+        if self.data_type() == ImageData::Gray {
+            self.data().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GrayImageData::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
 }
 
-impl flatbuffers::Verifiable for AnimationValues<'_> {
+impl flatbuffers::Verifiable for Image<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2403,101 +3343,406 @@ impl flatbuffers::Verifiable for AnimationValues<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<Vector3>("translation", Self::VT_TRANSLATION, false)?
-            .visit_field::<Vector4>("rotation", Self::VT_ROTATION, false)?
-            .visit_field::<Vector3>("scale", Self::VT_SCALE, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
-                "weights",
-                Self::VT_WEIGHTS,
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_union::<ImageData, _>(
+                "data_type",
+                Self::VT_DATA_TYPE,
+                "data",
+                Self::VT_DATA,
                 false,
+                |key, v, pos| match key {
+                    ImageData::ImageName => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<ImageName>>(
+                            "ImageData::ImageName",
+                            pos,
+                        ),
+                    ImageData::RGBA => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<RGBAImageData>>(
+                            "ImageData::RGBA",
+                            pos,
+                        ),
+                    ImageData::Color => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<RGBAColorData>>(
+                            "ImageData::Color",
+                            pos,
+                        ),
+                    ImageData::Gray => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<GrayImageData>>(
+                            "ImageData::Gray",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
             )?
+            .visit_field::<u32>("width", Self::VT_WIDTH, false)?
+            .visit_field::<u32>("height", Self::VT_HEIGHT, false)?
+            .visit_field::<bool>("factor_applied", Self::VT_FACTOR_APPLIED, false)?
+            .visit_field::<bool>("premultiplied", Self::VT_PREMULTIPLIED, false)?
+            .visit_field::<Sampler>("sampler", Self::VT_SAMPLER, false)?
+            .visit_field::<ImageEncoding>("encoding", Self::VT_ENCODING, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MipLevel>>,
+            >>("mip_levels", Self::VT_MIP_LEVELS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct AnimationValuesArgs<'a> {
-    pub translation: Option<&'a Vector3>,
-    pub rotation: Option<&'a Vector4>,
-    pub scale: Option<&'a Vector3>,
-    pub weights: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+pub struct ImageArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub data_type: ImageData,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub width: u32,
+    pub height: u32,
+    pub factor_applied: bool,
+    pub premultiplied: bool,
+    pub sampler: Option<&'a Sampler>,
+    pub encoding: ImageEncoding,
+    pub mip_levels: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MipLevel<'a>>>>,
+    >,
 }
-impl<'a> Default for AnimationValuesArgs<'a> {
+impl<'a> Default for ImageArgs<'a> {
     #[inline]
     fn default() -> Self {
-        AnimationValuesArgs {
-            translation: None,
-            rotation: None,
-            scale: None,
-            weights: None,
+        ImageArgs {
+            name: None,
+            data_type: ImageData::NONE,
+            data: None,
+            width: 0,
+            height: 0,
+            factor_applied: false,
+            premultiplied: false,
+            sampler: None,
+            encoding: ImageEncoding::Raw,
+            mip_levels: None,
         }
     }
 }
 
-pub struct AnimationValuesBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ImageBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AnimationValuesBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ImageBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_translation(&mut self, translation: &Vector3) {
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<&Vector3>(AnimationValues::VT_TRANSLATION, translation);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Image::VT_NAME, name);
     }
     #[inline]
-    pub fn add_rotation(&mut self, rotation: &Vector4) {
+    pub fn add_data_type(&mut self, data_type: ImageData) {
         self.fbb_
-            .push_slot_always::<&Vector4>(AnimationValues::VT_ROTATION, rotation);
+            .push_slot::<ImageData>(Image::VT_DATA_TYPE, data_type, ImageData::NONE);
     }
     #[inline]
-    pub fn add_scale(&mut self, scale: &Vector3) {
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
         self.fbb_
-            .push_slot_always::<&Vector3>(AnimationValues::VT_SCALE, scale);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Image::VT_DATA, data);
     }
     #[inline]
-    pub fn add_weights(&mut self, weights: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+    pub fn add_width(&mut self, width: u32) {
+        self.fbb_.push_slot::<u32>(Image::VT_WIDTH, width, 0);
+    }
+    #[inline]
+    pub fn add_height(&mut self, height: u32) {
+        self.fbb_.push_slot::<u32>(Image::VT_HEIGHT, height, 0);
+    }
+    #[inline]
+    pub fn add_factor_applied(&mut self, factor_applied: bool) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(AnimationValues::VT_WEIGHTS, weights);
+            .push_slot::<bool>(Image::VT_FACTOR_APPLIED, factor_applied, false);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> AnimationValuesBuilder<'a, 'b, A> {
+    pub fn add_premultiplied(&mut self, premultiplied: bool) {
+        self.fbb_
+            .push_slot::<bool>(Image::VT_PREMULTIPLIED, premultiplied, false);
+    }
+    #[inline]
+    pub fn add_sampler(&mut self, sampler: &Sampler) {
+        self.fbb_
+            .push_slot_always::<&Sampler>(Image::VT_SAMPLER, sampler);
+    }
+    #[inline]
+    pub fn add_encoding(&mut self, encoding: ImageEncoding) {
+        self.fbb_
+            .push_slot::<ImageEncoding>(Image::VT_ENCODING, encoding, ImageEncoding::Raw);
+    }
+    #[inline]
+    pub fn add_mip_levels(
+        &mut self,
+        mip_levels: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MipLevel<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Image::VT_MIP_LEVELS, mip_levels);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ImageBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        AnimationValuesBuilder {
+        ImageBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<AnimationValues<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Image<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for AnimationValues<'_> {
+impl core::fmt::Debug for Image<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("AnimationValues");
-        ds.field("translation", &self.translation());
-        ds.field("rotation", &self.rotation());
-        ds.field("scale", &self.scale());
-        ds.field("weights", &self.weights());
-        ds.finish()
-    }
-}
-pub enum AnimationKeyFramesOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct AnimationKeyFrames<'a> {
-    pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for AnimationKeyFrames<'a> {
-    type Inner = AnimationKeyFrames<'a>;
-    #[inline]
-    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
+        let mut ds = f.debug_struct("Image");
+        ds.field("name", &self.name());
+        ds.field("data_type", &self.data_type());
+        match self.data_type() {
+            ImageData::ImageName => {
+                if let Some(x) = self.data_as_image_name() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ImageData::RGBA => {
+                if let Some(x) = self.data_as_rgba() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ImageData::Color => {
+                if let Some(x) = self.data_as_color() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ImageData::Gray => {
+                if let Some(x) = self.data_as_gray() {
+                    ds.field("data", &x)
+                } else {
+                    ds.field(
+                        "data",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("data", &x)
+            }
+        };
+        ds.field("width", &self.width());
+        ds.field("height", &self.height());
+        ds.field("factor_applied", &self.factor_applied());
+        ds.field("premultiplied", &self.premultiplied());
+        ds.field("sampler", &self.sampler());
+        ds.field("encoding", &self.encoding());
+        ds.field("mip_levels", &self.mip_levels());
+        ds.finish()
+    }
+}
+pub enum AnimationValuesOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AnimationValues<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AnimationValues<'a> {
+    type Inner = AnimationValues<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> AnimationValues<'a> {
+    pub const VT_TRANSLATION: flatbuffers::VOffsetT = 4;
+    pub const VT_ROTATION: flatbuffers::VOffsetT = 6;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 8;
+    pub const VT_WEIGHTS: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        AnimationValues { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args AnimationValuesArgs<'args>,
+    ) -> flatbuffers::WIPOffset<AnimationValues<'bldr>> {
+        let mut builder = AnimationValuesBuilder::new(_fbb);
+        if let Some(x) = args.weights {
+            builder.add_weights(x);
+        }
+        if let Some(x) = args.scale {
+            builder.add_scale(x);
+        }
+        if let Some(x) = args.rotation {
+            builder.add_rotation(x);
+        }
+        if let Some(x) = args.translation {
+            builder.add_translation(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn translation(&self) -> Option<&'a Vector3> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<Vector3>(AnimationValues::VT_TRANSLATION, None)
+        }
+    }
+    #[inline]
+    pub fn rotation(&self) -> Option<&'a Vector4> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Vector4>(AnimationValues::VT_ROTATION, None) }
+    }
+    #[inline]
+    pub fn scale(&self) -> Option<&'a Vector3> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Vector3>(AnimationValues::VT_SCALE, None) }
+    }
+    #[inline]
+    pub fn weights(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    AnimationValues::VT_WEIGHTS,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for AnimationValues<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<Vector3>("translation", Self::VT_TRANSLATION, false)?
+            .visit_field::<Vector4>("rotation", Self::VT_ROTATION, false)?
+            .visit_field::<Vector3>("scale", Self::VT_SCALE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "weights",
+                Self::VT_WEIGHTS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct AnimationValuesArgs<'a> {
+    pub translation: Option<&'a Vector3>,
+    pub rotation: Option<&'a Vector4>,
+    pub scale: Option<&'a Vector3>,
+    pub weights: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+}
+impl<'a> Default for AnimationValuesArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        AnimationValuesArgs {
+            translation: None,
+            rotation: None,
+            scale: None,
+            weights: None,
+        }
+    }
+}
+
+pub struct AnimationValuesBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AnimationValuesBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_translation(&mut self, translation: &Vector3) {
+        self.fbb_
+            .push_slot_always::<&Vector3>(AnimationValues::VT_TRANSLATION, translation);
+    }
+    #[inline]
+    pub fn add_rotation(&mut self, rotation: &Vector4) {
+        self.fbb_
+            .push_slot_always::<&Vector4>(AnimationValues::VT_ROTATION, rotation);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: &Vector3) {
+        self.fbb_
+            .push_slot_always::<&Vector3>(AnimationValues::VT_SCALE, scale);
+    }
+    #[inline]
+    pub fn add_weights(&mut self, weights: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(AnimationValues::VT_WEIGHTS, weights);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> AnimationValuesBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        AnimationValuesBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<AnimationValues<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for AnimationValues<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("AnimationValues");
+        ds.field("translation", &self.translation());
+        ds.field("rotation", &self.rotation());
+        ds.field("scale", &self.scale());
+        ds.field("weights", &self.weights());
+        ds.finish()
+    }
+}
+pub enum AnimationKeyFramesOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AnimationKeyFrames<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AnimationKeyFrames<'a> {
+    type Inner = AnimationKeyFrames<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
         }
     }
 }
@@ -2841,42 +4086,2068 @@ impl<'a> Animation<'a> {
         }
     }
     #[inline]
-    pub fn interpolation(&self) -> Option<&'a InterpolationTargets> {
+    pub fn interpolation(&self) -> Option<&'a InterpolationTargets> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<InterpolationTargets>(Animation::VT_INTERPOLATION, None)
+        }
+    }
+    #[inline]
+    pub fn frames(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationKeyFrames<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationKeyFrames>>,
+            >>(Animation::VT_FRAMES, None)
+        }
+    }
+    #[inline]
+    pub fn duration(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Animation::VT_DURATION, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for Animation<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u64>("target", Self::VT_TARGET, false)?
+            .visit_field::<AnimationTargetTypes>("target_type", Self::VT_TARGET_TYPE, false)?
+            .visit_field::<InterpolationTargets>("interpolation", Self::VT_INTERPOLATION, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AnimationKeyFrames>>,
+            >>("frames", Self::VT_FRAMES, false)?
+            .visit_field::<f32>("duration", Self::VT_DURATION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct AnimationArgs<'a> {
+    pub target: u64,
+    pub target_type: AnimationTargetTypes,
+    pub interpolation: Option<&'a InterpolationTargets>,
+    pub frames: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationKeyFrames<'a>>>,
+        >,
+    >,
+    pub duration: f32,
+}
+impl<'a> Default for AnimationArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        AnimationArgs {
+            target: 0,
+            target_type: AnimationTargetTypes::Object,
+            interpolation: None,
+            frames: None,
+            duration: 0.0,
+        }
+    }
+}
+
+pub struct AnimationBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AnimationBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_target(&mut self, target: u64) {
+        self.fbb_.push_slot::<u64>(Animation::VT_TARGET, target, 0);
+    }
+    #[inline]
+    pub fn add_target_type(&mut self, target_type: AnimationTargetTypes) {
+        self.fbb_.push_slot::<AnimationTargetTypes>(
+            Animation::VT_TARGET_TYPE,
+            target_type,
+            AnimationTargetTypes::Object,
+        );
+    }
+    #[inline]
+    pub fn add_interpolation(&mut self, interpolation: &InterpolationTargets) {
+        self.fbb_
+            .push_slot_always::<&InterpolationTargets>(Animation::VT_INTERPOLATION, interpolation);
+    }
+    #[inline]
+    pub fn add_frames(
+        &mut self,
+        frames: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AnimationKeyFrames<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Animation::VT_FRAMES, frames);
+    }
+    #[inline]
+    pub fn add_duration(&mut self, duration: f32) {
+        self.fbb_
+            .push_slot::<f32>(Animation::VT_DURATION, duration, 0.0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AnimationBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        AnimationBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Animation<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for Animation<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("Animation");
+        ds.field("target", &self.target());
+        ds.field("target_type", &self.target_type());
+        ds.field("interpolation", &self.interpolation());
+        ds.field("frames", &self.frames());
+        ds.field("duration", &self.duration());
+        ds.finish()
+    }
+}
+pub enum MorphTargetsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MorphTargets<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MorphTargets<'a> {
+    type Inner = MorphTargets<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> MorphTargets<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_TRANSLATION: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MorphTargets { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args MorphTargetsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MorphTargets<'bldr>> {
+        let mut builder = MorphTargetsBuilder::new(_fbb);
+        if let Some(x) = args.translation {
+            builder.add_translation(x);
+        }
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'a str {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(MorphTargets::VT_NAME, None)
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn translation(&self) -> Option<flatbuffers::Vector<'a, Vector3>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Vector3>>>(
+                    MorphTargets::VT_TRANSLATION,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for MorphTargets<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Vector3>>>(
+                "translation",
+                Self::VT_TRANSLATION,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct MorphTargetsArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub translation: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Vector3>>>,
+}
+impl<'a> Default for MorphTargetsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        MorphTargetsArgs {
+            name: None, // required field
+            translation: None,
+        }
+    }
+}
+
+pub struct MorphTargetsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MorphTargetsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MorphTargets::VT_NAME, name);
+    }
+    #[inline]
+    pub fn add_translation(
+        &mut self,
+        translation: flatbuffers::WIPOffset<flatbuffers::Vector<'b, Vector3>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MorphTargets::VT_TRANSLATION,
+            translation,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> MorphTargetsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        MorphTargetsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<MorphTargets<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, MorphTargets::VT_NAME, "name");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for MorphTargets<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MorphTargets");
+        ds.field("name", &self.name());
+        ds.field("translation", &self.translation());
+        ds.finish()
+    }
+}
+pub enum SkeletonOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Skeleton<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Skeleton<'a> {
+    type Inner = Skeleton<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> Skeleton<'a> {
+    pub const VT_ID: flatbuffers::VOffsetT = 4;
+    pub const VT_NAME: flatbuffers::VOffsetT = 6;
+    pub const VT_ROOT_INDEX: flatbuffers::VOffsetT = 8;
+    pub const VT_INVERSE_BIND_MATRIXES: flatbuffers::VOffsetT = 10;
+    pub const VT_BONES: flatbuffers::VOffsetT = 12;
+    pub const VT_PROPERTIES: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Skeleton { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SkeletonArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Skeleton<'bldr>> {
+        let mut builder = SkeletonBuilder::new(_fbb);
+        builder.add_id(args.id);
+        if let Some(x) = args.properties {
+            builder.add_properties(x);
+        }
+        if let Some(x) = args.bones {
+            builder.add_bones(x);
+        }
+        if let Some(x) = args.inverse_bind_matrixes {
+            builder.add_inverse_bind_matrixes(x);
+        }
+        if let Some(x) = args.root_index {
+            builder.add_root_index(x);
+        }
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
+        builder.finish()
+    }
+
+    /// The internal index from the json file
+    #[inline]
+    pub fn id(&self) -> u64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u64>(Skeleton::VT_ID, Some(0)).unwrap() }
+    }
+    /// User-provided name
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Skeleton::VT_NAME, None)
+        }
+    }
+    /// The root of the skeleton tree
+    #[inline]
+    pub fn root_index(&self) -> Option<&'a GlobalNodeIdentifier> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<GlobalNodeIdentifier>(Skeleton::VT_ROOT_INDEX, None)
+        }
+    }
+    /// Matrixes used to bring coordinates being skinned into the same space as the joint
+    #[inline]
+    pub fn inverse_bind_matrixes(&self) -> Option<flatbuffers::Vector<'a, Matrix4>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Matrix4>>>(
+                    Skeleton::VT_INVERSE_BIND_MATRIXES,
+                    None,
+                )
+        }
+    }
+    /// List of bones assoicated with the skeleton
+    #[inline]
+    pub fn bones(&self) -> Option<flatbuffers::Vector<'a, GlobalNodeIdentifier>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, GlobalNodeIdentifier>>>(
+                    Skeleton::VT_BONES,
+                    None,
+                )
+        }
+    }
+    /// Extra user data
+    #[inline]
+    pub fn properties(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property>>,
+            >>(Skeleton::VT_PROPERTIES, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for Skeleton<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+     .visit_field::<u64>("id", Self::VT_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<GlobalNodeIdentifier>("root_index", Self::VT_ROOT_INDEX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Matrix4>>>("inverse_bind_matrixes", Self::VT_INVERSE_BIND_MATRIXES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, GlobalNodeIdentifier>>>("bones", Self::VT_BONES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>>>("properties", Self::VT_PROPERTIES, false)?
+     .finish();
+        Ok(())
+    }
+}
+pub struct SkeletonArgs<'a> {
+    pub id: u64,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub root_index: Option<&'a GlobalNodeIdentifier>,
+    pub inverse_bind_matrixes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Matrix4>>>,
+    pub bones: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, GlobalNodeIdentifier>>>,
+    pub properties: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>>,
+    >,
+}
+impl<'a> Default for SkeletonArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        SkeletonArgs {
+            id: 0,
+            name: None,
+            root_index: None,
+            inverse_bind_matrixes: None,
+            bones: None,
+            properties: None,
+        }
+    }
+}
+
+pub struct SkeletonBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SkeletonBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_id(&mut self, id: u64) {
+        self.fbb_.push_slot::<u64>(Skeleton::VT_ID, id, 0);
+    }
+    #[inline]
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Skeleton::VT_NAME, name);
+    }
+    #[inline]
+    pub fn add_root_index(&mut self, root_index: &GlobalNodeIdentifier) {
+        self.fbb_
+            .push_slot_always::<&GlobalNodeIdentifier>(Skeleton::VT_ROOT_INDEX, root_index);
+    }
+    #[inline]
+    pub fn add_inverse_bind_matrixes(
+        &mut self,
+        inverse_bind_matrixes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, Matrix4>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            Skeleton::VT_INVERSE_BIND_MATRIXES,
+            inverse_bind_matrixes,
+        );
+    }
+    #[inline]
+    pub fn add_bones(
+        &mut self,
+        bones: flatbuffers::WIPOffset<flatbuffers::Vector<'b, GlobalNodeIdentifier>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Skeleton::VT_BONES, bones);
+    }
+    #[inline]
+    pub fn add_properties(
+        &mut self,
+        properties: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Property<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Skeleton::VT_PROPERTIES, properties);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SkeletonBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SkeletonBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Skeleton<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for Skeleton<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("Skeleton");
+        ds.field("id", &self.id());
+        ds.field("name", &self.name());
+        ds.field("root_index", &self.root_index());
+        ds.field("inverse_bind_matrixes", &self.inverse_bind_matrixes());
+        ds.field("bones", &self.bones());
+        ds.field("properties", &self.properties());
+        ds.finish()
+    }
+}
+pub enum MeshLodOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MeshLod<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MeshLod<'a> {
+    type Inner = MeshLod<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> MeshLod<'a> {
+    pub const VT_TRIANGLES: flatbuffers::VOffsetT = 4;
+    pub const VT_ERROR: flatbuffers::VOffsetT = 6;
+    pub const VT_TARGET_RATIO: flatbuffers::VOffsetT = 8;
+    pub const VT_ACHIEVED_RATIO: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MeshLod { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args MeshLodArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MeshLod<'bldr>> {
+        let mut builder = MeshLodBuilder::new(_fbb);
+        builder.add_achieved_ratio(args.achieved_ratio);
+        builder.add_target_ratio(args.target_ratio);
+        builder.add_error(args.error);
+        if let Some(x) = args.triangles {
+            builder.add_triangles(x);
+        }
+        builder.finish()
+    }
+
+    /// Index buffer into the same points/attributes as the full mesh
+    #[inline]
+    pub fn triangles(&self) -> flatbuffers::Vector<'a, u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MeshLod::VT_TRIANGLES,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    /// How far (in model units) the simplified surface can be from the original one
+    #[inline]
+    pub fn error(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(MeshLod::VT_ERROR, Some(0.0)).unwrap() }
+    }
+    /// The triangle ratio that was asked for in `lod_levels`, levels that couldn't be simplified are left out
+    #[inline]
+    pub fn target_ratio(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(MeshLod::VT_TARGET_RATIO, Some(0.0))
+                .unwrap()
+        }
+    }
+    /// The triangle count of this level divided by the one of the full mesh
+    #[inline]
+    pub fn achieved_ratio(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(MeshLod::VT_ACHIEVED_RATIO, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for MeshLod<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "triangles",
+                Self::VT_TRIANGLES,
+                true,
+            )?
+            .visit_field::<f32>("error", Self::VT_ERROR, false)?
+            .visit_field::<f32>("target_ratio", Self::VT_TARGET_RATIO, false)?
+            .visit_field::<f32>("achieved_ratio", Self::VT_ACHIEVED_RATIO, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct MeshLodArgs<'a> {
+    pub triangles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub error: f32,
+    pub target_ratio: f32,
+    pub achieved_ratio: f32,
+}
+impl<'a> Default for MeshLodArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        MeshLodArgs {
+            triangles: None, // required field
+            error: 0.0,
+            target_ratio: 0.0,
+            achieved_ratio: 0.0,
+        }
+    }
+}
+
+pub struct MeshLodBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MeshLodBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_triangles(
+        &mut self,
+        triangles: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MeshLod::VT_TRIANGLES, triangles);
+    }
+    #[inline]
+    pub fn add_error(&mut self, error: f32) {
+        self.fbb_.push_slot::<f32>(MeshLod::VT_ERROR, error, 0.0);
+    }
+    #[inline]
+    pub fn add_target_ratio(&mut self, target_ratio: f32) {
+        self.fbb_
+            .push_slot::<f32>(MeshLod::VT_TARGET_RATIO, target_ratio, 0.0);
+    }
+    #[inline]
+    pub fn add_achieved_ratio(&mut self, achieved_ratio: f32) {
+        self.fbb_
+            .push_slot::<f32>(MeshLod::VT_ACHIEVED_RATIO, achieved_ratio, 0.0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MeshLodBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        MeshLodBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<MeshLod<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, MeshLod::VT_TRIANGLES, "triangles");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for MeshLod<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MeshLod");
+        ds.field("triangles", &self.triangles());
+        ds.field("error", &self.error());
+        ds.field("target_ratio", &self.target_ratio());
+        ds.field("achieved_ratio", &self.achieved_ratio());
+        ds.finish()
+    }
+}
+pub enum ToonOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Toon shading factors from VRMC_materials_mtoon (colors are linear RGB)
+pub struct Toon<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Toon<'a> {
+    type Inner = Toon<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> Toon<'a> {
+    pub const VT_SHADE_COLOR: flatbuffers::VOffsetT = 4;
+    pub const VT_SHADING_SHIFT: flatbuffers::VOffsetT = 6;
+    pub const VT_SHADING_TOONY: flatbuffers::VOffsetT = 8;
+    pub const VT_OUTLINE_WIDTH: flatbuffers::VOffsetT = 10;
+    pub const VT_OUTLINE_WIDTH_MODE: flatbuffers::VOffsetT = 12;
+    pub const VT_OUTLINE_COLOR: flatbuffers::VOffsetT = 14;
+    pub const VT_RIM_COLOR: flatbuffers::VOffsetT = 16;
+    pub const VT_RIM_FRESNEL_POWER: flatbuffers::VOffsetT = 18;
+    pub const VT_RIM_LIFT: flatbuffers::VOffsetT = 20;
+    pub const VT_RIM_LIGHTING_MIX: flatbuffers::VOffsetT = 22;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Toon { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ToonArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Toon<'bldr>> {
+        let mut builder = ToonBuilder::new(_fbb);
+        builder.add_rim_lighting_mix(args.rim_lighting_mix);
+        builder.add_rim_lift(args.rim_lift);
+        builder.add_rim_fresnel_power(args.rim_fresnel_power);
+        if let Some(x) = args.rim_color {
+            builder.add_rim_color(x);
+        }
+        if let Some(x) = args.outline_color {
+            builder.add_outline_color(x);
+        }
+        builder.add_outline_width(args.outline_width);
+        builder.add_shading_toony(args.shading_toony);
+        builder.add_shading_shift(args.shading_shift);
+        if let Some(x) = args.shade_color {
+            builder.add_shade_color(x);
+        }
+        builder.add_outline_width_mode(args.outline_width_mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn shade_color(&self) -> Option<&'a Vector3> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Vector3>(Toon::VT_SHADE_COLOR, None) }
+    }
+    #[inline]
+    pub fn shading_shift(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Toon::VT_SHADING_SHIFT, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn shading_toony(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Toon::VT_SHADING_TOONY, Some(0.9))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn outline_width(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Toon::VT_OUTLINE_WIDTH, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn outline_width_mode(&self) -> OutlineWidthMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<OutlineWidthMode>(Toon::VT_OUTLINE_WIDTH_MODE, Some(OutlineWidthMode::None))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn outline_color(&self) -> Option<&'a Vector3> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Vector3>(Toon::VT_OUTLINE_COLOR, None) }
+    }
+    #[inline]
+    pub fn rim_color(&self) -> Option<&'a Vector3> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Vector3>(Toon::VT_RIM_COLOR, None) }
+    }
+    #[inline]
+    pub fn rim_fresnel_power(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Toon::VT_RIM_FRESNEL_POWER, Some(5.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn rim_lift(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(Toon::VT_RIM_LIFT, Some(0.0)).unwrap() }
+    }
+    #[inline]
+    pub fn rim_lighting_mix(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Toon::VT_RIM_LIGHTING_MIX, Some(1.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for Toon<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<Vector3>("shade_color", Self::VT_SHADE_COLOR, false)?
+            .visit_field::<f32>("shading_shift", Self::VT_SHADING_SHIFT, false)?
+            .visit_field::<f32>("shading_toony", Self::VT_SHADING_TOONY, false)?
+            .visit_field::<f32>("outline_width", Self::VT_OUTLINE_WIDTH, false)?
+            .visit_field::<OutlineWidthMode>(
+                "outline_width_mode",
+                Self::VT_OUTLINE_WIDTH_MODE,
+                false,
+            )?
+            .visit_field::<Vector3>("outline_color", Self::VT_OUTLINE_COLOR, false)?
+            .visit_field::<Vector3>("rim_color", Self::VT_RIM_COLOR, false)?
+            .visit_field::<f32>("rim_fresnel_power", Self::VT_RIM_FRESNEL_POWER, false)?
+            .visit_field::<f32>("rim_lift", Self::VT_RIM_LIFT, false)?
+            .visit_field::<f32>("rim_lighting_mix", Self::VT_RIM_LIGHTING_MIX, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ToonArgs<'a> {
+    pub shade_color: Option<&'a Vector3>,
+    pub shading_shift: f32,
+    pub shading_toony: f32,
+    pub outline_width: f32,
+    pub outline_width_mode: OutlineWidthMode,
+    pub outline_color: Option<&'a Vector3>,
+    pub rim_color: Option<&'a Vector3>,
+    pub rim_fresnel_power: f32,
+    pub rim_lift: f32,
+    pub rim_lighting_mix: f32,
+}
+impl<'a> Default for ToonArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ToonArgs {
+            shade_color: None,
+            shading_shift: 0.0,
+            shading_toony: 0.9,
+            outline_width: 0.0,
+            outline_width_mode: OutlineWidthMode::None,
+            outline_color: None,
+            rim_color: None,
+            rim_fresnel_power: 5.0,
+            rim_lift: 0.0,
+            rim_lighting_mix: 1.0,
+        }
+    }
+}
+
+pub struct ToonBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ToonBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_shade_color(&mut self, shade_color: &Vector3) {
+        self.fbb_
+            .push_slot_always::<&Vector3>(Toon::VT_SHADE_COLOR, shade_color);
+    }
+    #[inline]
+    pub fn add_shading_shift(&mut self, shading_shift: f32) {
+        self.fbb_
+            .push_slot::<f32>(Toon::VT_SHADING_SHIFT, shading_shift, 0.0);
+    }
+    #[inline]
+    pub fn add_shading_toony(&mut self, shading_toony: f32) {
+        self.fbb_
+            .push_slot::<f32>(Toon::VT_SHADING_TOONY, shading_toony, 0.9);
+    }
+    #[inline]
+    pub fn add_outline_width(&mut self, outline_width: f32) {
+        self.fbb_
+            .push_slot::<f32>(Toon::VT_OUTLINE_WIDTH, outline_width, 0.0);
+    }
+    #[inline]
+    pub fn add_outline_width_mode(&mut self, outline_width_mode: OutlineWidthMode) {
+        self.fbb_.push_slot::<OutlineWidthMode>(
+            Toon::VT_OUTLINE_WIDTH_MODE,
+            outline_width_mode,
+            OutlineWidthMode::None,
+        );
+    }
+    #[inline]
+    pub fn add_outline_color(&mut self, outline_color: &Vector3) {
+        self.fbb_
+            .push_slot_always::<&Vector3>(Toon::VT_OUTLINE_COLOR, outline_color);
+    }
+    #[inline]
+    pub fn add_rim_color(&mut self, rim_color: &Vector3) {
+        self.fbb_
+            .push_slot_always::<&Vector3>(Toon::VT_RIM_COLOR, rim_color);
+    }
+    #[inline]
+    pub fn add_rim_fresnel_power(&mut self, rim_fresnel_power: f32) {
+        self.fbb_
+            .push_slot::<f32>(Toon::VT_RIM_FRESNEL_POWER, rim_fresnel_power, 5.0);
+    }
+    #[inline]
+    pub fn add_rim_lift(&mut self, rim_lift: f32) {
+        self.fbb_.push_slot::<f32>(Toon::VT_RIM_LIFT, rim_lift, 0.0);
+    }
+    #[inline]
+    pub fn add_rim_lighting_mix(&mut self, rim_lighting_mix: f32) {
+        self.fbb_
+            .push_slot::<f32>(Toon::VT_RIM_LIGHTING_MIX, rim_lighting_mix, 1.0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ToonBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ToonBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Toon<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for Toon<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("Toon");
+        ds.field("shade_color", &self.shade_color());
+        ds.field("shading_shift", &self.shading_shift());
+        ds.field("shading_toony", &self.shading_toony());
+        ds.field("outline_width", &self.outline_width());
+        ds.field("outline_width_mode", &self.outline_width_mode());
+        ds.field("outline_color", &self.outline_color());
+        ds.field("rim_color", &self.rim_color());
+        ds.field("rim_fresnel_power", &self.rim_fresnel_power());
+        ds.field("rim_lift", &self.rim_lift());
+        ds.field("rim_lighting_mix", &self.rim_lighting_mix());
+        ds.finish()
+    }
+}
+pub enum MaterialOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// How the mesh should be shaded, the base color is in the `texture` of the mesh
+pub struct Material<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Material<'a> {
+    type Inner = Material<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> Material<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_UNLIT: flatbuffers::VOffsetT = 6;
+    pub const VT_EMISSIVE_FACTOR: flatbuffers::VOffsetT = 8;
+    pub const VT_EMISSIVE_STRENGTH: flatbuffers::VOffsetT = 10;
+    pub const VT_TOON: flatbuffers::VOffsetT = 12;
+    pub const VT_EXTENSIONS: flatbuffers::VOffsetT = 14;
+    pub const VT_BASE_COLOR_FACTOR: flatbuffers::VOffsetT = 16;
+    pub const VT_OCCLUSION_TEXTURE: flatbuffers::VOffsetT = 18;
+    pub const VT_OCCLUSION_STRENGTH: flatbuffers::VOffsetT = 20;
+    pub const VT_METALLIC_ROUGHNESS_TEXTURE: flatbuffers::VOffsetT = 22;
+    pub const VT_METALLIC_TEXTURE: flatbuffers::VOffsetT = 24;
+    pub const VT_ROUGHNESS_TEXTURE: flatbuffers::VOffsetT = 26;
+    pub const VT_METALLIC_FACTOR: flatbuffers::VOffsetT = 28;
+    pub const VT_ROUGHNESS_FACTOR: flatbuffers::VOffsetT = 30;
+    pub const VT_EMISSIVE_TEXTURE: flatbuffers::VOffsetT = 32;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Material { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args MaterialArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Material<'bldr>> {
+        let mut builder = MaterialBuilder::new(_fbb);
+        if let Some(x) = args.emissive_texture {
+            builder.add_emissive_texture(x);
+        }
+        builder.add_roughness_factor(args.roughness_factor);
+        builder.add_metallic_factor(args.metallic_factor);
+        if let Some(x) = args.roughness_texture {
+            builder.add_roughness_texture(x);
+        }
+        if let Some(x) = args.metallic_texture {
+            builder.add_metallic_texture(x);
+        }
+        if let Some(x) = args.metallic_roughness_texture {
+            builder.add_metallic_roughness_texture(x);
+        }
+        builder.add_occlusion_strength(args.occlusion_strength);
+        if let Some(x) = args.occlusion_texture {
+            builder.add_occlusion_texture(x);
+        }
+        if let Some(x) = args.base_color_factor {
+            builder.add_base_color_factor(x);
+        }
+        if let Some(x) = args.extensions {
+            builder.add_extensions(x);
+        }
+        if let Some(x) = args.toon {
+            builder.add_toon(x);
+        }
+        builder.add_emissive_strength(args.emissive_strength);
+        if let Some(x) = args.emissive_factor {
+            builder.add_emissive_factor(x);
+        }
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
+        builder.add_unlit(args.unlit);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Material::VT_NAME, None)
+        }
+    }
+    /// Lighting should be ignored (KHR_materials_unlit)
+    #[inline]
+    pub fn unlit(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(Material::VT_UNLIT, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn emissive_factor(&self) -> Option<&'a Vector3> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Vector3>(Material::VT_EMISSIVE_FACTOR, None) }
+    }
+    /// Multiplies `emissive_factor` (KHR_materials_emissive_strength)
+    #[inline]
+    pub fn emissive_strength(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Material::VT_EMISSIVE_STRENGTH, Some(1.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn toon(&self) -> Option<Toon<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Toon>>(Material::VT_TOON, None)
+        }
+    }
+    /// Raw json of the extensions that aren't read, by extension name
+    #[inline]
+    pub fn extensions(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property>>,
+            >>(Material::VT_EXTENSIONS, None)
+        }
+    }
+    /// Linear multiplier of the base color, check `factor_applied` of the texture before using it
+    #[inline]
+    pub fn base_color_factor(&self) -> Option<&'a RGBAColor> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<RGBAColor>(Material::VT_BASE_COLOR_FACTOR, None)
+        }
+    }
+    /// The textures below are only there when asked for (`pbr_textures` of the options) and, like the mesh `texture`,
+    /// aren't sent again when the mesh has `instance_of` set. Their factors aren't applied.
+    /// Images named after a file keep the glTF layout: occlusion in red, roughness in green and metallic in blue.
+    /// Occlusion in red, or the only channel when exported as channels
+    #[inline]
+    pub fn occlusion_texture(&self) -> Option<Image<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Image>>(Material::VT_OCCLUSION_TEXTURE, None)
+        }
+    }
+    #[inline]
+    pub fn occlusion_strength(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Material::VT_OCCLUSION_STRENGTH, Some(1.0))
+                .unwrap()
+        }
+    }
+    /// Roughness in green and metallic in blue, only for packed textures
+    #[inline]
+    pub fn metallic_roughness_texture(&self) -> Option<Image<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<Image>>(
+                Material::VT_METALLIC_ROUGHNESS_TEXTURE,
+                None,
+            )
+        }
+    }
+    /// Only for textures exported as channels
+    #[inline]
+    pub fn metallic_texture(&self) -> Option<Image<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Image>>(Material::VT_METALLIC_TEXTURE, None)
+        }
+    }
+    #[inline]
+    pub fn roughness_texture(&self) -> Option<Image<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Image>>(Material::VT_ROUGHNESS_TEXTURE, None)
+        }
+    }
+    #[inline]
+    pub fn metallic_factor(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Material::VT_METALLIC_FACTOR, Some(1.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn roughness_factor(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(Material::VT_ROUGHNESS_FACTOR, Some(1.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn emissive_texture(&self) -> Option<Image<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Image>>(Material::VT_EMISSIVE_TEXTURE, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for Material<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_field::<bool>("unlit", Self::VT_UNLIT, false)?
+            .visit_field::<Vector3>("emissive_factor", Self::VT_EMISSIVE_FACTOR, false)?
+            .visit_field::<f32>("emissive_strength", Self::VT_EMISSIVE_STRENGTH, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Toon>>("toon", Self::VT_TOON, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>,
+            >>("extensions", Self::VT_EXTENSIONS, false)?
+            .visit_field::<RGBAColor>("base_color_factor", Self::VT_BASE_COLOR_FACTOR, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Image>>(
+                "occlusion_texture",
+                Self::VT_OCCLUSION_TEXTURE,
+                false,
+            )?
+            .visit_field::<f32>("occlusion_strength", Self::VT_OCCLUSION_STRENGTH, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Image>>(
+                "metallic_roughness_texture",
+                Self::VT_METALLIC_ROUGHNESS_TEXTURE,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<Image>>(
+                "metallic_texture",
+                Self::VT_METALLIC_TEXTURE,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<Image>>(
+                "roughness_texture",
+                Self::VT_ROUGHNESS_TEXTURE,
+                false,
+            )?
+            .visit_field::<f32>("metallic_factor", Self::VT_METALLIC_FACTOR, false)?
+            .visit_field::<f32>("roughness_factor", Self::VT_ROUGHNESS_FACTOR, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Image>>(
+                "emissive_texture",
+                Self::VT_EMISSIVE_TEXTURE,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct MaterialArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub unlit: bool,
+    pub emissive_factor: Option<&'a Vector3>,
+    pub emissive_strength: f32,
+    pub toon: Option<flatbuffers::WIPOffset<Toon<'a>>>,
+    pub extensions: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>>,
+    >,
+    pub base_color_factor: Option<&'a RGBAColor>,
+    pub occlusion_texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
+    pub occlusion_strength: f32,
+    pub metallic_roughness_texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
+    pub metallic_texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
+    pub roughness_texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
+    pub metallic_factor: f32,
+    pub roughness_factor: f32,
+    pub emissive_texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
+}
+impl<'a> Default for MaterialArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        MaterialArgs {
+            name: None,
+            unlit: false,
+            emissive_factor: None,
+            emissive_strength: 1.0,
+            toon: None,
+            extensions: None,
+            base_color_factor: None,
+            occlusion_texture: None,
+            occlusion_strength: 1.0,
+            metallic_roughness_texture: None,
+            metallic_texture: None,
+            roughness_texture: None,
+            metallic_factor: 1.0,
+            roughness_factor: 1.0,
+            emissive_texture: None,
+        }
+    }
+}
+
+pub struct MaterialBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MaterialBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Material::VT_NAME, name);
+    }
+    #[inline]
+    pub fn add_unlit(&mut self, unlit: bool) {
+        self.fbb_
+            .push_slot::<bool>(Material::VT_UNLIT, unlit, false);
+    }
+    #[inline]
+    pub fn add_emissive_factor(&mut self, emissive_factor: &Vector3) {
+        self.fbb_
+            .push_slot_always::<&Vector3>(Material::VT_EMISSIVE_FACTOR, emissive_factor);
+    }
+    #[inline]
+    pub fn add_emissive_strength(&mut self, emissive_strength: f32) {
+        self.fbb_
+            .push_slot::<f32>(Material::VT_EMISSIVE_STRENGTH, emissive_strength, 1.0);
+    }
+    #[inline]
+    pub fn add_toon(&mut self, toon: flatbuffers::WIPOffset<Toon<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<Toon>>(Material::VT_TOON, toon);
+    }
+    #[inline]
+    pub fn add_extensions(
+        &mut self,
+        extensions: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Property<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Material::VT_EXTENSIONS, extensions);
+    }
+    #[inline]
+    pub fn add_base_color_factor(&mut self, base_color_factor: &RGBAColor) {
+        self.fbb_
+            .push_slot_always::<&RGBAColor>(Material::VT_BASE_COLOR_FACTOR, base_color_factor);
+    }
+    #[inline]
+    pub fn add_occlusion_texture(&mut self, occlusion_texture: flatbuffers::WIPOffset<Image<'b>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Image>>(
+            Material::VT_OCCLUSION_TEXTURE,
+            occlusion_texture,
+        );
+    }
+    #[inline]
+    pub fn add_occlusion_strength(&mut self, occlusion_strength: f32) {
+        self.fbb_
+            .push_slot::<f32>(Material::VT_OCCLUSION_STRENGTH, occlusion_strength, 1.0);
+    }
+    #[inline]
+    pub fn add_metallic_roughness_texture(
+        &mut self,
+        metallic_roughness_texture: flatbuffers::WIPOffset<Image<'b>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Image>>(
+            Material::VT_METALLIC_ROUGHNESS_TEXTURE,
+            metallic_roughness_texture,
+        );
+    }
+    #[inline]
+    pub fn add_metallic_texture(&mut self, metallic_texture: flatbuffers::WIPOffset<Image<'b>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Image>>(
+            Material::VT_METALLIC_TEXTURE,
+            metallic_texture,
+        );
+    }
+    #[inline]
+    pub fn add_roughness_texture(&mut self, roughness_texture: flatbuffers::WIPOffset<Image<'b>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Image>>(
+            Material::VT_ROUGHNESS_TEXTURE,
+            roughness_texture,
+        );
+    }
+    #[inline]
+    pub fn add_metallic_factor(&mut self, metallic_factor: f32) {
+        self.fbb_
+            .push_slot::<f32>(Material::VT_METALLIC_FACTOR, metallic_factor, 1.0);
+    }
+    #[inline]
+    pub fn add_roughness_factor(&mut self, roughness_factor: f32) {
+        self.fbb_
+            .push_slot::<f32>(Material::VT_ROUGHNESS_FACTOR, roughness_factor, 1.0);
+    }
+    #[inline]
+    pub fn add_emissive_texture(&mut self, emissive_texture: flatbuffers::WIPOffset<Image<'b>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Image>>(
+            Material::VT_EMISSIVE_TEXTURE,
+            emissive_texture,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MaterialBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        MaterialBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Material<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for Material<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("Material");
+        ds.field("name", &self.name());
+        ds.field("unlit", &self.unlit());
+        ds.field("emissive_factor", &self.emissive_factor());
+        ds.field("emissive_strength", &self.emissive_strength());
+        ds.field("toon", &self.toon());
+        ds.field("extensions", &self.extensions());
+        ds.field("base_color_factor", &self.base_color_factor());
+        ds.field("occlusion_texture", &self.occlusion_texture());
+        ds.field("occlusion_strength", &self.occlusion_strength());
+        ds.field(
+            "metallic_roughness_texture",
+            &self.metallic_roughness_texture(),
+        );
+        ds.field("metallic_texture", &self.metallic_texture());
+        ds.field("roughness_texture", &self.roughness_texture());
+        ds.field("metallic_factor", &self.metallic_factor());
+        ds.field("roughness_factor", &self.roughness_factor());
+        ds.field("emissive_texture", &self.emissive_texture());
+        ds.finish()
+    }
+}
+pub enum MaterialVariantOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// Texture to use when a material variant (KHR_materials_variants) is picked
+pub struct MaterialVariant<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MaterialVariant<'a> {
+    type Inner = MaterialVariant<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> MaterialVariant<'a> {
+    pub const VT_VARIANT: flatbuffers::VOffsetT = 4;
+    pub const VT_TEXTURE: flatbuffers::VOffsetT = 6;
+    pub const VT_MATERIAL: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        MaterialVariant { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args MaterialVariantArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MaterialVariant<'bldr>> {
+        let mut builder = MaterialVariantBuilder::new(_fbb);
+        if let Some(x) = args.material {
+            builder.add_material(x);
+        }
+        if let Some(x) = args.texture {
+            builder.add_texture(x);
+        }
+        builder.add_variant(args.variant);
+        builder.finish()
+    }
+
+    /// Index into the `variants` of the scene
+    #[inline]
+    pub fn variant(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(MaterialVariant::VT_VARIANT, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn texture(&self) -> Option<Image<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Image>>(MaterialVariant::VT_TEXTURE, None)
+        }
+    }
+    #[inline]
+    pub fn material(&self) -> Option<Material<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Material>>(MaterialVariant::VT_MATERIAL, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for MaterialVariant<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("variant", Self::VT_VARIANT, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Image>>("texture", Self::VT_TEXTURE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Material>>(
+                "material",
+                Self::VT_MATERIAL,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct MaterialVariantArgs<'a> {
+    pub variant: u32,
+    pub texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
+    pub material: Option<flatbuffers::WIPOffset<Material<'a>>>,
+}
+impl<'a> Default for MaterialVariantArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        MaterialVariantArgs {
+            variant: 0,
+            texture: None,
+            material: None,
+        }
+    }
+}
+
+pub struct MaterialVariantBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MaterialVariantBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_variant(&mut self, variant: u32) {
+        self.fbb_
+            .push_slot::<u32>(MaterialVariant::VT_VARIANT, variant, 0);
+    }
+    #[inline]
+    pub fn add_texture(&mut self, texture: flatbuffers::WIPOffset<Image<'b>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Image>>(
+            MaterialVariant::VT_TEXTURE,
+            texture,
+        );
+    }
+    #[inline]
+    pub fn add_material(&mut self, material: flatbuffers::WIPOffset<Material<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<Material>>(
+                MaterialVariant::VT_MATERIAL,
+                material,
+            );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> MaterialVariantBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        MaterialVariantBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<MaterialVariant<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for MaterialVariant<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("MaterialVariant");
+        ds.field("variant", &self.variant());
+        ds.field("texture", &self.texture());
+        ds.field("material", &self.material());
+        ds.finish()
+    }
+}
+pub enum MeshOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Mesh<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Mesh<'a> {
+    type Inner = Mesh<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> Mesh<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_ID: flatbuffers::VOffsetT = 6;
+    pub const VT_TRANSFORM: flatbuffers::VOffsetT = 8;
+    pub const VT_POINTS: flatbuffers::VOffsetT = 10;
+    pub const VT_MORPH_TARGETS: flatbuffers::VOffsetT = 12;
+    pub const VT_DEFAULT_MORPH_WEIGHTS: flatbuffers::VOffsetT = 14;
+    pub const VT_TRIANGLES: flatbuffers::VOffsetT = 16;
+    pub const VT_ANIMATIONS: flatbuffers::VOffsetT = 18;
+    pub const VT_UVS: flatbuffers::VOffsetT = 20;
+    pub const VT_SKELETON: flatbuffers::VOffsetT = 22;
+    pub const VT_TEXTURE: flatbuffers::VOffsetT = 24;
+    pub const VT_LAYOUT_TYPE: flatbuffers::VOffsetT = 26;
+    pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 28;
+    pub const VT_PROPERTIES: flatbuffers::VOffsetT = 30;
+    pub const VT_LODS: flatbuffers::VOffsetT = 32;
+    pub const VT_BOUNDS: flatbuffers::VOffsetT = 34;
+    pub const VT_BOUNDING_SPHERE: flatbuffers::VOffsetT = 36;
+    pub const VT_MATRIX: flatbuffers::VOffsetT = 38;
+    pub const VT_TRANSFORM_IS_LOSSY: flatbuffers::VOffsetT = 40;
+    pub const VT_PATH: flatbuffers::VOffsetT = 42;
+    pub const VT_INSTANCE_OF: flatbuffers::VOffsetT = 44;
+    pub const VT_INSTANCES: flatbuffers::VOffsetT = 46;
+    pub const VT_VARIANTS: flatbuffers::VOffsetT = 48;
+    pub const VT_MATERIAL: flatbuffers::VOffsetT = 50;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Mesh { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args MeshArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Mesh<'bldr>> {
+        let mut builder = MeshBuilder::new(_fbb);
+        builder.add_id(args.id);
+        if let Some(x) = args.material {
+            builder.add_material(x);
+        }
+        if let Some(x) = args.variants {
+            builder.add_variants(x);
+        }
+        if let Some(x) = args.instances {
+            builder.add_instances(x);
+        }
+        builder.add_instance_of(args.instance_of);
+        if let Some(x) = args.path {
+            builder.add_path(x);
+        }
+        if let Some(x) = args.matrix {
+            builder.add_matrix(x);
+        }
+        if let Some(x) = args.bounding_sphere {
+            builder.add_bounding_sphere(x);
+        }
+        if let Some(x) = args.bounds {
+            builder.add_bounds(x);
+        }
+        if let Some(x) = args.lods {
+            builder.add_lods(x);
+        }
+        if let Some(x) = args.properties {
+            builder.add_properties(x);
+        }
+        if let Some(x) = args.attributes {
+            builder.add_attributes(x);
+        }
+        if let Some(x) = args.layout_type {
+            builder.add_layout_type(x);
+        }
+        if let Some(x) = args.texture {
+            builder.add_texture(x);
+        }
+        if let Some(x) = args.skeleton {
+            builder.add_skeleton(x);
+        }
+        if let Some(x) = args.uvs {
+            builder.add_uvs(x);
+        }
+        if let Some(x) = args.animations {
+            builder.add_animations(x);
+        }
+        if let Some(x) = args.triangles {
+            builder.add_triangles(x);
+        }
+        if let Some(x) = args.default_morph_weights {
+            builder.add_default_morph_weights(x);
+        }
+        if let Some(x) = args.morph_targets {
+            builder.add_morph_targets(x);
+        }
+        if let Some(x) = args.points {
+            builder.add_points(x);
+        }
+        if let Some(x) = args.transform {
+            builder.add_transform(x);
+        }
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
+        builder.add_transform_is_lossy(args.transform_is_lossy);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'a str {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Mesh::VT_NAME, None)
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn id(&self) -> u64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u64>(Mesh::VT_ID, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn transform(&self) -> &'a Transform {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<Transform>(Mesh::VT_TRANSFORM, None)
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn points(&self) -> flatbuffers::Vector<'a, f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    Mesh::VT_POINTS,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn morph_targets(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MorphTargets<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MorphTargets>>,
+            >>(Mesh::VT_MORPH_TARGETS, None)
+        }
+    }
+    #[inline]
+    pub fn default_morph_weights(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    Mesh::VT_DEFAULT_MORPH_WEIGHTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn triangles(&self) -> flatbuffers::Vector<'a, u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    Mesh::VT_TRIANGLES,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn animations(
+        &self,
+    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet>>,
+                >>(Mesh::VT_ANIMATIONS, None)
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn uvs(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    Mesh::VT_UVS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn skeleton(&self) -> Option<Skeleton<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Skeleton>>(Mesh::VT_SKELETON, None)
+        }
+    }
+    #[inline]
+    pub fn texture(&self) -> Option<Image<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<Image>>(Mesh::VT_TEXTURE, None)
+        }
+    }
+    #[inline]
+    pub fn layout_type(&self) -> &'a LayoutType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<LayoutType>(Mesh::VT_LAYOUT_TYPE, None)
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn attributes(&self) -> flatbuffers::Vector<'a, f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
+                    Mesh::VT_ATTRIBUTES,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn properties(
+        &self,
+    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property>>,
+                >>(Mesh::VT_PROPERTIES, None)
+                .unwrap()
+        }
+    }
+    /// Simplified versions of the mesh going from the most to the least detailed
+    #[inline]
+    pub fn lods(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MeshLod<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MeshLod>>,
+            >>(Mesh::VT_LODS, None)
+        }
+    }
+    /// Bounds of the points in the mesh's own space
+    #[inline]
+    pub fn bounds(&self) -> Option<&'a BoundingBox> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<BoundingBox>(Mesh::VT_BOUNDS, None) }
+    }
+    #[inline]
+    pub fn bounding_sphere(&self) -> Option<&'a BoundingSphere> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<BoundingSphere>(Mesh::VT_BOUNDING_SPHERE, None)
+        }
+    }
+    /// The full local transform, `transform` can't hold shear or mirroring so use this when `transform_is_lossy`
+    #[inline]
+    pub fn matrix(&self) -> Option<&'a Matrix4> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Matrix4>(Mesh::VT_MATRIX, None) }
+    }
+    #[inline]
+    pub fn transform_is_lossy(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(Mesh::VT_TRANSFORM_IS_LOSSY, Some(false))
+                .unwrap()
+        }
+    }
+    /// Unique path of the node like `Scene/Armature/Body/0` (the last part is the primitive for meshes)
+    #[inline]
+    pub fn path(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Mesh::VT_PATH, None)
+        }
+    }
+    /// Index of the node that holds the geometry (points, triangles, uvs, attributes, morph targets, lods and texture)
+    /// when this mesh reuses it, -1 when the geometry is in this table
+    #[inline]
+    pub fn instance_of(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(Mesh::VT_INSTANCE_OF, Some(-1))
+                .unwrap()
+        }
+    }
+    /// EXT_mesh_gpu_instancing, the mesh is drawn once per transform (applied before the mesh transform)
+    #[inline]
+    pub fn instances(&self) -> Option<flatbuffers::Vector<'a, Transform>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<InterpolationTargets>(Animation::VT_INTERPOLATION, None)
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Transform>>>(
+                    Mesh::VT_INSTANCES,
+                    None,
+                )
         }
     }
+    /// Textures for the variants that change the material of this mesh, the others keep `texture`
     #[inline]
-    pub fn frames(
+    pub fn variants(
         &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationKeyFrames<'a>>>> {
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MaterialVariant<'a>>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationKeyFrames>>,
-            >>(Animation::VT_FRAMES, None)
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MaterialVariant>>,
+            >>(Mesh::VT_VARIANTS, None)
         }
     }
     #[inline]
-    pub fn duration(&self) -> f32 {
+    pub fn material(&self) -> Option<Material<'a>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(Animation::VT_DURATION, Some(0.0))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<Material>>(Mesh::VT_MATERIAL, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for Animation<'_> {
+impl flatbuffers::Verifiable for Mesh<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -2884,257 +6155,375 @@ impl flatbuffers::Verifiable for Animation<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<u64>("target", Self::VT_TARGET, false)?
-            .visit_field::<AnimationTargetTypes>("target_type", Self::VT_TARGET_TYPE, false)?
-            .visit_field::<InterpolationTargets>("interpolation", Self::VT_INTERPOLATION, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+            .visit_field::<u64>("id", Self::VT_ID, false)?
+            .visit_field::<Transform>("transform", Self::VT_TRANSFORM, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "points",
+                Self::VT_POINTS,
+                true,
+            )?
             .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AnimationKeyFrames>>,
-            >>("frames", Self::VT_FRAMES, false)?
-            .visit_field::<f32>("duration", Self::VT_DURATION, false)?
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MorphTargets>>,
+            >>("morph_targets", Self::VT_MORPH_TARGETS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "default_morph_weights",
+                Self::VT_DEFAULT_MORPH_WEIGHTS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "triangles",
+                Self::VT_TRIANGLES,
+                true,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AnimationSet>>,
+            >>("animations", Self::VT_ANIMATIONS, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "uvs",
+                Self::VT_UVS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<Skeleton>>(
+                "skeleton",
+                Self::VT_SKELETON,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<Image>>("texture", Self::VT_TEXTURE, false)?
+            .visit_field::<LayoutType>("layout_type", Self::VT_LAYOUT_TYPE, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
+                "attributes",
+                Self::VT_ATTRIBUTES,
+                true,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>,
+            >>("properties", Self::VT_PROPERTIES, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MeshLod>>,
+            >>("lods", Self::VT_LODS, false)?
+            .visit_field::<BoundingBox>("bounds", Self::VT_BOUNDS, false)?
+            .visit_field::<BoundingSphere>("bounding_sphere", Self::VT_BOUNDING_SPHERE, false)?
+            .visit_field::<Matrix4>("matrix", Self::VT_MATRIX, false)?
+            .visit_field::<bool>("transform_is_lossy", Self::VT_TRANSFORM_IS_LOSSY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, false)?
+            .visit_field::<i32>("instance_of", Self::VT_INSTANCE_OF, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Transform>>>(
+                "instances",
+                Self::VT_INSTANCES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MaterialVariant>>,
+            >>("variants", Self::VT_VARIANTS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Material>>(
+                "material",
+                Self::VT_MATERIAL,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct AnimationArgs<'a> {
-    pub target: u64,
-    pub target_type: AnimationTargetTypes,
-    pub interpolation: Option<&'a InterpolationTargets>,
-    pub frames: Option<
+pub struct MeshArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub id: u64,
+    pub transform: Option<&'a Transform>,
+    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub morph_targets: Option<
         flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationKeyFrames<'a>>>,
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MorphTargets<'a>>>,
         >,
     >,
-    pub duration: f32,
+    pub default_morph_weights: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub triangles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub animations: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>>,
+        >,
+    >,
+    pub uvs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub skeleton: Option<flatbuffers::WIPOffset<Skeleton<'a>>>,
+    pub texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
+    pub layout_type: Option<&'a LayoutType>,
+    pub attributes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub properties: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>>,
+    >,
+    pub lods: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MeshLod<'a>>>>,
+    >,
+    pub bounds: Option<&'a BoundingBox>,
+    pub bounding_sphere: Option<&'a BoundingSphere>,
+    pub matrix: Option<&'a Matrix4>,
+    pub transform_is_lossy: bool,
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub instance_of: i32,
+    pub instances: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Transform>>>,
+    pub variants: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MaterialVariant<'a>>>,
+        >,
+    >,
+    pub material: Option<flatbuffers::WIPOffset<Material<'a>>>,
 }
-impl<'a> Default for AnimationArgs<'a> {
+impl<'a> Default for MeshArgs<'a> {
     #[inline]
     fn default() -> Self {
-        AnimationArgs {
-            target: 0,
-            target_type: AnimationTargetTypes::Object,
-            interpolation: None,
-            frames: None,
-            duration: 0.0,
+        MeshArgs {
+            name: None, // required field
+            id: 0,
+            transform: None, // required field
+            points: None,    // required field
+            morph_targets: None,
+            default_morph_weights: None,
+            triangles: None,  // required field
+            animations: None, // required field
+            uvs: None,
+            skeleton: None,
+            texture: None,
+            layout_type: None, // required field
+            attributes: None,  // required field
+            properties: None,  // required field
+            lods: None,
+            bounds: None,
+            bounding_sphere: None,
+            matrix: None,
+            transform_is_lossy: false,
+            path: None,
+            instance_of: -1,
+            instances: None,
+            variants: None,
+            material: None,
         }
     }
 }
 
-pub struct AnimationBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MeshBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AnimationBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MeshBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_target(&mut self, target: u64) {
-        self.fbb_.push_slot::<u64>(Animation::VT_TARGET, target, 0);
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_NAME, name);
     }
     #[inline]
-    pub fn add_target_type(&mut self, target_type: AnimationTargetTypes) {
-        self.fbb_.push_slot::<AnimationTargetTypes>(
-            Animation::VT_TARGET_TYPE,
-            target_type,
-            AnimationTargetTypes::Object,
+    pub fn add_id(&mut self, id: u64) {
+        self.fbb_.push_slot::<u64>(Mesh::VT_ID, id, 0);
+    }
+    #[inline]
+    pub fn add_transform(&mut self, transform: &Transform) {
+        self.fbb_
+            .push_slot_always::<&Transform>(Mesh::VT_TRANSFORM, transform);
+    }
+    #[inline]
+    pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_POINTS, points);
+    }
+    #[inline]
+    pub fn add_morph_targets(
+        &mut self,
+        morph_targets: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MorphTargets<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_MORPH_TARGETS, morph_targets);
+    }
+    #[inline]
+    pub fn add_default_morph_weights(
+        &mut self,
+        default_morph_weights: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            Mesh::VT_DEFAULT_MORPH_WEIGHTS,
+            default_morph_weights,
         );
     }
     #[inline]
-    pub fn add_interpolation(&mut self, interpolation: &InterpolationTargets) {
+    pub fn add_triangles(
+        &mut self,
+        triangles: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
         self.fbb_
-            .push_slot_always::<&InterpolationTargets>(Animation::VT_INTERPOLATION, interpolation);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_TRIANGLES, triangles);
     }
     #[inline]
-    pub fn add_frames(
+    pub fn add_animations(
         &mut self,
-        frames: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AnimationKeyFrames<'b>>>,
+        animations: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AnimationSet<'b>>>,
         >,
     ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Animation::VT_FRAMES, frames);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_ANIMATIONS, animations);
     }
     #[inline]
-    pub fn add_duration(&mut self, duration: f32) {
+    pub fn add_uvs(&mut self, uvs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
         self.fbb_
-            .push_slot::<f32>(Animation::VT_DURATION, duration, 0.0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_UVS, uvs);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AnimationBuilder<'a, 'b, A> {
-        let start = _fbb.start_table();
-        AnimationBuilder {
-            fbb_: _fbb,
-            start_: start,
-        }
+    pub fn add_skeleton(&mut self, skeleton: flatbuffers::WIPOffset<Skeleton<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<Skeleton>>(Mesh::VT_SKELETON, skeleton);
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Animation<'a>> {
-        let o = self.fbb_.end_table(self.start_);
-        flatbuffers::WIPOffset::new(o.value())
+    pub fn add_texture(&mut self, texture: flatbuffers::WIPOffset<Image<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<Image>>(Mesh::VT_TEXTURE, texture);
     }
-}
-
-impl core::fmt::Debug for Animation<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Animation");
-        ds.field("target", &self.target());
-        ds.field("target_type", &self.target_type());
-        ds.field("interpolation", &self.interpolation());
-        ds.field("frames", &self.frames());
-        ds.field("duration", &self.duration());
-        ds.finish()
+    #[inline]
+    pub fn add_layout_type(&mut self, layout_type: &LayoutType) {
+        self.fbb_
+            .push_slot_always::<&LayoutType>(Mesh::VT_LAYOUT_TYPE, layout_type);
     }
-}
-pub enum MorphTargetsOffset {}
-#[derive(Copy, Clone, PartialEq)]
-
-pub struct MorphTargets<'a> {
-    pub _tab: flatbuffers::Table<'a>,
-}
-
-impl<'a> flatbuffers::Follow<'a> for MorphTargets<'a> {
-    type Inner = MorphTargets<'a>;
     #[inline]
-    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
-        Self {
-            _tab: flatbuffers::Table::new(buf, loc),
-        }
+    pub fn add_attributes(
+        &mut self,
+        attributes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_ATTRIBUTES, attributes);
+    }
+    #[inline]
+    pub fn add_properties(
+        &mut self,
+        properties: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Property<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_PROPERTIES, properties);
+    }
+    #[inline]
+    pub fn add_lods(
+        &mut self,
+        lods: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MeshLod<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_LODS, lods);
     }
-}
-
-impl<'a> MorphTargets<'a> {
-    pub const VT_NAME: flatbuffers::VOffsetT = 4;
-    pub const VT_TRANSLATION: flatbuffers::VOffsetT = 6;
-
     #[inline]
-    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MorphTargets { _tab: table }
+    pub fn add_bounds(&mut self, bounds: &BoundingBox) {
+        self.fbb_
+            .push_slot_always::<&BoundingBox>(Mesh::VT_BOUNDS, bounds);
     }
-    #[allow(unused_mut)]
-    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
-        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args MorphTargetsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<MorphTargets<'bldr>> {
-        let mut builder = MorphTargetsBuilder::new(_fbb);
-        if let Some(x) = args.translation {
-            builder.add_translation(x);
-        }
-        if let Some(x) = args.name {
-            builder.add_name(x);
-        }
-        builder.finish()
+    #[inline]
+    pub fn add_bounding_sphere(&mut self, bounding_sphere: &BoundingSphere) {
+        self.fbb_
+            .push_slot_always::<&BoundingSphere>(Mesh::VT_BOUNDING_SPHERE, bounding_sphere);
     }
-
     #[inline]
-    pub fn name(&self) -> &'a str {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(MorphTargets::VT_NAME, None)
-                .unwrap()
-        }
+    pub fn add_matrix(&mut self, matrix: &Matrix4) {
+        self.fbb_
+            .push_slot_always::<&Matrix4>(Mesh::VT_MATRIX, matrix);
     }
     #[inline]
-    pub fn translation(&self) -> Option<flatbuffers::Vector<'a, Vector3>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Vector3>>>(
-                    MorphTargets::VT_TRANSLATION,
-                    None,
-                )
-        }
+    pub fn add_transform_is_lossy(&mut self, transform_is_lossy: bool) {
+        self.fbb_
+            .push_slot::<bool>(Mesh::VT_TRANSFORM_IS_LOSSY, transform_is_lossy, false);
     }
-}
-
-impl flatbuffers::Verifiable for MorphTargets<'_> {
     #[inline]
-    fn run_verifier(
-        v: &mut flatbuffers::Verifier,
-        pos: usize,
-    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
-        use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Vector3>>>(
-                "translation",
-                Self::VT_TRANSLATION,
-                false,
-            )?
-            .finish();
-        Ok(())
+    pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_PATH, path);
     }
-}
-pub struct MorphTargetsArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub translation: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Vector3>>>,
-}
-impl<'a> Default for MorphTargetsArgs<'a> {
     #[inline]
-    fn default() -> Self {
-        MorphTargetsArgs {
-            name: None, // required field
-            translation: None,
-        }
+    pub fn add_instance_of(&mut self, instance_of: i32) {
+        self.fbb_
+            .push_slot::<i32>(Mesh::VT_INSTANCE_OF, instance_of, -1);
     }
-}
-
-pub struct MorphTargetsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
-    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
-}
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MorphTargetsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+    pub fn add_instances(
+        &mut self,
+        instances: flatbuffers::WIPOffset<flatbuffers::Vector<'b, Transform>>,
+    ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(MorphTargets::VT_NAME, name);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_INSTANCES, instances);
     }
     #[inline]
-    pub fn add_translation(
+    pub fn add_variants(
         &mut self,
-        translation: flatbuffers::WIPOffset<flatbuffers::Vector<'b, Vector3>>,
+        variants: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MaterialVariant<'b>>>,
+        >,
     ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            MorphTargets::VT_TRANSLATION,
-            translation,
-        );
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_VARIANTS, variants);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> MorphTargetsBuilder<'a, 'b, A> {
+    pub fn add_material(&mut self, material: flatbuffers::WIPOffset<Material<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<Material>>(Mesh::VT_MATERIAL, material);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MeshBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        MorphTargetsBuilder {
+        MeshBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<MorphTargets<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Mesh<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_.required(o, MorphTargets::VT_NAME, "name");
+        self.fbb_.required(o, Mesh::VT_NAME, "name");
+        self.fbb_.required(o, Mesh::VT_TRANSFORM, "transform");
+        self.fbb_.required(o, Mesh::VT_POINTS, "points");
+        self.fbb_.required(o, Mesh::VT_TRIANGLES, "triangles");
+        self.fbb_.required(o, Mesh::VT_ANIMATIONS, "animations");
+        self.fbb_.required(o, Mesh::VT_LAYOUT_TYPE, "layout_type");
+        self.fbb_.required(o, Mesh::VT_ATTRIBUTES, "attributes");
+        self.fbb_.required(o, Mesh::VT_PROPERTIES, "properties");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for MorphTargets<'_> {
+impl core::fmt::Debug for Mesh<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("MorphTargets");
+        let mut ds = f.debug_struct("Mesh");
         ds.field("name", &self.name());
-        ds.field("translation", &self.translation());
+        ds.field("id", &self.id());
+        ds.field("transform", &self.transform());
+        ds.field("points", &self.points());
+        ds.field("morph_targets", &self.morph_targets());
+        ds.field("default_morph_weights", &self.default_morph_weights());
+        ds.field("triangles", &self.triangles());
+        ds.field("animations", &self.animations());
+        ds.field("uvs", &self.uvs());
+        ds.field("skeleton", &self.skeleton());
+        ds.field("texture", &self.texture());
+        ds.field("layout_type", &self.layout_type());
+        ds.field("attributes", &self.attributes());
+        ds.field("properties", &self.properties());
+        ds.field("lods", &self.lods());
+        ds.field("bounds", &self.bounds());
+        ds.field("bounding_sphere", &self.bounding_sphere());
+        ds.field("matrix", &self.matrix());
+        ds.field("transform_is_lossy", &self.transform_is_lossy());
+        ds.field("path", &self.path());
+        ds.field("instance_of", &self.instance_of());
+        ds.field("instances", &self.instances());
+        ds.field("variants", &self.variants());
+        ds.field("material", &self.material());
         ds.finish()
     }
 }
-pub enum SkeletonOffset {}
+pub enum EmptiesOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Skeleton<'a> {
+pub struct Empties<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Skeleton<'a> {
-    type Inner = Skeleton<'a>;
+impl<'a> flatbuffers::Follow<'a> for Empties<'a> {
+    type Inner = Empties<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3143,118 +6532,141 @@ impl<'a> flatbuffers::Follow<'a> for Skeleton<'a> {
     }
 }
 
-impl<'a> Skeleton<'a> {
+impl<'a> Empties<'a> {
     pub const VT_ID: flatbuffers::VOffsetT = 4;
     pub const VT_NAME: flatbuffers::VOffsetT = 6;
-    pub const VT_ROOT_INDEX: flatbuffers::VOffsetT = 8;
-    pub const VT_INVERSE_BIND_MATRIXES: flatbuffers::VOffsetT = 10;
-    pub const VT_BONES: flatbuffers::VOffsetT = 12;
-    pub const VT_PROPERTIES: flatbuffers::VOffsetT = 14;
+    pub const VT_TRANSFORM: flatbuffers::VOffsetT = 8;
+    pub const VT_ANIMATIONS: flatbuffers::VOffsetT = 10;
+    pub const VT_PROPERTIES: flatbuffers::VOffsetT = 12;
+    pub const VT_MATRIX: flatbuffers::VOffsetT = 14;
+    pub const VT_TRANSFORM_IS_LOSSY: flatbuffers::VOffsetT = 16;
+    pub const VT_PATH: flatbuffers::VOffsetT = 18;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Skeleton { _tab: table }
+        Empties { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args SkeletonArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Skeleton<'bldr>> {
-        let mut builder = SkeletonBuilder::new(_fbb);
+        args: &'args EmptiesArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Empties<'bldr>> {
+        let mut builder = EmptiesBuilder::new(_fbb);
         builder.add_id(args.id);
+        if let Some(x) = args.path {
+            builder.add_path(x);
+        }
+        if let Some(x) = args.matrix {
+            builder.add_matrix(x);
+        }
         if let Some(x) = args.properties {
             builder.add_properties(x);
         }
-        if let Some(x) = args.bones {
-            builder.add_bones(x);
-        }
-        if let Some(x) = args.inverse_bind_matrixes {
-            builder.add_inverse_bind_matrixes(x);
+        if let Some(x) = args.animations {
+            builder.add_animations(x);
         }
-        if let Some(x) = args.root_index {
-            builder.add_root_index(x);
+        if let Some(x) = args.transform {
+            builder.add_transform(x);
         }
         if let Some(x) = args.name {
             builder.add_name(x);
         }
+        builder.add_transform_is_lossy(args.transform_is_lossy);
         builder.finish()
     }
 
-    /// The internal index from the json file
     #[inline]
     pub fn id(&self) -> u64 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<u64>(Skeleton::VT_ID, Some(0)).unwrap() }
+        unsafe { self._tab.get::<u64>(Empties::VT_ID, Some(0)).unwrap() }
     }
-    /// User-provided name
     #[inline]
-    pub fn name(&self) -> Option<&'a str> {
+    pub fn name(&self) -> &'a str {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(Skeleton::VT_NAME, None)
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Empties::VT_NAME, None)
+                .unwrap()
         }
     }
-    /// The root of the skeleton tree
     #[inline]
-    pub fn root_index(&self) -> Option<&'a GlobalNodeIdentifier> {
+    pub fn transform(&self) -> &'a Transform {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<GlobalNodeIdentifier>(Skeleton::VT_ROOT_INDEX, None)
+                .get::<Transform>(Empties::VT_TRANSFORM, None)
+                .unwrap()
         }
     }
-    /// Matrixes used to bring coordinates being skinned into the same space as the joint
     #[inline]
-    pub fn inverse_bind_matrixes(&self) -> Option<flatbuffers::Vector<'a, Matrix4>> {
+    pub fn animations(
+        &self,
+    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Matrix4>>>(
-                    Skeleton::VT_INVERSE_BIND_MATRIXES,
-                    None,
-                )
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet>>,
+                >>(Empties::VT_ANIMATIONS, None)
+                .unwrap()
         }
     }
-    /// List of bones assoicated with the skeleton
     #[inline]
-    pub fn bones(&self) -> Option<flatbuffers::Vector<'a, GlobalNodeIdentifier>> {
+    pub fn properties(
+        &self,
+    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, GlobalNodeIdentifier>>>(
-                    Skeleton::VT_BONES,
-                    None,
-                )
+                .get::<flatbuffers::ForwardsUOffset<
+                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property>>,
+                >>(Empties::VT_PROPERTIES, None)
+                .unwrap()
         }
     }
-    /// Extra user data
+    /// The full local transform, `transform` can't hold shear or mirroring so use this when `transform_is_lossy`
     #[inline]
-    pub fn properties(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>> {
+    pub fn matrix(&self) -> Option<&'a Matrix4> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<Matrix4>(Empties::VT_MATRIX, None) }
+    }
+    #[inline]
+    pub fn transform_is_lossy(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property>>,
-            >>(Skeleton::VT_PROPERTIES, None)
+            self._tab
+                .get::<bool>(Empties::VT_TRANSFORM_IS_LOSSY, Some(false))
+                .unwrap()
+        }
+    }
+    /// Unique path of the node like `Scene/Armature/Body/0` (the last part is the primitive for meshes)
+    #[inline]
+    pub fn path(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Empties::VT_PATH, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for Skeleton<'_> {
+impl flatbuffers::Verifiable for Empties<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3262,76 +6674,82 @@ impl flatbuffers::Verifiable for Skeleton<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-     .visit_field::<u64>("id", Self::VT_ID, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
-     .visit_field::<GlobalNodeIdentifier>("root_index", Self::VT_ROOT_INDEX, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Matrix4>>>("inverse_bind_matrixes", Self::VT_INVERSE_BIND_MATRIXES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, GlobalNodeIdentifier>>>("bones", Self::VT_BONES, false)?
-     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>>>("properties", Self::VT_PROPERTIES, false)?
-     .finish();
+            .visit_field::<u64>("id", Self::VT_ID, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+            .visit_field::<Transform>("transform", Self::VT_TRANSFORM, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AnimationSet>>,
+            >>("animations", Self::VT_ANIMATIONS, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>,
+            >>("properties", Self::VT_PROPERTIES, true)?
+            .visit_field::<Matrix4>("matrix", Self::VT_MATRIX, false)?
+            .visit_field::<bool>("transform_is_lossy", Self::VT_TRANSFORM_IS_LOSSY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("path", Self::VT_PATH, false)?
+            .finish();
         Ok(())
     }
 }
-pub struct SkeletonArgs<'a> {
+pub struct EmptiesArgs<'a> {
     pub id: u64,
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub root_index: Option<&'a GlobalNodeIdentifier>,
-    pub inverse_bind_matrixes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Matrix4>>>,
-    pub bones: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, GlobalNodeIdentifier>>>,
+    pub transform: Option<&'a Transform>,
+    pub animations: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>>,
+        >,
+    >,
     pub properties: Option<
         flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>>,
     >,
+    pub matrix: Option<&'a Matrix4>,
+    pub transform_is_lossy: bool,
+    pub path: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for SkeletonArgs<'a> {
+impl<'a> Default for EmptiesArgs<'a> {
     #[inline]
     fn default() -> Self {
-        SkeletonArgs {
+        EmptiesArgs {
             id: 0,
-            name: None,
-            root_index: None,
-            inverse_bind_matrixes: None,
-            bones: None,
-            properties: None,
+            name: None,       // required field
+            transform: None,  // required field
+            animations: None, // required field
+            properties: None, // required field
+            matrix: None,
+            transform_is_lossy: false,
+            path: None,
         }
     }
 }
 
-pub struct SkeletonBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct EmptiesBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SkeletonBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EmptiesBuilder<'a, 'b, A> {
     #[inline]
     pub fn add_id(&mut self, id: u64) {
-        self.fbb_.push_slot::<u64>(Skeleton::VT_ID, id, 0);
+        self.fbb_.push_slot::<u64>(Empties::VT_ID, id, 0);
     }
     #[inline]
     pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Skeleton::VT_NAME, name);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Empties::VT_NAME, name);
     }
     #[inline]
-    pub fn add_root_index(&mut self, root_index: &GlobalNodeIdentifier) {
+    pub fn add_transform(&mut self, transform: &Transform) {
         self.fbb_
-            .push_slot_always::<&GlobalNodeIdentifier>(Skeleton::VT_ROOT_INDEX, root_index);
-    }
-    #[inline]
-    pub fn add_inverse_bind_matrixes(
-        &mut self,
-        inverse_bind_matrixes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, Matrix4>>,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            Skeleton::VT_INVERSE_BIND_MATRIXES,
-            inverse_bind_matrixes,
-        );
+            .push_slot_always::<&Transform>(Empties::VT_TRANSFORM, transform);
     }
     #[inline]
-    pub fn add_bones(
+    pub fn add_animations(
         &mut self,
-        bones: flatbuffers::WIPOffset<flatbuffers::Vector<'b, GlobalNodeIdentifier>>,
+        animations: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AnimationSet<'b>>>,
+        >,
     ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Skeleton::VT_BONES, bones);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Empties::VT_ANIMATIONS, animations);
     }
     #[inline]
     pub fn add_properties(
@@ -3341,44 +6759,65 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SkeletonBuilder<'a, 'b, A> {
         >,
     ) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Skeleton::VT_PROPERTIES, properties);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Empties::VT_PROPERTIES, properties);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SkeletonBuilder<'a, 'b, A> {
+    pub fn add_matrix(&mut self, matrix: &Matrix4) {
+        self.fbb_
+            .push_slot_always::<&Matrix4>(Empties::VT_MATRIX, matrix);
+    }
+    #[inline]
+    pub fn add_transform_is_lossy(&mut self, transform_is_lossy: bool) {
+        self.fbb_
+            .push_slot::<bool>(Empties::VT_TRANSFORM_IS_LOSSY, transform_is_lossy, false);
+    }
+    #[inline]
+    pub fn add_path(&mut self, path: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Empties::VT_PATH, path);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EmptiesBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        SkeletonBuilder {
+        EmptiesBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Skeleton<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Empties<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, Empties::VT_NAME, "name");
+        self.fbb_.required(o, Empties::VT_TRANSFORM, "transform");
+        self.fbb_.required(o, Empties::VT_ANIMATIONS, "animations");
+        self.fbb_.required(o, Empties::VT_PROPERTIES, "properties");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Skeleton<'_> {
+impl core::fmt::Debug for Empties<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Skeleton");
+        let mut ds = f.debug_struct("Empties");
         ds.field("id", &self.id());
         ds.field("name", &self.name());
-        ds.field("root_index", &self.root_index());
-        ds.field("inverse_bind_matrixes", &self.inverse_bind_matrixes());
-        ds.field("bones", &self.bones());
+        ds.field("transform", &self.transform());
+        ds.field("animations", &self.animations());
         ds.field("properties", &self.properties());
+        ds.field("matrix", &self.matrix());
+        ds.field("transform_is_lossy", &self.transform_is_lossy());
+        ds.field("path", &self.path());
         ds.finish()
     }
 }
-pub enum MeshOffset {}
+pub enum NodeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Mesh<'a> {
+pub struct Node<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Mesh<'a> {
-    type Inner = Mesh<'a>;
+impl<'a> flatbuffers::Follow<'a> for Node<'a> {
+    type Inner = Node<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3387,249 +6826,119 @@ impl<'a> flatbuffers::Follow<'a> for Mesh<'a> {
     }
 }
 
-impl<'a> Mesh<'a> {
-    pub const VT_NAME: flatbuffers::VOffsetT = 4;
-    pub const VT_ID: flatbuffers::VOffsetT = 6;
-    pub const VT_TRANSFORM: flatbuffers::VOffsetT = 8;
-    pub const VT_POINTS: flatbuffers::VOffsetT = 10;
-    pub const VT_MORPH_TARGETS: flatbuffers::VOffsetT = 12;
-    pub const VT_DEFAULT_MORPH_WEIGHTS: flatbuffers::VOffsetT = 14;
-    pub const VT_TRIANGLES: flatbuffers::VOffsetT = 16;
-    pub const VT_ANIMATIONS: flatbuffers::VOffsetT = 18;
-    pub const VT_UVS: flatbuffers::VOffsetT = 20;
-    pub const VT_SKELETON: flatbuffers::VOffsetT = 22;
-    pub const VT_TEXTURE: flatbuffers::VOffsetT = 24;
-    pub const VT_LAYOUT_TYPE: flatbuffers::VOffsetT = 26;
-    pub const VT_ATTRIBUTES: flatbuffers::VOffsetT = 28;
-    pub const VT_PROPERTIES: flatbuffers::VOffsetT = 30;
+impl<'a> Node<'a> {
+    pub const VT_CHILDREN: flatbuffers::VOffsetT = 4;
+    pub const VT_OBJECT_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_OBJECT: flatbuffers::VOffsetT = 8;
+    pub const VT_PARENT: flatbuffers::VOffsetT = 10;
+    pub const VT_WORLD_MATRIX: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Mesh { _tab: table }
+        Node { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args MeshArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Mesh<'bldr>> {
-        let mut builder = MeshBuilder::new(_fbb);
-        builder.add_id(args.id);
-        if let Some(x) = args.properties {
-            builder.add_properties(x);
-        }
-        if let Some(x) = args.attributes {
-            builder.add_attributes(x);
-        }
-        if let Some(x) = args.layout_type {
-            builder.add_layout_type(x);
-        }
-        if let Some(x) = args.texture {
-            builder.add_texture(x);
-        }
-        if let Some(x) = args.skeleton {
-            builder.add_skeleton(x);
-        }
-        if let Some(x) = args.uvs {
-            builder.add_uvs(x);
-        }
-        if let Some(x) = args.animations {
-            builder.add_animations(x);
-        }
-        if let Some(x) = args.triangles {
-            builder.add_triangles(x);
-        }
-        if let Some(x) = args.default_morph_weights {
-            builder.add_default_morph_weights(x);
-        }
-        if let Some(x) = args.morph_targets {
-            builder.add_morph_targets(x);
-        }
-        if let Some(x) = args.points {
-            builder.add_points(x);
-        }
-        if let Some(x) = args.transform {
-            builder.add_transform(x);
-        }
-        if let Some(x) = args.name {
-            builder.add_name(x);
-        }
-        builder.finish()
-    }
-
-    #[inline]
-    pub fn name(&self) -> &'a str {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(Mesh::VT_NAME, None)
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn id(&self) -> u64 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<u64>(Mesh::VT_ID, Some(0)).unwrap() }
-    }
-    #[inline]
-    pub fn transform(&self) -> &'a Transform {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<Transform>(Mesh::VT_TRANSFORM, None)
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn points(&self) -> flatbuffers::Vector<'a, f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                    Mesh::VT_POINTS,
-                    None,
-                )
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn morph_targets(
-        &self,
-    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MorphTargets<'a>>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab.get::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MorphTargets>>,
-            >>(Mesh::VT_MORPH_TARGETS, None)
-        }
-    }
-    #[inline]
-    pub fn default_morph_weights(&self) -> Option<flatbuffers::Vector<'a, f32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                    Mesh::VT_DEFAULT_MORPH_WEIGHTS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn triangles(&self) -> flatbuffers::Vector<'a, u32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    Mesh::VT_TRIANGLES,
-                    None,
-                )
-                .unwrap()
+        args: &'args NodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Node<'bldr>> {
+        let mut builder = NodeBuilder::new(_fbb);
+        if let Some(x) = args.world_matrix {
+            builder.add_world_matrix(x);
         }
-    }
-    #[inline]
-    pub fn animations(
-        &self,
-    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<
-                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet>>,
-                >>(Mesh::VT_ANIMATIONS, None)
-                .unwrap()
+        builder.add_parent(args.parent);
+        if let Some(x) = args.object {
+            builder.add_object(x);
+        }
+        if let Some(x) = args.children {
+            builder.add_children(x);
         }
+        builder.add_object_type(args.object_type);
+        builder.finish()
     }
+
     #[inline]
-    pub fn uvs(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    pub fn children(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                    Mesh::VT_UVS,
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    Node::VT_CHILDREN,
                     None,
                 )
+                .unwrap()
         }
     }
     #[inline]
-    pub fn skeleton(&self) -> Option<Skeleton<'a>> {
+    pub fn object_type(&self) -> Object {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<Skeleton>>(Mesh::VT_SKELETON, None)
+                .get::<Object>(Node::VT_OBJECT_TYPE, Some(Object::NONE))
+                .unwrap()
         }
     }
     #[inline]
-    pub fn texture(&self) -> Option<Image<'a>> {
+    pub fn object(&self) -> flatbuffers::Table<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<Image>>(Mesh::VT_TEXTURE, None)
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Node::VT_OBJECT, None)
+                .unwrap()
         }
     }
+    /// Index of the parent node, -1 for root nodes
     #[inline]
-    pub fn layout_type(&self) -> &'a LayoutType {
+    pub fn parent(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<LayoutType>(Mesh::VT_LAYOUT_TYPE, None)
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(Node::VT_PARENT, Some(-1)).unwrap() }
     }
+    /// Rest pose matrix of the node in scene space (all the parents applied)
     #[inline]
-    pub fn attributes(&self) -> flatbuffers::Vector<'a, f32> {
+    pub fn world_matrix(&self) -> Option<&'a Matrix4> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(
-                    Mesh::VT_ATTRIBUTES,
-                    None,
-                )
-                .unwrap()
+        unsafe { self._tab.get::<Matrix4>(Node::VT_WORLD_MATRIX, None) }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn object_as_mesh(&self) -> Option<Mesh<'a>> {
+        if self.object_type() == Object::Mesh {
+            let u = self.object();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { Mesh::init_from_table(u) })
+        } else {
+            None
         }
     }
+
     #[inline]
-    pub fn properties(
-        &self,
-    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<
-                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property>>,
-                >>(Mesh::VT_PROPERTIES, None)
-                .unwrap()
+    #[allow(non_snake_case)]
+    pub fn object_as_empties(&self) -> Option<Empties<'a>> {
+        if self.object_type() == Object::Empties {
+            let u = self.object();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { Empties::init_from_table(u) })
+        } else {
+            None
         }
     }
 }
 
-impl flatbuffers::Verifiable for Mesh<'_> {
+impl flatbuffers::Verifiable for Node<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3637,254 +6946,148 @@ impl flatbuffers::Verifiable for Mesh<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
-            .visit_field::<u64>("id", Self::VT_ID, false)?
-            .visit_field::<Transform>("transform", Self::VT_TRANSFORM, true)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
-                "points",
-                Self::VT_POINTS,
-                true,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<MorphTargets>>,
-            >>("morph_targets", Self::VT_MORPH_TARGETS, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
-                "default_morph_weights",
-                Self::VT_DEFAULT_MORPH_WEIGHTS,
-                false,
-            )?
             .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "triangles",
-                Self::VT_TRIANGLES,
+                "children",
+                Self::VT_CHILDREN,
                 true,
             )?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AnimationSet>>,
-            >>("animations", Self::VT_ANIMATIONS, true)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
-                "uvs",
-                Self::VT_UVS,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<Skeleton>>(
-                "skeleton",
-                Self::VT_SKELETON,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<Image>>("texture", Self::VT_TEXTURE, false)?
-            .visit_field::<LayoutType>("layout_type", Self::VT_LAYOUT_TYPE, true)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>(
-                "attributes",
-                Self::VT_ATTRIBUTES,
+            .visit_union::<Object, _>(
+                "object_type",
+                Self::VT_OBJECT_TYPE,
+                "object",
+                Self::VT_OBJECT,
                 true,
+                |key, v, pos| match key {
+                    Object::Mesh => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Mesh>>(
+                        "Object::Mesh",
+                        pos,
+                    ),
+                    Object::Empties => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<Empties>>(
+                            "Object::Empties",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
             )?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>,
-            >>("properties", Self::VT_PROPERTIES, true)?
+            .visit_field::<i32>("parent", Self::VT_PARENT, false)?
+            .visit_field::<Matrix4>("world_matrix", Self::VT_WORLD_MATRIX, false)?
             .finish();
         Ok(())
     }
 }
-pub struct MeshArgs<'a> {
-    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub id: u64,
-    pub transform: Option<&'a Transform>,
-    pub points: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub morph_targets: Option<
-        flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<MorphTargets<'a>>>,
-        >,
-    >,
-    pub default_morph_weights: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub triangles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub animations: Option<
-        flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>>,
-        >,
-    >,
-    pub uvs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub skeleton: Option<flatbuffers::WIPOffset<Skeleton<'a>>>,
-    pub texture: Option<flatbuffers::WIPOffset<Image<'a>>>,
-    pub layout_type: Option<&'a LayoutType>,
-    pub attributes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
-    pub properties: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>>,
-    >,
+pub struct NodeArgs<'a> {
+    pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub object_type: Object,
+    pub object: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub parent: i32,
+    pub world_matrix: Option<&'a Matrix4>,
 }
-impl<'a> Default for MeshArgs<'a> {
+impl<'a> Default for NodeArgs<'a> {
     #[inline]
     fn default() -> Self {
-        MeshArgs {
-            name: None, // required field
-            id: 0,
-            transform: None, // required field
-            points: None,    // required field
-            morph_targets: None,
-            default_morph_weights: None,
-            triangles: None,  // required field
-            animations: None, // required field
-            uvs: None,
-            skeleton: None,
-            texture: None,
-            layout_type: None, // required field
-            attributes: None,  // required field
-            properties: None,  // required field
+        NodeArgs {
+            children: None, // required field
+            object_type: Object::NONE,
+            object: None, // required field
+            parent: -1,
+            world_matrix: None,
         }
     }
 }
 
-pub struct MeshBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct NodeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MeshBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_NAME, name);
-    }
-    #[inline]
-    pub fn add_id(&mut self, id: u64) {
-        self.fbb_.push_slot::<u64>(Mesh::VT_ID, id, 0);
-    }
-    #[inline]
-    pub fn add_transform(&mut self, transform: &Transform) {
-        self.fbb_
-            .push_slot_always::<&Transform>(Mesh::VT_TRANSFORM, transform);
-    }
-    #[inline]
-    pub fn add_points(&mut self, points: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_POINTS, points);
-    }
-    #[inline]
-    pub fn add_morph_targets(
-        &mut self,
-        morph_targets: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<MorphTargets<'b>>>,
-        >,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_MORPH_TARGETS, morph_targets);
-    }
-    #[inline]
-    pub fn add_default_morph_weights(
-        &mut self,
-        default_morph_weights: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            Mesh::VT_DEFAULT_MORPH_WEIGHTS,
-            default_morph_weights,
-        );
-    }
-    #[inline]
-    pub fn add_triangles(
-        &mut self,
-        triangles: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_TRIANGLES, triangles);
-    }
-    #[inline]
-    pub fn add_animations(
-        &mut self,
-        animations: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AnimationSet<'b>>>,
-        >,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_ANIMATIONS, animations);
-    }
-    #[inline]
-    pub fn add_uvs(&mut self, uvs: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_UVS, uvs);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NodeBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_skeleton(&mut self, skeleton: flatbuffers::WIPOffset<Skeleton<'b>>) {
+    pub fn add_children(&mut self, children: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<Skeleton>>(Mesh::VT_SKELETON, skeleton);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_CHILDREN, children);
     }
     #[inline]
-    pub fn add_texture(&mut self, texture: flatbuffers::WIPOffset<Image<'b>>) {
+    pub fn add_object_type(&mut self, object_type: Object) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<Image>>(Mesh::VT_TEXTURE, texture);
+            .push_slot::<Object>(Node::VT_OBJECT_TYPE, object_type, Object::NONE);
     }
     #[inline]
-    pub fn add_layout_type(&mut self, layout_type: &LayoutType) {
+    pub fn add_object(&mut self, object: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
         self.fbb_
-            .push_slot_always::<&LayoutType>(Mesh::VT_LAYOUT_TYPE, layout_type);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_OBJECT, object);
     }
     #[inline]
-    pub fn add_attributes(
-        &mut self,
-        attributes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, f32>>,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_ATTRIBUTES, attributes);
+    pub fn add_parent(&mut self, parent: i32) {
+        self.fbb_.push_slot::<i32>(Node::VT_PARENT, parent, -1);
     }
     #[inline]
-    pub fn add_properties(
-        &mut self,
-        properties: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Property<'b>>>,
-        >,
-    ) {
+    pub fn add_world_matrix(&mut self, world_matrix: &Matrix4) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Mesh::VT_PROPERTIES, properties);
+            .push_slot_always::<&Matrix4>(Node::VT_WORLD_MATRIX, world_matrix);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MeshBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> NodeBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        MeshBuilder {
+        NodeBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Mesh<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Node<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_.required(o, Mesh::VT_NAME, "name");
-        self.fbb_.required(o, Mesh::VT_TRANSFORM, "transform");
-        self.fbb_.required(o, Mesh::VT_POINTS, "points");
-        self.fbb_.required(o, Mesh::VT_TRIANGLES, "triangles");
-        self.fbb_.required(o, Mesh::VT_ANIMATIONS, "animations");
-        self.fbb_.required(o, Mesh::VT_LAYOUT_TYPE, "layout_type");
-        self.fbb_.required(o, Mesh::VT_ATTRIBUTES, "attributes");
-        self.fbb_.required(o, Mesh::VT_PROPERTIES, "properties");
+        self.fbb_.required(o, Node::VT_CHILDREN, "children");
+        self.fbb_.required(o, Node::VT_OBJECT, "object");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Mesh<'_> {
+impl core::fmt::Debug for Node<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Mesh");
-        ds.field("name", &self.name());
-        ds.field("id", &self.id());
-        ds.field("transform", &self.transform());
-        ds.field("points", &self.points());
-        ds.field("morph_targets", &self.morph_targets());
-        ds.field("default_morph_weights", &self.default_morph_weights());
-        ds.field("triangles", &self.triangles());
-        ds.field("animations", &self.animations());
-        ds.field("uvs", &self.uvs());
-        ds.field("skeleton", &self.skeleton());
-        ds.field("texture", &self.texture());
-        ds.field("layout_type", &self.layout_type());
-        ds.field("attributes", &self.attributes());
-        ds.field("properties", &self.properties());
+        let mut ds = f.debug_struct("Node");
+        ds.field("children", &self.children());
+        ds.field("object_type", &self.object_type());
+        match self.object_type() {
+            Object::Mesh => {
+                if let Some(x) = self.object_as_mesh() {
+                    ds.field("object", &x)
+                } else {
+                    ds.field(
+                        "object",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            Object::Empties => {
+                if let Some(x) = self.object_as_empties() {
+                    ds.field("object", &x)
+                } else {
+                    ds.field(
+                        "object",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("object", &x)
+            }
+        };
+        ds.field("parent", &self.parent());
+        ds.field("world_matrix", &self.world_matrix());
         ds.finish()
     }
 }
-pub enum EmptiesOffset {}
+pub enum NodeNameOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Empties<'a> {
+/// Sorted by name so it can be binary searched
+pub struct NodeName<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Empties<'a> {
-    type Inner = Empties<'a>;
+impl<'a> flatbuffers::Follow<'a> for NodeName<'a> {
+    type Inner = NodeName<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3893,32 +7096,22 @@ impl<'a> flatbuffers::Follow<'a> for Empties<'a> {
     }
 }
 
-impl<'a> Empties<'a> {
-    pub const VT_ID: flatbuffers::VOffsetT = 4;
-    pub const VT_NAME: flatbuffers::VOffsetT = 6;
-    pub const VT_TRANSFORM: flatbuffers::VOffsetT = 8;
-    pub const VT_ANIMATIONS: flatbuffers::VOffsetT = 10;
-    pub const VT_PROPERTIES: flatbuffers::VOffsetT = 12;
+impl<'a> NodeName<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_INDICES: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Empties { _tab: table }
+        NodeName { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args EmptiesArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Empties<'bldr>> {
-        let mut builder = EmptiesBuilder::new(_fbb);
-        builder.add_id(args.id);
-        if let Some(x) = args.properties {
-            builder.add_properties(x);
-        }
-        if let Some(x) = args.animations {
-            builder.add_animations(x);
-        }
-        if let Some(x) = args.transform {
-            builder.add_transform(x);
+        args: &'args NodeNameArgs<'args>,
+    ) -> flatbuffers::WIPOffset<NodeName<'bldr>> {
+        let mut builder = NodeNameBuilder::new(_fbb);
+        if let Some(x) = args.indices {
+            builder.add_indices(x);
         }
         if let Some(x) = args.name {
             builder.add_name(x);
@@ -3926,13 +7119,7 @@ impl<'a> Empties<'a> {
         builder.finish()
     }
 
-    #[inline]
-    pub fn id(&self) -> u64 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<u64>(Empties::VT_ID, Some(0)).unwrap() }
-    }
+    /// Name of the glTF node
     #[inline]
     pub fn name(&self) -> &'a str {
         // Safety:
@@ -3940,54 +7127,39 @@ impl<'a> Empties<'a> {
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(Empties::VT_NAME, None)
+                .get::<flatbuffers::ForwardsUOffset<&str>>(NodeName::VT_NAME, None)
                 .unwrap()
         }
     }
     #[inline]
-    pub fn transform(&self) -> &'a Transform {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<Transform>(Empties::VT_TRANSFORM, None)
-                .unwrap()
-        }
+    pub fn key_compare_less_than(&self, o: &NodeName) -> bool {
+        self.name() < o.name()
     }
+
     #[inline]
-    pub fn animations(
-        &self,
-    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<
-                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet>>,
-                >>(Empties::VT_ANIMATIONS, None)
-                .unwrap()
-        }
+    pub fn key_compare_with_value(&self, val: &str) -> ::core::cmp::Ordering {
+        let key = self.name();
+        key.cmp(val)
     }
+    /// Every node made from a glTF node with that name, in tree order.
+    /// There's more than one when the name is used twice or when the mesh of the node has several primitives
     #[inline]
-    pub fn properties(
-        &self,
-    ) -> flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>> {
+    pub fn indices(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<
-                    flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property>>,
-                >>(Empties::VT_PROPERTIES, None)
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    NodeName::VT_INDICES,
+                    None,
+                )
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for Empties<'_> {
+impl flatbuffers::Verifiable for NodeName<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3995,123 +7167,80 @@ impl flatbuffers::Verifiable for Empties<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<u64>("id", Self::VT_ID, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
-            .visit_field::<Transform>("transform", Self::VT_TRANSFORM, true)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<AnimationSet>>,
-            >>("animations", Self::VT_ANIMATIONS, true)?
-            .visit_field::<flatbuffers::ForwardsUOffset<
-                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>,
-            >>("properties", Self::VT_PROPERTIES, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "indices",
+                Self::VT_INDICES,
+                true,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct EmptiesArgs<'a> {
-    pub id: u64,
+pub struct NodeNameArgs<'a> {
     pub name: Option<flatbuffers::WIPOffset<&'a str>>,
-    pub transform: Option<&'a Transform>,
-    pub animations: Option<
-        flatbuffers::WIPOffset<
-            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<AnimationSet<'a>>>,
-        >,
-    >,
-    pub properties: Option<
-        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>>,
-    >,
+    pub indices: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for EmptiesArgs<'a> {
+impl<'a> Default for NodeNameArgs<'a> {
     #[inline]
     fn default() -> Self {
-        EmptiesArgs {
-            id: 0,
-            name: None,       // required field
-            transform: None,  // required field
-            animations: None, // required field
-            properties: None, // required field
+        NodeNameArgs {
+            name: None,    // required field
+            indices: None, // required field
         }
     }
 }
 
-pub struct EmptiesBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct NodeNameBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EmptiesBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_id(&mut self, id: u64) {
-        self.fbb_.push_slot::<u64>(Empties::VT_ID, id, 0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NodeNameBuilder<'a, 'b, A> {
     #[inline]
     pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Empties::VT_NAME, name);
-    }
-    #[inline]
-    pub fn add_transform(&mut self, transform: &Transform) {
-        self.fbb_
-            .push_slot_always::<&Transform>(Empties::VT_TRANSFORM, transform);
-    }
-    #[inline]
-    pub fn add_animations(
-        &mut self,
-        animations: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<AnimationSet<'b>>>,
-        >,
-    ) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Empties::VT_ANIMATIONS, animations);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(NodeName::VT_NAME, name);
     }
     #[inline]
-    pub fn add_properties(
-        &mut self,
-        properties: flatbuffers::WIPOffset<
-            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Property<'b>>>,
-        >,
-    ) {
+    pub fn add_indices(&mut self, indices: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Empties::VT_PROPERTIES, properties);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(NodeName::VT_INDICES, indices);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EmptiesBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> NodeNameBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        EmptiesBuilder {
+        NodeNameBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Empties<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<NodeName<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_.required(o, Empties::VT_NAME, "name");
-        self.fbb_.required(o, Empties::VT_TRANSFORM, "transform");
-        self.fbb_.required(o, Empties::VT_ANIMATIONS, "animations");
-        self.fbb_.required(o, Empties::VT_PROPERTIES, "properties");
+        self.fbb_.required(o, NodeName::VT_NAME, "name");
+        self.fbb_.required(o, NodeName::VT_INDICES, "indices");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Empties<'_> {
+impl core::fmt::Debug for NodeName<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Empties");
-        ds.field("id", &self.id());
+        let mut ds = f.debug_struct("NodeName");
         ds.field("name", &self.name());
-        ds.field("transform", &self.transform());
-        ds.field("animations", &self.animations());
-        ds.field("properties", &self.properties());
+        ds.field("indices", &self.indices());
         ds.finish()
     }
 }
-pub enum NodeOffset {}
+pub enum VisibilityGroupOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct Node<'a> {
+/// Nodes that are shown or hidden together (an outfit, a hairstyle...)
+pub struct VisibilityGroup<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for Node<'a> {
-    type Inner = Node<'a>;
+impl<'a> flatbuffers::Follow<'a> for VisibilityGroup<'a> {
+    type Inner = VisibilityGroup<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4120,97 +7249,91 @@ impl<'a> flatbuffers::Follow<'a> for Node<'a> {
     }
 }
 
-impl<'a> Node<'a> {
-    pub const VT_CHILDREN: flatbuffers::VOffsetT = 4;
-    pub const VT_OBJECT_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_OBJECT: flatbuffers::VOffsetT = 8;
+impl<'a> VisibilityGroup<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_NODES: flatbuffers::VOffsetT = 6;
+    pub const VT_EXCLUSIVE_SET: flatbuffers::VOffsetT = 8;
+    pub const VT_EXCLUSIVE_WITH: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        Node { _tab: table }
+        VisibilityGroup { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args NodeArgs<'args>,
-    ) -> flatbuffers::WIPOffset<Node<'bldr>> {
-        let mut builder = NodeBuilder::new(_fbb);
-        if let Some(x) = args.object {
-            builder.add_object(x);
+        args: &'args VisibilityGroupArgs<'args>,
+    ) -> flatbuffers::WIPOffset<VisibilityGroup<'bldr>> {
+        let mut builder = VisibilityGroupBuilder::new(_fbb);
+        if let Some(x) = args.exclusive_with {
+            builder.add_exclusive_with(x);
         }
-        if let Some(x) = args.children {
-            builder.add_children(x);
+        if let Some(x) = args.exclusive_set {
+            builder.add_exclusive_set(x);
+        }
+        if let Some(x) = args.nodes {
+            builder.add_nodes(x);
+        }
+        if let Some(x) = args.name {
+            builder.add_name(x);
         }
-        builder.add_object_type(args.object_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn children(&self) -> flatbuffers::Vector<'a, u32> {
+    pub fn name(&self) -> &'a str {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(VisibilityGroup::VT_NAME, None)
+                .unwrap()
+        }
+    }
+    /// The nodes tagged with the group and everything under them
+    #[inline]
+    pub fn nodes(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
                 .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    Node::VT_CHILDREN,
+                    VisibilityGroup::VT_NODES,
                     None,
                 )
                 .unwrap()
         }
     }
+    /// Groups of the same set are mutually exclusive, empty when the group isn't part of one
     #[inline]
-    pub fn object_type(&self) -> Object {
+    pub fn exclusive_set(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<Object>(Node::VT_OBJECT_TYPE, Some(Object::NONE))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<&str>>(VisibilityGroup::VT_EXCLUSIVE_SET, None)
         }
     }
+    /// Indexes of the other groups of the set
     #[inline]
-    pub fn object(&self) -> flatbuffers::Table<'a> {
+    pub fn exclusive_with(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(Node::VT_OBJECT, None)
-                .unwrap()
-        }
-    }
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn object_as_mesh(&self) -> Option<Mesh<'a>> {
-        if self.object_type() == Object::Mesh {
-            let u = self.object();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { Mesh::init_from_table(u) })
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn object_as_empties(&self) -> Option<Empties<'a>> {
-        if self.object_type() == Object::Empties {
-            let u = self.object();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { Empties::init_from_table(u) })
-        } else {
-            None
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    VisibilityGroup::VT_EXCLUSIVE_WITH,
+                    None,
+                )
         }
     }
 }
 
-impl flatbuffers::Verifiable for Node<'_> {
+impl flatbuffers::Verifiable for VisibilityGroup<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4218,118 +7341,102 @@ impl flatbuffers::Verifiable for Node<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
             .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "children",
-                Self::VT_CHILDREN,
+                "nodes",
+                Self::VT_NODES,
                 true,
             )?
-            .visit_union::<Object, _>(
-                "object_type",
-                Self::VT_OBJECT_TYPE,
-                "object",
-                Self::VT_OBJECT,
-                true,
-                |key, v, pos| match key {
-                    Object::Mesh => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Mesh>>(
-                        "Object::Mesh",
-                        pos,
-                    ),
-                    Object::Empties => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<Empties>>(
-                            "Object::Empties",
-                            pos,
-                        ),
-                    _ => Ok(()),
-                },
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "exclusive_set",
+                Self::VT_EXCLUSIVE_SET,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "exclusive_with",
+                Self::VT_EXCLUSIVE_WITH,
+                false,
             )?
             .finish();
         Ok(())
     }
 }
-pub struct NodeArgs<'a> {
-    pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub object_type: Object,
-    pub object: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct VisibilityGroupArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub nodes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub exclusive_set: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub exclusive_with: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for NodeArgs<'a> {
+impl<'a> Default for VisibilityGroupArgs<'a> {
     #[inline]
     fn default() -> Self {
-        NodeArgs {
-            children: None, // required field
-            object_type: Object::NONE,
-            object: None, // required field
+        VisibilityGroupArgs {
+            name: None,  // required field
+            nodes: None, // required field
+            exclusive_set: None,
+            exclusive_with: None,
         }
     }
 }
 
-pub struct NodeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct VisibilityGroupBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NodeBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> VisibilityGroupBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_children(&mut self, children: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_CHILDREN, children);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(VisibilityGroup::VT_NAME, name);
     }
     #[inline]
-    pub fn add_object_type(&mut self, object_type: Object) {
+    pub fn add_nodes(&mut self, nodes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
         self.fbb_
-            .push_slot::<Object>(Node::VT_OBJECT_TYPE, object_type, Object::NONE);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(VisibilityGroup::VT_NODES, nodes);
     }
     #[inline]
-    pub fn add_object(&mut self, object: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_OBJECT, object);
+    pub fn add_exclusive_set(&mut self, exclusive_set: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            VisibilityGroup::VT_EXCLUSIVE_SET,
+            exclusive_set,
+        );
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> NodeBuilder<'a, 'b, A> {
+    pub fn add_exclusive_with(
+        &mut self,
+        exclusive_with: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            VisibilityGroup::VT_EXCLUSIVE_WITH,
+            exclusive_with,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> VisibilityGroupBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        NodeBuilder {
+        VisibilityGroupBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<Node<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<VisibilityGroup<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_.required(o, Node::VT_CHILDREN, "children");
-        self.fbb_.required(o, Node::VT_OBJECT, "object");
+        self.fbb_.required(o, VisibilityGroup::VT_NAME, "name");
+        self.fbb_.required(o, VisibilityGroup::VT_NODES, "nodes");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for Node<'_> {
+impl core::fmt::Debug for VisibilityGroup<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("Node");
-        ds.field("children", &self.children());
-        ds.field("object_type", &self.object_type());
-        match self.object_type() {
-            Object::Mesh => {
-                if let Some(x) = self.object_as_mesh() {
-                    ds.field("object", &x)
-                } else {
-                    ds.field(
-                        "object",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            Object::Empties => {
-                if let Some(x) = self.object_as_empties() {
-                    ds.field("object", &x)
-                } else {
-                    ds.field(
-                        "object",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("object", &x)
-            }
-        };
+        let mut ds = f.debug_struct("VisibilityGroup");
+        ds.field("name", &self.name());
+        ds.field("nodes", &self.nodes());
+        ds.field("exclusive_set", &self.exclusive_set());
+        ds.field("exclusive_with", &self.exclusive_with());
         ds.finish()
     }
 }
@@ -4357,6 +7464,14 @@ impl<'a> GltfScene<'a> {
     pub const VT_MODEL_INDEX: flatbuffers::VOffsetT = 10;
     pub const VT_EMPTY_INDEX: flatbuffers::VOffsetT = 12;
     pub const VT_PROPERTIES: flatbuffers::VOffsetT = 14;
+    pub const VT_BOUNDS: flatbuffers::VOffsetT = 16;
+    pub const VT_ANIMATED_BOUNDS: flatbuffers::VOffsetT = 18;
+    pub const VT_EVALUATION_ORDER: flatbuffers::VOffsetT = 20;
+    pub const VT_NODE_NAMES: flatbuffers::VOffsetT = 22;
+    pub const VT_INDEX: flatbuffers::VOffsetT = 24;
+    pub const VT_IS_DEFAULT: flatbuffers::VOffsetT = 26;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 28;
+    pub const VT_VARIANTS: flatbuffers::VOffsetT = 30;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4368,6 +7483,25 @@ impl<'a> GltfScene<'a> {
         args: &'args GltfSceneArgs<'args>,
     ) -> flatbuffers::WIPOffset<GltfScene<'bldr>> {
         let mut builder = GltfSceneBuilder::new(_fbb);
+        if let Some(x) = args.variants {
+            builder.add_variants(x);
+        }
+        if let Some(x) = args.groups {
+            builder.add_groups(x);
+        }
+        builder.add_index(args.index);
+        if let Some(x) = args.node_names {
+            builder.add_node_names(x);
+        }
+        if let Some(x) = args.evaluation_order {
+            builder.add_evaluation_order(x);
+        }
+        if let Some(x) = args.animated_bounds {
+            builder.add_animated_bounds(x);
+        }
+        if let Some(x) = args.bounds {
+            builder.add_bounds(x);
+        }
         if let Some(x) = args.properties {
             builder.add_properties(x);
         }
@@ -4386,6 +7520,7 @@ impl<'a> GltfScene<'a> {
         if let Some(x) = args.name {
             builder.add_name(x);
         }
+        builder.add_is_default(args.is_default);
         builder.finish()
     }
 
@@ -4471,6 +7606,101 @@ impl<'a> GltfScene<'a> {
                 .unwrap()
         }
     }
+    /// Bounds of every mesh in the scene (in scene space) with the default pose
+    #[inline]
+    pub fn bounds(&self) -> Option<&'a BoundingBox> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<BoundingBox>(GltfScene::VT_BOUNDS, None) }
+    }
+    /// Bounds that also holds every keyframe of every animation, only there when it was asked for
+    #[inline]
+    pub fn animated_bounds(&self) -> Option<&'a BoundingBox> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<BoundingBox>(GltfScene::VT_ANIMATED_BOUNDS, None)
+        }
+    }
+    /// Every node index ordered so that parents come before their children
+    #[inline]
+    pub fn evaluation_order(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    GltfScene::VT_EVALUATION_ORDER,
+                    None,
+                )
+        }
+    }
+    /// Node indices for every glTF node name, sorted so `lookup_by_key` works
+    #[inline]
+    pub fn node_names(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeName<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeName>>,
+            >>(GltfScene::VT_NODE_NAMES, None)
+        }
+    }
+    /// Index of the scene in the file
+    #[inline]
+    pub fn index(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(GltfScene::VT_INDEX, Some(0)).unwrap() }
+    }
+    /// The file wants this scene to be shown first
+    #[inline]
+    pub fn is_default(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GltfScene::VT_IS_DEFAULT, Some(false))
+                .unwrap()
+        }
+    }
+    /// Visibility groups read from the node extras, sorted by name
+    #[inline]
+    pub fn groups(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<VisibilityGroup<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<VisibilityGroup>>,
+            >>(GltfScene::VT_GROUPS, None)
+        }
+    }
+    /// Names of the material variants (KHR_materials_variants)
+    #[inline]
+    pub fn variants(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(GltfScene::VT_VARIANTS, None)
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for GltfScene<'_> {
@@ -4503,6 +7733,24 @@ impl flatbuffers::Verifiable for GltfScene<'_> {
             .visit_field::<flatbuffers::ForwardsUOffset<
                 flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Property>>,
             >>("properties", Self::VT_PROPERTIES, true)?
+            .visit_field::<BoundingBox>("bounds", Self::VT_BOUNDS, false)?
+            .visit_field::<BoundingBox>("animated_bounds", Self::VT_ANIMATED_BOUNDS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "evaluation_order",
+                Self::VT_EVALUATION_ORDER,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<NodeName>>,
+            >>("node_names", Self::VT_NODE_NAMES, false)?
+            .visit_field::<u32>("index", Self::VT_INDEX, false)?
+            .visit_field::<bool>("is_default", Self::VT_IS_DEFAULT, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<VisibilityGroup>>,
+            >>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("variants", Self::VT_VARIANTS, false)?
             .finish();
         Ok(())
     }
@@ -4518,6 +7766,22 @@ pub struct GltfSceneArgs<'a> {
     pub properties: Option<
         flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Property<'a>>>>,
     >,
+    pub bounds: Option<&'a BoundingBox>,
+    pub animated_bounds: Option<&'a BoundingBox>,
+    pub evaluation_order: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub node_names: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<NodeName<'a>>>>,
+    >,
+    pub index: u32,
+    pub is_default: bool,
+    pub groups: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<VisibilityGroup<'a>>>,
+        >,
+    >,
+    pub variants: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
 }
 impl<'a> Default for GltfSceneArgs<'a> {
     #[inline]
@@ -4529,6 +7793,14 @@ impl<'a> Default for GltfSceneArgs<'a> {
             model_index: None, // required field
             empty_index: None, // required field
             properties: None,  // required field
+            bounds: None,
+            animated_bounds: None,
+            evaluation_order: None,
+            node_names: None,
+            index: 0,
+            is_default: false,
+            groups: None,
+            variants: None,
         }
     }
 }
@@ -4588,6 +7860,65 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GltfSceneBuilder<'a, 'b, A> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(GltfScene::VT_PROPERTIES, properties);
     }
     #[inline]
+    pub fn add_bounds(&mut self, bounds: &BoundingBox) {
+        self.fbb_
+            .push_slot_always::<&BoundingBox>(GltfScene::VT_BOUNDS, bounds);
+    }
+    #[inline]
+    pub fn add_animated_bounds(&mut self, animated_bounds: &BoundingBox) {
+        self.fbb_
+            .push_slot_always::<&BoundingBox>(GltfScene::VT_ANIMATED_BOUNDS, animated_bounds);
+    }
+    #[inline]
+    pub fn add_evaluation_order(
+        &mut self,
+        evaluation_order: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            GltfScene::VT_EVALUATION_ORDER,
+            evaluation_order,
+        );
+    }
+    #[inline]
+    pub fn add_node_names(
+        &mut self,
+        node_names: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<NodeName<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(GltfScene::VT_NODE_NAMES, node_names);
+    }
+    #[inline]
+    pub fn add_index(&mut self, index: u32) {
+        self.fbb_.push_slot::<u32>(GltfScene::VT_INDEX, index, 0);
+    }
+    #[inline]
+    pub fn add_is_default(&mut self, is_default: bool) {
+        self.fbb_
+            .push_slot::<bool>(GltfScene::VT_IS_DEFAULT, is_default, false);
+    }
+    #[inline]
+    pub fn add_groups(
+        &mut self,
+        groups: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<VisibilityGroup<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(GltfScene::VT_GROUPS, groups);
+    }
+    #[inline]
+    pub fn add_variants(
+        &mut self,
+        variants: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(GltfScene::VT_VARIANTS, variants);
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GltfSceneBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
         GltfSceneBuilder {
@@ -4621,6 +7952,14 @@ impl core::fmt::Debug for GltfScene<'_> {
         ds.field("model_index", &self.model_index());
         ds.field("empty_index", &self.empty_index());
         ds.field("properties", &self.properties());
+        ds.field("bounds", &self.bounds());
+        ds.field("animated_bounds", &self.animated_bounds());
+        ds.field("evaluation_order", &self.evaluation_order());
+        ds.field("node_names", &self.node_names());
+        ds.field("index", &self.index());
+        ds.field("is_default", &self.is_default());
+        ds.field("groups", &self.groups());
+        ds.field("variants", &self.variants());
         ds.finish()
     }
 }
@@ -4643,6 +7982,7 @@ impl<'a> flatbuffers::Follow<'a> for Scenes<'a> {
 
 impl<'a> Scenes<'a> {
     pub const VT_SCENES: flatbuffers::VOffsetT = 4;
+    pub const VT_DEFAULT_SCENE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -4654,6 +7994,7 @@ impl<'a> Scenes<'a> {
         args: &'args ScenesArgs<'args>,
     ) -> flatbuffers::WIPOffset<Scenes<'bldr>> {
         let mut builder = ScenesBuilder::new(_fbb);
+        builder.add_default_scene(args.default_scene);
         if let Some(x) = args.scenes {
             builder.add_scenes(x);
         }
@@ -4673,6 +8014,18 @@ impl<'a> Scenes<'a> {
                 .unwrap()
         }
     }
+    /// Position in `scenes` of the file's default scene, -1 when it wasn't loaded or the file has none
+    #[inline]
+    pub fn default_scene(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(Scenes::VT_DEFAULT_SCENE, Some(-1))
+                .unwrap()
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for Scenes<'_> {
@@ -4686,6 +8039,7 @@ impl flatbuffers::Verifiable for Scenes<'_> {
             .visit_field::<flatbuffers::ForwardsUOffset<
                 flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<GltfScene>>,
             >>("scenes", Self::VT_SCENES, true)?
+            .visit_field::<i32>("default_scene", Self::VT_DEFAULT_SCENE, false)?
             .finish();
         Ok(())
     }
@@ -4696,12 +8050,14 @@ pub struct ScenesArgs<'a> {
             flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<GltfScene<'a>>>,
         >,
     >,
+    pub default_scene: i32,
 }
 impl<'a> Default for ScenesArgs<'a> {
     #[inline]
     fn default() -> Self {
         ScenesArgs {
             scenes: None, // required field
+            default_scene: -1,
         }
     }
 }
@@ -4722,6 +8078,11 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScenesBuilder<'a, 'b, A> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(Scenes::VT_SCENES, scenes);
     }
     #[inline]
+    pub fn add_default_scene(&mut self, default_scene: i32) {
+        self.fbb_
+            .push_slot::<i32>(Scenes::VT_DEFAULT_SCENE, default_scene, -1);
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScenesBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
         ScenesBuilder {
@@ -4741,6 +8102,7 @@ impl core::fmt::Debug for Scenes<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut ds = f.debug_struct("Scenes");
         ds.field("scenes", &self.scenes());
+        ds.field("default_scene", &self.default_scene());
         ds.finish()
     }
 }
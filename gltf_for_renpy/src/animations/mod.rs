@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use gltf_loader::InterpolationTargets;
+use gltf_loader::utils::CoordinateConvention;
 use rayon::iter::IntoParallelRefMutIterator;
 use rayon::iter::ParallelIterator;
 
@@ -119,7 +120,10 @@ pub struct AnimationSet {
 }
 
 impl AnimationSet {
-    pub fn from_node(node: &Vec<gltf_loader::GLTFAnimation>) -> Vec<AnimationSet> {
+    pub fn from_node(
+        node: &Vec<gltf_loader::GLTFAnimation>,
+        convention: &CoordinateConvention,
+    ) -> Vec<AnimationSet> {
         let mut animations: Vec<AnimationSet> = Vec::with_capacity(node.len());
 
         for animation in node {
@@ -128,9 +132,11 @@ impl AnimationSet {
             let target: usize = animation.target;
 
             let mut frames: Vec<gltf_loader::GLTFAnimationFrame> = animation.frames.clone();
-            frames
-                .par_iter_mut()
-                .for_each(|item| item.value.transformation.as_renpy_coords(true));
+            frames.par_iter_mut().for_each(|item| {
+                item.value
+                    .transformation
+                    .as_converted_coords(convention, true)
+            });
 
             animations.push(AnimationSet {
                 name,
@@ -0,0 +1,60 @@
+use super::Aabb;
+
+// Renpy's `config.perspective`, what `perspective True` uses as (near, z_one_one, far)
+const DEFAULT_NEAR: f32 = 100.0;
+const DEFAULT_Z_ONE_ONE: f32 = 1000.0;
+const DEFAULT_FAR: f32 = 100_000.0;
+
+/// Everything a renpy transform needs to center a model and make it fit the screen
+/// Models are in meters and renpy treats 1 unit as 1 pixel which is why importing without this makes everything tiny
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Framing {
+    /// `xoffset`, `yoffset` and `zpos` (in pixels) that moves the center of the bounds to the center of the stage
+    pub offset: [f32; 3],
+    /// `zoom` that makes the bounds fit inside the viewport
+    pub zoom: f32,
+    /// `perspective` value as (near, z_one_one, far) so that the whole model is between the near and far planes
+    pub perspective: [f32; 3],
+}
+
+/// Works out the transform that fits `bounds` inside a `viewport_width`x`viewport_height` viewport.
+/// `fill` is how much of the viewport the model should take (1.0 touches the edges).
+///
+/// Returns None when the bounds or viewport are empty
+pub fn frame_bounds(
+    bounds: &Aabb,
+    viewport_width: f32,
+    viewport_height: f32,
+    fill: f32,
+) -> Option<Framing> {
+    if bounds.is_empty() || viewport_width <= 0.0 || viewport_height <= 0.0 || fill <= 0.0 {
+        return None;
+    }
+
+    let size = bounds.size();
+    let zoom_x = (size.x > 0.0).then(|| viewport_width * fill / size.x);
+    let zoom_y = (size.y > 0.0).then(|| viewport_height * fill / size.y);
+
+    let zoom = match (zoom_x, zoom_y) {
+        (Some(x), Some(y)) => x.min(y),
+        (Some(zoom), None) | (None, Some(zoom)) => zoom,
+        // A single point can't be fitted to anything
+        (None, None) => return None,
+    };
+
+    let center = bounds.center();
+    let offset = [-center.x * zoom, -center.y * zoom, -center.z * zoom];
+
+    // The model is centered on z = 0 which is where renpy shows things at a 1:1 scale.
+    // A model deep enough to reach the near plane moves the camera back instead, that way z = 0 stays at 1:1 and the zoom still fits
+    let half_depth = size.z * zoom / 2.0;
+    let z_one_one = DEFAULT_Z_ONE_ONE.max(half_depth + DEFAULT_NEAR * 2.0);
+    let far = DEFAULT_FAR.max((z_one_one + half_depth) * 2.0);
+
+    Some(Framing {
+        offset,
+        zoom,
+        perspective: [DEFAULT_NEAR, z_one_one, far],
+    })
+}
@@ -0,0 +1,420 @@
+use std::collections::BTreeSet;
+
+use cgmath::{InnerSpace, Matrix4, Point3, Transform, Vector3, VectorSpace};
+use gltf_for_renpy_flatbuffer as flatbuffer;
+use gltf_loader::utils::GlobalNodeIdentifier;
+use gltf_loader::{GLTFAnimationFrame, InterpolationTargets, InterpolationTypes};
+
+use crate::SimpleFlatbufferConversion;
+
+pub mod framing;
+use crate::gltf_objects::{GltfObject, RenpyScene, mesh::Mesh};
+
+/// Axis aligned bounding box
+/// An empty box has its min above its max so that anything added to it replaces it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Vector3<f32>,
+    pub max: Vector3<f32>,
+}
+
+impl Default for Aabb {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl Aabb {
+    pub fn empty() -> Self {
+        Aabb {
+            min: Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+            max: Vector3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+        }
+    }
+
+    /// Box around a list of points (3 floats per point)
+    pub fn from_points(points: &[f32]) -> Self {
+        let mut aabb = Aabb::empty();
+        for point in points.chunks_exact(3) {
+            if let [x, y, z] = point {
+                aabb.add_point(Vector3::new(*x, *y, *z));
+            }
+        }
+        aabb
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn add_point(&mut self, point: Vector3<f32>) {
+        self.min = self.min.zip(point, f32::min);
+        self.max = self.max.zip(point, f32::max);
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.zip(other.min, f32::min),
+            max: self.max.zip(other.max, f32::max),
+        }
+    }
+
+    pub fn center(&self) -> Vector3<f32> {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    pub fn size(&self) -> Vector3<f32> {
+        self.max.zip(self.min, |max, min| max - min)
+    }
+
+    pub fn corners(&self) -> [Vector3<f32>; 8] {
+        let (min, max) = (self.min, self.max);
+        [
+            Vector3::new(min.x, min.y, min.z),
+            Vector3::new(max.x, min.y, min.z),
+            Vector3::new(min.x, max.y, min.z),
+            Vector3::new(max.x, max.y, min.z),
+            Vector3::new(min.x, min.y, max.z),
+            Vector3::new(max.x, min.y, max.z),
+            Vector3::new(min.x, max.y, max.z),
+            Vector3::new(max.x, max.y, max.z),
+        ]
+    }
+
+    /// Box around this box after it was transformed (so it may be bigger than the transformed points)
+    pub fn transformed(&self, matrix: &Matrix4<f32>) -> Aabb {
+        let mut aabb = Aabb::empty();
+        if self.is_empty() {
+            return aabb;
+        }
+
+        for corner in self.corners() {
+            let point = matrix.transform_point(Point3::new(corner.x, corner.y, corner.z));
+            aabb.add_point(Vector3::new(point.x, point.y, point.z));
+        }
+        aabb
+    }
+
+    /// None for empty boxes since they don't have anything useful to send
+    pub fn to_flatbuffer(&self) -> Option<flatbuffer::BoundingBox> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(flatbuffer::BoundingBox::new(
+            &self.min.to_flatbuffer(),
+            &self.max.to_flatbuffer(),
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingSphere {
+    pub center: Vector3<f32>,
+    pub radius: f32,
+}
+
+impl Default for BoundingSphere {
+    fn default() -> Self {
+        BoundingSphere {
+            center: Vector3::new(0.0, 0.0, 0.0),
+            radius: 0.0,
+        }
+    }
+}
+
+impl BoundingSphere {
+    /// Sphere centered on the box of the points that holds every point
+    pub fn from_points(points: &[f32], aabb: &Aabb) -> Self {
+        let center = aabb.center();
+
+        let mut radius: f32 = 0.0;
+        for point in points.chunks_exact(3) {
+            if let [x, y, z] = point {
+                let offset = Vector3::new(*x, *y, *z).zip(center, |point, center| point - center);
+                radius = radius.max(offset.magnitude());
+            }
+        }
+
+        BoundingSphere { center, radius }
+    }
+
+    pub fn to_flatbuffer(&self) -> flatbuffer::BoundingSphere {
+        flatbuffer::BoundingSphere::new(&self.center.to_flatbuffer(), self.radius)
+    }
+}
+
+/// Bounds of the scene in the default pose
+/// Skinned meshes are placed by their joints (see `joint_bounds`), the other objects by their node transform
+pub fn scene_bounds(scene: &RenpyScene) -> Aabb {
+    let skins = scene_joint_bounds(scene, false);
+    let mesh_bounds: Vec<Option<Aabb>> = scene
+        .objects
+        .nodes
+        .iter()
+        .zip(&skins)
+        .map(|(node, skin)| match &node.value {
+            GltfObject::Mesh(_, mesh) if skin.is_none() => {
+                Some(mesh.instanced_bounds(&mesh.bounds))
+            }
+            GltfObject::Mesh(_, _) | GltfObject::Empty(_, _) => None,
+        })
+        .collect();
+
+    let world = scene
+        .objects
+        .world_matrices(|node| *node.value.rest_matrix());
+
+    bounds_with(&world, &mesh_bounds).union(&skinned_bounds_with(&world, &skins))
+}
+
+/// Bounds over the default pose and every keyframe of every animation.
+/// Morph targets are counted as fully on (or off) for every vertex which keeps the box conservative.
+/// Skinned meshes follow the boxes of their joints. Nodes that are not part of an animation stay in their default pose.
+pub fn animated_scene_bounds(scene: &RenpyScene) -> Aabb {
+    let skins = scene_joint_bounds(scene, true);
+    let morphed_bounds: Vec<Option<Aabb>> = scene
+        .objects
+        .nodes
+        .iter()
+        .zip(&skins)
+        .map(|(node, skin)| match &node.value {
+            GltfObject::Mesh(_, mesh) if skin.is_none() => {
+                Some(mesh.instanced_bounds(&morphed_mesh_bounds(mesh)))
+            }
+            GltfObject::Mesh(_, _) | GltfObject::Empty(_, _) => None,
+        })
+        .collect();
+    let posed_bounds = |world: &[Matrix4<f32>]| {
+        bounds_with(world, &morphed_bounds).union(&skinned_bounds_with(world, &skins))
+    };
+
+    let rest_world = scene
+        .objects
+        .world_matrices(|node| *node.value.rest_matrix());
+    let mut bounds = posed_bounds(&rest_world);
+
+    let clip_names: BTreeSet<&str> = scene
+        .objects
+        .nodes
+        .iter()
+        .flat_map(|node| node.value.animations())
+        .map(|set| set.name.as_str())
+        .collect();
+
+    for clip in clip_names {
+        let mut times: Vec<f32> = scene
+            .objects
+            .nodes
+            .iter()
+            .flat_map(|node| node.value.animations())
+            .filter(|set| set.name == clip)
+            .flat_map(|set| &set.animation.frames)
+            .map(|frame| frame.time)
+            .collect();
+        times.sort_by(f32::total_cmp);
+        times.dedup();
+
+        for time in times {
+            let world = scene.objects.world_matrices(|node| {
+                node.value
+                    .animations()
+                    .iter()
+                    .find(|set| set.name == clip)
+                    .and_then(|set| {
+                        sample_frames(&set.animation.frames, set.animation.interpolation, time)
+                    })
+                    .unwrap_or(*node.value.rest_matrix())
+            });
+
+            bounds = bounds.union(&posed_bounds(&world));
+        }
+    }
+
+    bounds
+}
+
+// Union of the local boxes of every node (None for nodes without geometry) moved to scene space
+fn bounds_with(world: &[Matrix4<f32>], local: &[Option<Aabb>]) -> Aabb {
+    let mut bounds = Aabb::empty();
+    for (matrix, node_bounds) in world.iter().zip(local) {
+        if let Some(node_bounds) = node_bounds {
+            bounds = bounds.union(&node_bounds.transformed(matrix));
+        }
+    }
+    bounds
+}
+
+// Union of the joint boxes of every skinned mesh moved by the world matrix of their joint
+fn skinned_bounds_with(world: &[Matrix4<f32>], skins: &[Option<Vec<(usize, Aabb)>>]) -> Aabb {
+    let mut bounds = Aabb::empty();
+    for (joint, joint_bounds) in skins.iter().flatten().flatten() {
+        if let Some(matrix) = world.get(*joint) {
+            bounds = bounds.union(&joint_bounds.transformed(matrix));
+        }
+    }
+    bounds
+}
+
+// `joint_bounds` of every node, None for nodes that aren't skinned meshes
+fn scene_joint_bounds(scene: &RenpyScene, morphed: bool) -> Vec<Option<Vec<(usize, Aabb)>>> {
+    scene
+        .objects
+        .nodes
+        .iter()
+        .map(|node| match &node.value {
+            GltfObject::Mesh(_, mesh) => joint_bounds(scene, mesh, morphed),
+            GltfObject::Empty(_, _) => None,
+        })
+        .collect()
+}
+
+/// Box of the vertices each joint of a skinned mesh moves, in the space of the joint (after its inverse bind matrix),
+/// with the node index of the joint.
+///
+/// A skinned vertex is a weighted average of its point moved by each of its joints, so it always stays inside
+/// the union of these boxes moved by the world matrix of their joint whatever the pose.
+/// None when the mesh isn't skinned or a joint isn't in the scene.
+pub fn joint_bounds(scene: &RenpyScene, mesh: &Mesh, morphed: bool) -> Option<Vec<(usize, Aabb)>> {
+    let skeleton = mesh.skeleton.as_ref()?;
+    if mesh.bone_indexes.is_empty() {
+        return None;
+    }
+
+    let joints = skeleton
+        .bones
+        .iter()
+        .map(|bone| match bone {
+            GlobalNodeIdentifier::NodeId(id) => scene
+                .objects
+                .find_node(*id)
+                .ok()
+                .and_then(|node| usize::try_from(node).ok()),
+            GlobalNodeIdentifier::ObjectIndex(index) => Some(*index),
+            GlobalNodeIdentifier::SceneRoot => None,
+        })
+        .collect::<Option<Vec<usize>>>()?;
+
+    let mut boxes = vec![Aabb::empty(); joints.len()];
+    let influences = mesh
+        .bone_indexes
+        .chunks_exact(4)
+        .zip(mesh.bone_weights.chunks_exact(4));
+    for (index, (bones, weights)) in influences.enumerate() {
+        let point = if morphed {
+            morphed_point_bounds(mesh, index)
+        } else {
+            let mut point = Aabb::empty();
+            if let Some([x, y, z]) = mesh.vertexes.chunks_exact(3).nth(index) {
+                point.add_point(Vector3::new(*x, *y, *z));
+            }
+            point
+        };
+
+        for (bone, weight) in bones.iter().zip(weights) {
+            if *weight <= 0.0 {
+                continue;
+            }
+            let bone = usize::from(*bone);
+            let inverse_bind = skeleton.inverse_bind_matrixes.get(bone)?;
+            let joint_box = boxes.get_mut(bone)?;
+            *joint_box = joint_box.union(&point.transformed(inverse_bind));
+        }
+    }
+
+    Some(
+        joints
+            .into_iter()
+            .zip(boxes)
+            .filter(|(_, aabb)| !aabb.is_empty())
+            .collect(),
+    )
+}
+
+// Box around a vertex moved by every combination of its morph targets
+fn morphed_point_bounds(mesh: &Mesh, index: usize) -> Aabb {
+    let mut aabb = Aabb::empty();
+    let Some([x, y, z]) = mesh.vertexes.chunks_exact(3).nth(index) else {
+        return aabb;
+    };
+
+    let mut low = Vector3::new(*x, *y, *z);
+    let mut high = low;
+    for target in &mesh.morph_targets {
+        if let Some(shape) = target.blend_shapes.get(index) {
+            let delta = shape.position;
+            low = low.zip(delta, |low, delta| low + delta.min(0.0));
+            high = high.zip(delta, |high, delta| high + delta.max(0.0));
+        }
+    }
+
+    aabb.add_point(low);
+    aabb.add_point(high);
+    aabb
+}
+
+// Box around every vertex moved by every combination of its morph targets
+fn morphed_mesh_bounds(mesh: &Mesh) -> Aabb {
+    if mesh.morph_targets.is_empty() {
+        return mesh.bounds;
+    }
+
+    let mut aabb = Aabb::empty();
+    for index in 0..mesh.vertexes.len().div_euclid(3) {
+        aabb = aabb.union(&morphed_point_bounds(mesh, index));
+    }
+    aabb
+}
+
+/// Local matrix of an animated node at `time`
+/// Keyframes are already in renpy coords and store the whole transform so only the 2 closest ones are needed.
+/// Each channel is sampled with its own interpolation.
+pub fn sample_frames(
+    frames: &[GLTFAnimationFrame],
+    interpolation: InterpolationTargets,
+    time: f32,
+) -> Option<Matrix4<f32>> {
+    let after = frames
+        .iter()
+        .position(|frame| frame.time > time)
+        .unwrap_or(frames.len());
+
+    let Some(previous) = after.checked_sub(1).and_then(|index| frames.get(index)) else {
+        // Before the first keyframe
+        return frames
+            .first()
+            .map(|frame| frame.value.transformation.clone().into());
+    };
+    let Some(next) = frames.get(after) else {
+        return Some(previous.value.transformation.clone().into());
+    };
+
+    let span = next.time - previous.time;
+    if span <= 0.0 {
+        return Some(previous.value.transformation.clone().into());
+    }
+
+    let amount = ((time - previous.time) / span).clamp(0.0, 1.0);
+    // Step channels keep the value of the previous keyframe
+    let channel_amount = |interpolation: InterpolationTypes| match interpolation {
+        InterpolationTypes::Step => 0.0,
+        InterpolationTypes::None | InterpolationTypes::Linear | InterpolationTypes::Cubic => amount,
+    };
+    let (from, to) = (&previous.value.transformation, &next.value.transformation);
+
+    let mut transform = from.clone();
+    transform.translation = from
+        .translation
+        .lerp(to.translation, channel_amount(interpolation.translation));
+    transform.scale = from
+        .scale
+        .lerp(to.scale, channel_amount(interpolation.scale));
+    transform.rotation = gltf_loader::utils::RotationTransform::Quaternion(
+        from.rotation.clone().unwrap_quaternion().slerp(
+            to.rotation.clone().unwrap_quaternion(),
+            channel_amount(interpolation.rotation),
+        ),
+    );
+
+    Some(transform.into())
+}
@@ -0,0 +1,290 @@
+// Grafting the nodes of one file under a node of another (props in a hand, a hat on a head...)
+// The attachment ends up as part of the scene so it follows the animations of the node it's attached to
+
+use cgmath::{Matrix4, Quaternion, Vector3};
+use gltf_loader::utils::{DecomposedTransform, GlobalNodeIdentifier, RotationTransform};
+use nohash_hasher::IntSet;
+use serde::Deserialize;
+
+use crate::gltf_objects::{GltfObject, ObjectId, RenpyScene, empty::Empty};
+use crate::node_path::{self, SEPARATOR};
+use crate::renpy_interop::{NodeID, NodeNotFoundInTree};
+use crate::{ExportOptions, bounds, groups};
+
+/// Transform between the node attached to and the roots of the attachment.
+/// Given in glTF coordinates (Y up, meters) and converted like any other node.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Offset {
+    pub translation: [f32; 3],
+    /// `[x, y, z, w]` quaternion
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl Default for Offset {
+    fn default() -> Self {
+        Self {
+            translation: [0.0; 3],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [1.0; 3],
+        }
+    }
+}
+
+impl From<&Offset> for DecomposedTransform {
+    fn from(value: &Offset) -> Self {
+        let [x, y, z, w] = value.rotation;
+        DecomposedTransform {
+            translation: Vector3::from(value.translation),
+            rotation: RotationTransform::Quaternion(Quaternion::new(w, x, y, z)),
+            scale: Vector3::from(value.scale),
+        }
+    }
+}
+
+/// A file to load and attach to every scene that has the target node
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Attachment {
+    /// Path of the file to attach (only its default scene is used)
+    pub file: String,
+    /// Path or glob pattern of the node to attach to (ex: `**/Hand_L`), the first match is used
+    pub target: String,
+    #[serde(default)]
+    pub offset: Offset,
+}
+
+/// Finds the node an attachment goes on, an exact path is tried before the glob pattern
+pub fn find_target(scene: &RenpyScene, target: &str) -> Result<NodeID, NodeNotFoundInTree> {
+    scene.objects.find_node_by_path(target).or_else(|_| {
+        scene
+            .objects
+            .query(target)
+            .first()
+            .copied()
+            .ok_or(NodeNotFoundInTree)
+    })
+}
+
+/// Moves every node of `attachment` into `scene`, under an empty placed at `offset` from the `target` node.
+///
+/// The grafted nodes get the `NodeID`s after the ones already in the scene and their paths are moved under the target
+/// (`Scene/Armature/Hand_L/Sword/Blade/0`). The empty gets the `ObjectId` after the highest one of the scene and the
+/// ids of the grafted nodes are moved after it, so they can still be looked up by id. Returns the id of the empty holding the attachment.
+pub fn attach(
+    scene: &mut RenpyScene,
+    target: &str,
+    attachment: RenpyScene,
+    offset: &Offset,
+    options: &ExportOptions,
+) -> Result<NodeID, NodeNotFoundInTree> {
+    let target = find_target(scene, target)?;
+    let target_node = scene.objects.get_node(target).ok_or(NodeNotFoundInTree)?;
+
+    // The attachment scene name plays the part of a node under the target
+    let siblings: Vec<String> = target_node
+        .children
+        .iter()
+        .filter_map(|child| scene.objects.get_value(*child))
+        .filter_map(|child| child.path().rsplit_once(SEPARATOR))
+        .map(|(_, segment)| segment.to_owned())
+        .collect();
+
+    #[allow(clippy::cast_possible_truncation)]
+    // Truncation is fine since we will realistically not go over this... hopefully?
+    let socket_id = scene.objects.nodes.len() as NodeID;
+    let socket_path = node_path::join(
+        target_node.value.path(),
+        &node_path::segment(Some(&attachment.name), socket_id as usize, &siblings),
+    );
+
+    // The empty doesn't come from a glTF node so it takes the id after the ones of the scene
+    let socket_object_id = scene
+        .objects
+        .nodes
+        .iter()
+        .flat_map(|node| object_ids(&node.value))
+        .max()
+        .map_or(0, |id| id.saturating_add(1));
+    let object_shift = socket_object_id.saturating_add(1);
+
+    let convention = &options.coordinate_convention;
+    let offset = DecomposedTransform::from(offset);
+    let socket = Empty {
+        id: socket_object_id,
+        name: format!("{}:{}", scene.name, attachment.name),
+        path: socket_path.clone(),
+        node_name: Some(attachment.name.clone()),
+        rest_matrix: convention.convert_matrix(Matrix4::from(offset.clone())),
+        transform: offset.to_converted_coords(convention, false),
+        animations: Vec::new(),
+        properties: attachment.properties,
+    };
+    let mut socket_ids: IntSet<ObjectId> = IntSet::default();
+    socket_ids.insert(socket_object_id);
+
+    scene
+        .objects
+        .push(target, GltfObject::Empty(socket_ids, Box::new(socket)))?;
+
+    // Every node of the attachment moves by the same amount
+    let shift = socket_id.saturating_add(1);
+    let shift_id = |id: NodeID| id.saturating_add(shift);
+    let shift_identifier = |identifier: &mut GlobalNodeIdentifier| match identifier {
+        GlobalNodeIdentifier::ObjectIndex(index) => *index = index.saturating_add(shift as usize),
+        GlobalNodeIdentifier::NodeId(id) => *id = id.saturating_add(object_shift),
+        GlobalNodeIdentifier::SceneRoot => {}
+    };
+
+    // Variants are matched by name, the ones the scene doesn't have yet are added at the end
+    let variant_ids: Vec<u32> = attachment
+        .variants
+        .iter()
+        .map(|name| {
+            let index = match scene.variants.iter().position(|variant| variant == name) {
+                Some(index) => index,
+                None => {
+                    scene.variants.push(name.clone());
+                    scene.variants.len().saturating_sub(1)
+                }
+            };
+            u32::try_from(index).unwrap_or(u32::MAX)
+        })
+        .collect();
+
+    let mut objects = attachment.objects;
+    for node in &mut objects.nodes {
+        for child in &mut node.children {
+            *child = shift_id(*child);
+        }
+
+        let path = match &mut node.value {
+            GltfObject::Empty(ids, empty) => {
+                *ids = ids
+                    .iter()
+                    .map(|id| id.saturating_add(object_shift))
+                    .collect();
+                empty.id = empty.id.saturating_add(object_shift);
+                &mut empty.path
+            }
+            GltfObject::Mesh(ids, mesh) => {
+                *ids = ids
+                    .iter()
+                    .map(|id| id.saturating_add(object_shift))
+                    .collect();
+                mesh.id = mesh.id.saturating_add(object_shift);
+                mesh.instance_of = mesh.instance_of.map(shift_id);
+                for variant in &mut mesh.variants {
+                    if let Some(id) = variant_ids.get(variant.variant as usize) {
+                        variant.variant = *id;
+                    }
+                }
+                if let Some(skeleton) = &mut mesh.skeleton {
+                    skeleton.bones.iter_mut().for_each(shift_identifier);
+                    shift_identifier(&mut skeleton.root_index);
+                }
+                &mut mesh.path
+            }
+        };
+
+        // Swap the attachment scene segment for the socket path
+        *path = match path.split_once(SEPARATOR) {
+            Some((_, rest)) => node_path::join(&socket_path, rest),
+            None => socket_path.clone(),
+        };
+    }
+
+    if let Some(socket) = scene.objects.nodes.get_mut(socket_id as usize) {
+        socket
+            .children
+            .extend(objects.roots.iter().copied().map(shift_id));
+    }
+    scene.objects.nodes.extend(objects.nodes);
+    scene.objects.rebuild_index();
+
+    scene.empty_indexes.push(socket_id);
+    scene
+        .empty_indexes
+        .extend(attachment.empty_indexes.into_iter().map(shift_id));
+    scene
+        .mesh_indexes
+        .extend(attachment.mesh_indexes.into_iter().map(shift_id));
+
+    // Groups with the same name are merged, so an attached outfit joins the ones of the character
+    for mut group in attachment.groups {
+        group
+            .nodes
+            .iter_mut()
+            .for_each(|node| *node = shift_id(*node));
+        match scene
+            .groups
+            .iter_mut()
+            .find(|existing| existing.name == group.name)
+        {
+            Some(existing) => {
+                existing.nodes.extend(group.nodes);
+                existing.nodes.sort_unstable();
+            }
+            None => scene.groups.push(group),
+        }
+    }
+    scene.groups.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
+    groups::link_sets(&mut scene.groups);
+
+    scene.bounds = bounds::scene_bounds(scene);
+    if scene.animated_bounds.is_some() {
+        scene.animated_bounds = Some(bounds::animated_scene_bounds(scene));
+    }
+
+    Ok(socket_id)
+}
+
+// Every id the object answers to
+fn object_ids(object: &GltfObject) -> impl Iterator<Item = ObjectId> + '_ {
+    let ids = match object {
+        GltfObject::Empty(ids, _) | GltfObject::Mesh(ids, _) => ids,
+    };
+    ids.iter().copied().chain(std::iter::once(object.id()))
+}
+
+/// Loads every attachment of the options and grafts it on each scene that has its target.
+/// Fails when an attachment can't be loaded or when no scene has its target.
+pub fn attach_files(scenes: &mut [RenpyScene], options: &ExportOptions) -> anyhow::Result<()> {
+    // The attached files are converted the same way, without attachments of their own
+    let attachment_options = ExportOptions {
+        scenes: gltf_loader::SceneSelection::Default,
+        attachments: Vec::new(),
+        ..options.clone()
+    };
+
+    for attachment in &options.attachments {
+        let loaded = crate::load_renpy_scenes(&attachment.file, &attachment_options)?;
+        let Some(attached_scene) = loaded.into_iter().next() else {
+            anyhow::bail!("{} has no scene to attach", attachment.file);
+        };
+
+        let mut attached = false;
+        for scene in scenes.iter_mut() {
+            if find_target(scene, &attachment.target).is_ok() {
+                attach(
+                    scene,
+                    &attachment.target,
+                    attached_scene.clone(),
+                    &attachment.offset,
+                    options,
+                )?;
+                attached = true;
+            }
+        }
+
+        if !attached {
+            anyhow::bail!(
+                "No node matches {} to attach {} to",
+                attachment.target,
+                attachment.file
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+// Rules deciding which nodes of the file make it to renpy
+// Helper geometry, colliders and reference planes can be left out without having to touch the blend file
+
+use crate::node_path::{self, SEPARATOR};
+use ego_tree::{NodeId, Tree};
+use gltf_loader::SceneObject;
+use gltf_loader::utils::GlobalNodeIdentifier;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+
+/// The kind of object a node turns into
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum ObjectType {
+    Mesh,
+    Empty,
+}
+
+/// What happens to the children of an excluded node
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum ExcludedChildren {
+    /// The children are moved up to the closest exported parent (or become roots).
+    /// The transform of the node left out is moved into theirs, their animations stay relative to it.
+    #[default]
+    Reparent,
+    /// The children are left out too
+    Drop,
+}
+
+/// Which nodes get exported.
+///
+/// Patterns are globs matched against the node path (see `node_path::glob_match`), so `**/COL_*` matches every
+/// node whose name starts with `COL_`. The primitive segment of meshes isn't part of the matched path.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ExportRules {
+    /// When not empty, only the nodes matching one of these patterns get exported
+    pub include: Vec<String>,
+
+    /// Nodes matching one of these patterns are left out
+    pub exclude: Vec<String>,
+
+    /// Nodes with this extra property set to `true` are left out, checked on the node and on the mesh it uses.
+    /// Off by default, `renpy_ignore` is the usual name for it
+    pub ignore_extra: Option<String>,
+
+    /// Every node of these types is left out
+    pub exclude_types: Vec<ObjectType>,
+
+    /// What happens to the children of the nodes left out
+    pub excluded_children: ExcludedChildren,
+}
+
+impl ExportRules {
+    /// Objects of the scene left out by the rules, `paths` being the paths given by `node_path::PathBuilder`.
+    ///
+    /// The joints of the skins and the nodes above them are always kept so the skins still have their bones,
+    /// with a warning when the rules would have left them out.
+    /// The nodes above the included ones don't have to match `include`, so `**/Sword` keeps the path to the sword.
+    pub fn excluded_objects(
+        &self,
+        objects: &Tree<SceneObject>,
+        paths: &HashMap<NodeId, String>,
+    ) -> HashSet<NodeId> {
+        let joints: HashSet<usize> = objects
+            .values()
+            .filter_map(|object| match object {
+                SceneObject::Mesh(model) => model.skeleton().as_ref(),
+                _ => None,
+            })
+            .flat_map(|skeleton| &skeleton.bones)
+            .filter_map(|bone| match bone {
+                GlobalNodeIdentifier::NodeId(index) => Some(*index),
+                _ => None,
+            })
+            .collect();
+
+        let mut kept = HashSet::new();
+        let mut above_included = HashSet::new();
+        for object in objects.root().descendants() {
+            let ancestors = object.ancestors().map(|ancestor| ancestor.id());
+            if node_index(object.value()).is_some_and(|index| joints.contains(&index)) {
+                kept.insert(object.id());
+                kept.extend(ancestors);
+            } else if paths
+                .get(&object.id())
+                .is_some_and(|path| self.includes(object.value(), path))
+            {
+                above_included.extend(ancestors);
+            }
+        }
+
+        let mut excluded = HashSet::new();
+        for object in objects.root().descendants() {
+            let Some(path) = paths.get(&object.id()) else {
+                continue;
+            };
+            let left_out = self.excludes(object.value(), path)
+                || !(above_included.contains(&object.id()) || self.includes(object.value(), path));
+
+            if !left_out {
+                continue;
+            }
+            if kept.contains(&object.id()) {
+                eprintln!(
+                    "Warning: {path} is kept even though the export rules leave it out, a skin needs it"
+                );
+            } else {
+                excluded.insert(object.id());
+            }
+        }
+        excluded
+    }
+
+    /// Whether the object matches one of the `include` patterns, every object does when there are none
+    pub fn includes(&self, object: &SceneObject, path: &str) -> bool {
+        let path = match object {
+            SceneObject::Root => return true,
+            // Drop the primitive segment
+            SceneObject::Mesh(_) => path
+                .rsplit_once(SEPARATOR)
+                .map_or(path, |(node_path, _)| node_path),
+            SceneObject::Empties(_) => path,
+        };
+
+        self.include.is_empty()
+            || self
+                .include
+                .iter()
+                .any(|pattern| node_path::glob_match(pattern, path))
+    }
+
+    /// Whether the object is left out by the exclude, type or extra rules, `include` is checked by `includes`
+    pub fn excludes(&self, object: &SceneObject, path: &str) -> bool {
+        let (object_type, path, extras) = match object {
+            SceneObject::Root => return false,
+            SceneObject::Mesh(model) => (
+                ObjectType::Mesh,
+                // Drop the primitive segment
+                path.rsplit_once(SEPARATOR)
+                    .map_or(path, |(node_path, _)| node_path),
+                vec![model.node_extras(), model.mesh_extras()],
+            ),
+            SceneObject::Empties(empty) => (ObjectType::Empty, path, vec![&empty.extras]),
+        };
+
+        if self.exclude_types.contains(&object_type) {
+            return true;
+        }
+
+        if self
+            .exclude
+            .iter()
+            .any(|pattern| node_path::glob_match(pattern, path))
+        {
+            return true;
+        }
+
+        self.ignore_extra.as_ref().is_some_and(|key| {
+            extras
+                .into_iter()
+                .flatten()
+                .any(|extras| is_set(extras, key))
+        })
+    }
+}
+
+// Index of the glTF node the object comes from
+fn node_index(object: &SceneObject) -> Option<usize> {
+    match object {
+        SceneObject::Root => None,
+        SceneObject::Mesh(model) => Some(model.index()),
+        SceneObject::Empties(empty) => Some(empty.id),
+    }
+}
+
+// Extras are turned into json strings by the loader
+fn is_set(extras: &HashMap<String, String>, key: &str) -> bool {
+    extras.get(key).is_some_and(|value| value == "true")
+}
@@ -1,9 +1,11 @@
 use gltf_for_renpy_flatbuffer as flatbuffer;
 
+use cgmath::Matrix4;
 use gltf_loader::utils::DecomposedTransform;
 use nohash_hasher::IntSet;
 
-use crate::{AnimationSet, FlatbufferConversion, SimpleFlatbufferConversion};
+use crate::mesh_processing::bake;
+use crate::{AnimationSet, ExportOptions, FlatbufferConversion, SimpleFlatbufferConversion};
 
 use super::{
     ObjectId,
@@ -18,7 +20,14 @@ pub struct Empty {
 
     pub name: String,
 
+    // Unique path like `Scene/Armature/Body/0`, see `node_path`
+    pub path: String,
+
+    // Name of the glTF node
+    pub node_name: Option<String>,
+
     pub transform: DecomposedTransform,
+    pub rest_matrix: Matrix4<f32>,
 
     pub animations: Vec<AnimationSet>,
 
@@ -26,10 +35,21 @@ pub struct Empty {
 }
 
 impl Empty {
-    pub fn create(empty: &gltf_loader::Empty, scene_name: String) -> super::GltfObject {
+    pub fn create(
+        empty: &gltf_loader::Empty,
+        scene_name: String,
+        path: String,
+        options: &ExportOptions,
+    ) -> super::GltfObject {
+        let convention = &options.coordinate_convention;
         let properties = Property::load(empty.extras.clone());
 
-        let transform = empty.transform().clone().to_renpy_coords(false);
+        let transform = empty
+            .transform()
+            .clone()
+            .to_converted_coords(convention, false);
+        // Taken from the matrix instead of the transform so shear isn't lost
+        let rest_matrix = convention.convert_matrix(empty.matrix());
 
         let name = format!(
             "{}:{}",
@@ -37,14 +57,15 @@ impl Empty {
             empty.name.clone().unwrap_or("Empty".to_owned())
         );
 
-        let animations = AnimationSet::from_node(empty.animations());
+        let animations = AnimationSet::from_node(empty.animations(), convention);
 
-        // X has to be negated because in gltf, +X is left, while in renpy, +X is right.
-        // I am not sure why I have to negate z...
         let loaded_empty = Empty {
             id: empty.id,
             name,
+            path,
+            node_name: empty.name.clone(),
             transform,
+            rest_matrix,
             animations,
             properties,
         };
@@ -61,6 +82,7 @@ impl Empty {
     ) -> flatbuffers::WIPOffset<flatbuffer::Empties<'a>> {
         // The return type is dictated by the flatbuffer schema and can remain.
         let name = builder.create_string(&self.name);
+        let path = builder.create_string(&self.path);
 
         let animation_offsets: Vec<_> = self
             .animations
@@ -85,6 +107,9 @@ impl Empty {
                 transform: Some(&self.transform.to_flatbuffer()),
                 animations,
                 properties: Some(properties),
+                matrix: Some(&self.rest_matrix.to_flatbuffer()),
+                transform_is_lossy: bake::is_lossy(&self.rest_matrix),
+                path: Some(path),
             },
         )
     }
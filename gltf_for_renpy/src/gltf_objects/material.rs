@@ -0,0 +1,200 @@
+use cgmath::{Vector3, Vector4};
+use gltf_for_renpy_flatbuffer as flatbuffer;
+use gltf_loader::model::{OutlineWidthMode, Toon};
+
+use super::property::{Properties, Property};
+use crate::images::{PbrTextures, RenpyImage};
+use crate::{ExportOptions, SimpleFlatbufferConversion};
+
+/// The shading part of a material, the base color is sent as the mesh texture
+#[derive(Clone, Debug)]
+pub struct RenpyMaterial {
+    pub name: String,
+    // Linear multiplier of the base color, also sent when the texture already has it
+    pub base_color_factor: Vector4<f32>,
+    pub unlit: bool,
+    pub emissive_factor: Vector3<f32>,
+    pub emissive_strength: f32,
+    pub toon: Option<Toon>,
+    // Raw json of the vendor extensions so renpy can read the ones we don't know about
+    pub extensions: Properties,
+
+    pub occlusion_strength: f32,
+    pub metallic_factor: f32,
+    pub roughness_factor: f32,
+    // Only loaded when asked for in the options, see `PbrTextures`
+    pub occlusion_texture: Option<RenpyImage>,
+    pub metallic_roughness_texture: Option<RenpyImage>,
+    pub metallic_texture: Option<RenpyImage>,
+    pub roughness_texture: Option<RenpyImage>,
+    pub emissive_texture: Option<RenpyImage>,
+}
+
+impl RenpyMaterial {
+    pub fn load(material: &gltf_loader::Material, options: &ExportOptions) -> Self {
+        let pbr = &material.pbr;
+        let occlusion = material.occlusion.as_ref();
+        let mode = options.textures.pbr_textures;
+
+        let occlusion_texture = occlusion.and_then(|occlusion| {
+            let name = Some(occlusion.texture_name.clone());
+            match mode {
+                PbrTextures::None => None,
+                PbrTextures::Packed => Some(RenpyImage::load_rgb_image(
+                    &occlusion.packed_texture,
+                    &name,
+                    occlusion.sampler,
+                    false,
+                    options,
+                )),
+                PbrTextures::Channels => Some(RenpyImage::load_gray_image(
+                    &occlusion.texture,
+                    &name,
+                    occlusion.sampler,
+                    options,
+                )),
+            }
+        });
+
+        let packed = mode == PbrTextures::Packed;
+        let channels = mode == PbrTextures::Channels;
+        let name = &pbr.metallic_roughness_texture_name;
+        let sampler = pbr.metallic_roughness_sampler;
+        // Both channels come out of the same image so they get a suffix to keep their names apart
+        let channel = |texture: &Option<std::sync::Arc<image::GrayImage>>, suffix: &str| {
+            texture.as_ref().filter(|_| channels).map(|texture| {
+                let name = name
+                    .as_ref()
+                    .map(|name| std::sync::Arc::new(format!("{name}_{suffix}")));
+                RenpyImage::load_gray_image(texture, &name, sampler, options)
+            })
+        };
+
+        RenpyMaterial {
+            name: material.name.clone().unwrap_or_default(),
+            base_color_factor: material.pbr.base_color_factor,
+            unlit: material.unlit,
+            emissive_factor: material.emissive.factor,
+            emissive_strength: material.emissive.strength,
+            toon: material.toon.clone(),
+            extensions: material
+                .extensions
+                .iter()
+                .map(|(name, value)| Property {
+                    name: name.clone(),
+                    value: value.clone(),
+                })
+                .collect(),
+            occlusion_strength: occlusion.map_or(1.0, |occlusion| occlusion.factor),
+            metallic_factor: pbr.metallic_factor,
+            roughness_factor: pbr.roughness_factor,
+            occlusion_texture,
+            metallic_roughness_texture: pbr
+                .metallic_roughness_texture
+                .as_ref()
+                .filter(|_| packed)
+                .map(|texture| RenpyImage::load_rgb_image(texture, name, sampler, false, options)),
+            metallic_texture: channel(&pbr.metallic_texture, "metallic"),
+            roughness_texture: channel(&pbr.roughness_texture, "roughness"),
+            emissive_texture: material
+                .emissive
+                .texture
+                .as_ref()
+                .filter(|_| mode != PbrTextures::None)
+                .map(|texture| {
+                    RenpyImage::load_rgb_image(
+                        texture,
+                        &material.emissive.texture_name,
+                        material.emissive.sampler,
+                        true,
+                        options,
+                    )
+                }),
+        }
+    }
+
+    /// Names of the textures other than the base color, meshes sharing geometry need the same ones
+    pub fn texture_names(&self) -> [Option<&str>; 5] {
+        [
+            &self.occlusion_texture,
+            &self.metallic_roughness_texture,
+            &self.metallic_texture,
+            &self.roughness_texture,
+            &self.emissive_texture,
+        ]
+        .map(|texture| texture.as_ref().map(|texture| texture.name.as_str()))
+    }
+
+    // The textures are left out for meshes that reuse the geometry (and textures) of another one
+    pub fn to_flatbuffer<'a>(
+        &self,
+        builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+        with_textures: bool,
+    ) -> flatbuffers::WIPOffset<flatbuffer::Material<'a>> {
+        let name = builder.create_string(&self.name);
+
+        let toon = self.toon.as_ref().map(|toon| {
+            flatbuffer::Toon::create(
+                builder,
+                &flatbuffer::ToonArgs {
+                    shade_color: Some(&toon.shade_color_factor.to_flatbuffer()),
+                    shading_shift: toon.shading_shift_factor,
+                    shading_toony: toon.shading_toony_factor,
+                    outline_width: toon.outline_width_factor,
+                    outline_width_mode: match toon.outline_width_mode {
+                        OutlineWidthMode::None => flatbuffer::OutlineWidthMode::None,
+                        OutlineWidthMode::World => flatbuffer::OutlineWidthMode::World,
+                        OutlineWidthMode::Screen => flatbuffer::OutlineWidthMode::Screen,
+                    },
+                    outline_color: Some(&toon.outline_color_factor.to_flatbuffer()),
+                    rim_color: Some(&toon.rim_color_factor.to_flatbuffer()),
+                    rim_fresnel_power: toon.rim_fresnel_power,
+                    rim_lift: toon.rim_lift_factor,
+                    rim_lighting_mix: toon.rim_lighting_mix_factor,
+                },
+            )
+        });
+
+        let extensions: Vec<_> = self
+            .extensions
+            .iter()
+            .map(|extension| extension.to_flatbuffer(builder))
+            .collect();
+        let extensions = builder.create_vector(&extensions);
+
+        let mut texture = |texture: &Option<RenpyImage>| {
+            texture
+                .as_ref()
+                .filter(|_| with_textures)
+                .map(|texture| texture.to_flatbuffer(builder))
+        };
+        let occlusion_texture = texture(&self.occlusion_texture);
+        let metallic_roughness_texture = texture(&self.metallic_roughness_texture);
+        let metallic_texture = texture(&self.metallic_texture);
+        let roughness_texture = texture(&self.roughness_texture);
+        let emissive_texture = texture(&self.emissive_texture);
+
+        flatbuffer::Material::create(
+            builder,
+            &flatbuffer::MaterialArgs {
+                name: Some(name),
+                base_color_factor: Some(&flatbuffer::RGBAColor::new(
+                    &self.base_color_factor.into(),
+                )),
+                unlit: self.unlit,
+                emissive_factor: Some(&self.emissive_factor.to_flatbuffer()),
+                emissive_strength: self.emissive_strength,
+                toon,
+                extensions: Some(extensions),
+                occlusion_texture,
+                occlusion_strength: self.occlusion_strength,
+                metallic_roughness_texture,
+                metallic_texture,
+                roughness_texture,
+                metallic_factor: self.metallic_factor,
+                roughness_factor: self.roughness_factor,
+                emissive_texture,
+            },
+        )
+    }
+}
@@ -1,18 +1,33 @@
-use std::ops::Neg;
-
+use cgmath::Matrix4;
 use gltf_for_renpy_flatbuffer::{self as flatbuffer, MeshArgs};
-use gltf_loader::model::{MorphTarget, Skeleton, VertexAttributeIterator};
+use gltf_loader::model::{Mode, MorphTarget, Skeleton, VertexAttributeIterator};
 use nohash_hasher::IntSet;
 
+use crate::bounds::{Aabb, BoundingSphere};
 use crate::gltf_loader::utils::DecomposedTransform;
-use crate::{AnimationSet, FlatbufferConversion, RenpyImage, SimpleFlatbufferConversion};
-
-use super::{ObjectId, property::Properties};
+use crate::mesh_processing::{
+    bake,
+    lod::{self, MeshLod},
+    optimize::{self, VertexCacheStats},
+    weld,
+};
+use crate::{
+    AnimationSet, ExportOptions, FlatbufferConversion, RenpyImage, SimpleFlatbufferConversion,
+};
+
+use super::{ObjectId, material::RenpyMaterial, property::Properties};
+use crate::renpy_interop::NodeID;
 
 #[derive(Clone, Debug)]
 pub struct Mesh {
     pub name: String,
 
+    // Unique path like `Scene/Armature/Body/0`, see `node_path`
+    pub path: String,
+
+    // Name of the glTF node, shared by every primitive of its mesh
+    pub node_name: Option<String>,
+
     pub id: ObjectId,
 
     pub vertexes: Vec<f32>,
@@ -20,6 +35,7 @@ pub struct Mesh {
     pub triangles: Vec<u32>,
 
     pub default_transform: DecomposedTransform,
+    pub rest_matrix: Matrix4<f32>,
 
     pub skeleton: Option<Skeleton>,
     pub bone_indexes: Vec<u16>,
@@ -33,26 +49,72 @@ pub struct Mesh {
     pub uvs: Vec<f32>,
 
     pub texture: RenpyImage,
+    pub material: RenpyMaterial,
 
     pub properties: Properties,
+
+    // Only filled when the optimization pass ran on the mesh
+    pub cache_stats: Option<VertexCacheStats>,
+
+    // Index buffers of the simplified versions of the mesh, from most to least detailed
+    pub lods: Vec<MeshLod>,
+
+    // Bounds of the points before the transform is applied
+    pub bounds: Aabb,
+    pub bounding_sphere: BoundingSphere,
+
+    // (mesh, primitive) index in the file, meshes with the same key started out with the same geometry
+    pub geometry_key: (usize, usize),
+    // Node that holds the geometry when this mesh is just another instance of it (see `mesh_processing::instancing`)
+    pub instance_of: Option<NodeID>,
+
+    // EXT_mesh_gpu_instancing transforms, relative to the mesh
+    pub instances: Vec<DecomposedTransform>,
+    pub instance_matrices: Vec<Matrix4<f32>>,
+
+    // Material and base color texture for each material variant that changes this mesh (KHR_materials_variants)
+    pub variants: Vec<MaterialVariant>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MaterialVariant {
+    // Index into the `variants` of the scene
+    pub variant: u32,
+    // The base color is sent as a texture like the one of the mesh, the rest of the material goes with it
+    pub texture: RenpyImage,
+    pub material: RenpyMaterial,
+}
+
+fn base_color_image(material: &gltf_loader::Material, options: &ExportOptions) -> RenpyImage {
+    let pbr_material = &material.pbr;
+
+    RenpyImage::load_image(
+        &pbr_material.base_color_texture,
+        &pbr_material.base_color_texture_name,
+        &Some(pbr_material.base_color_factor),
+        pbr_material.base_color_sampler,
+        options,
+    )
 }
 
 impl Mesh {
     pub fn create(
         model: &gltf_loader::Model,
         scene_name: String,
-        use_embed_textures: bool,
+        path: String,
+        options: &ExportOptions,
     ) -> super::GltfObject {
+        let convention = &options.coordinate_convention;
+
         let mut model_points: Vec<f32> = Vec::with_capacity(model.vertices_len().saturating_mul(3));
         let mut uvs: Vec<f32> = Vec::with_capacity(model.vertices_len().saturating_mul(2));
 
         let id = model.index();
 
         for vertex in model.vertices() {
-            model_points.push(vertex.position.x);
-            // We need to negate the y position as if we don't then the model will be upside down???
-            model_points.push(vertex.position.y.neg());
-            model_points.push(vertex.position.z);
+            // Flips/swaps the axes for renpy (y goes down by default), see `CoordinateConvention`
+            let position = convention.convert_point(vertex.position);
+            model_points.extend_from_slice(&[position.x, position.y, position.z]);
 
             uvs.push(vertex.tex_coords.x);
             uvs.push(vertex.tex_coords.y);
@@ -68,14 +130,18 @@ impl Mesh {
             }
         }
 
-        let pbr_material = &model.material().pbr;
-
-        let image = RenpyImage::load_image(
-            &pbr_material.base_color_texture,
-            &pbr_material.base_color_texture_name,
-            &Some(pbr_material.base_color_factor),
-            use_embed_textures,
-        );
+        let image = base_color_image(&model.material(), options);
+        let variants = model
+            .material_variants()
+            .iter()
+            .filter_map(|(variant, material)| {
+                Some(MaterialVariant {
+                    variant: u32::try_from(*variant).ok()?,
+                    texture: base_color_image(material, options),
+                    material: RenpyMaterial::load(material, options),
+                })
+            })
+            .collect();
 
         let name = format!(
             "{}:{}:{}",
@@ -84,19 +150,45 @@ impl Mesh {
             model.primitive_index()
         );
 
-        let default_transform = model.transform().to_owned().to_renpy_coords(false);
-
-        let animations: Vec<AnimationSet> = AnimationSet::from_node(model.animations());
-
-        let morph_targets: Vec<MorphTarget> = model.morph_targets().clone();
+        let default_transform = model
+            .transform()
+            .to_owned()
+            .to_converted_coords(convention, false);
+        // Taken from the matrix instead of the transform so shear isn't lost
+        let rest_matrix = convention.convert_matrix(model.matrix());
+
+        let animations: Vec<AnimationSet> = AnimationSet::from_node(model.animations(), convention);
+
+        let mut morph_targets: Vec<MorphTarget> = model.morph_targets().clone();
+        // The offsets have to move the same way as the points
+        for shape in morph_targets
+            .iter_mut()
+            .flat_map(|target| &mut target.blend_shapes)
+        {
+            shape.position = convention.convert_point(shape.position);
+            shape.normal = convention.convert_direction(shape.normal);
+        }
         let morph_weights: Vec<f32> = model.morph_weights().clone();
 
         let skeleton: Option<Skeleton> = model.skeleton().clone();
         let bone_indexes = model.bone_indexes().clone();
         let bone_weights = model.bone_weights().clone();
 
-        let mesh = Mesh {
+        let instances = model
+            .instances()
+            .iter()
+            .map(|instance| instance.clone().to_converted_coords(convention, false))
+            .collect();
+        let instance_matrices = model
+            .instances()
+            .iter()
+            .map(|instance| convention.convert_matrix(instance.clone().into()))
+            .collect();
+
+        let mut mesh = Mesh {
             name,
+            path,
+            node_name: model.node_name().map(str::to_owned),
             id,
             vertexes: model_points,
             morph_targets,
@@ -104,25 +196,87 @@ impl Mesh {
             skeleton,
             triangles,
             default_transform,
+            rest_matrix,
             animations,
             uvs,
             texture: image,
+            material: RenpyMaterial::load(&model.material(), options),
             properties: Vec::new(),
             bone_indexes,
             bone_weights,
+            cache_stats: None,
+            lods: Vec::new(),
+            bounds: Aabb::empty(),
+            bounding_sphere: BoundingSphere::default(),
+            geometry_key: (model.mesh_index(), model.primitive_index()),
+            instance_of: None,
+            instances,
+            instance_matrices,
+            variants,
         };
 
+        if model.mode() == Mode::Triangles {
+            if options.weld_vertices {
+                let normals: Vec<f32> = model
+                    .vertices()
+                    .iter()
+                    .flat_map(|vertex| [vertex.normal.x, vertex.normal.y, vertex.normal.z])
+                    .collect();
+                weld::weld_vertices(&mut mesh, &normals, options.weld_epsilon);
+            }
+
+            if options.optimize_meshes {
+                mesh.cache_stats = optimize::optimize_mesh(&mut mesh);
+            }
+
+            if !options.lod_levels.is_empty() {
+                mesh.lods = lod::generate_lods(
+                    &mesh,
+                    &options.lod_levels,
+                    options.lod_max_error,
+                    options.optimize_meshes,
+                );
+            }
+        }
+
+        mesh.update_bounds();
+
         let mut associated_object_ids: IntSet<ObjectId> = IntSet::default();
         associated_object_ids.insert(mesh.id);
 
         super::GltfObject::Mesh(associated_object_ids, Box::new(mesh))
     }
 
+    /// Recomputes `bounds` and `bounding_sphere` after the points changed
+    pub fn update_bounds(&mut self) {
+        self.bounds = Aabb::from_points(&self.vertexes);
+        self.bounding_sphere = BoundingSphere::from_points(&self.vertexes, &self.bounds);
+    }
+
+    /// Bounds holding every GPU instance of the mesh, same as `bounds` when it isn't instanced
+    pub fn instanced_bounds(&self, bounds: &Aabb) -> Aabb {
+        if self.instance_matrices.is_empty() {
+            return *bounds;
+        }
+
+        self.instance_matrices
+            .iter()
+            .fold(Aabb::empty(), |total, matrix| {
+                total.union(&bounds.transformed(matrix))
+            })
+    }
+
+    /// Number of vertices in the mesh (each vertex takes 3 floats in `vertexes`)
+    pub fn vertex_count(&self) -> usize {
+        self.vertexes.chunks_exact(3).len()
+    }
+
     pub fn to_flatbuffer<'a>(
         &self,
         builder: &mut flatbuffers::FlatBufferBuilder<'a>,
     ) -> flatbuffers::WIPOffset<flatbuffer::Mesh<'a>> {
         let name = builder.create_string(&self.name);
+        let path = builder.create_string(&self.path);
 
         let animations = if self.animations.is_empty() {
             Some(builder.create_vector::<flatbuffers::WIPOffset<_>>(&[]))
@@ -143,16 +297,16 @@ impl Mesh {
 
         let properties = builder.create_vector(&properties);
 
-        let points = builder.create_vector(&self.vertexes);
-        let triangles = builder.create_vector(&self.triangles);
+        // Instances only point to the node with the geometry so none of it gets written again
+        let shared = self.instance_of.is_some();
 
-        let (has_uvs, uvs) = if self.uvs.is_empty() {
-            (false, None)
-        } else {
-            (true, Some(builder.create_vector(&self.uvs)))
-        };
+        let points = builder.create_vector(if shared { &[] } else { &self.vertexes[..] });
+        let triangles = builder.create_vector(if shared { &[] } else { &self.triangles[..] });
 
-        let texture = self.texture.to_flatbuffer(builder);
+        let has_uvs = !self.uvs.is_empty();
+        let uvs = (has_uvs && !shared).then(|| builder.create_vector(&self.uvs));
+
+        let texture = (!shared).then(|| self.texture.to_flatbuffer(builder));
 
         let default_morph_weights = if self.morph_weights.is_empty() {
             None
@@ -162,6 +316,9 @@ impl Mesh {
 
         let (target_count, morph_targets) = if self.morph_targets.is_empty() {
             (0_u8, None)
+        } else if shared {
+            #[allow(clippy::cast_possible_truncation)]
+            (self.morph_targets.len().min(u8::MAX.into()) as u8, None)
         } else {
             let morph_buffer: Vec<_> = self
                 .morph_targets
@@ -190,7 +347,9 @@ impl Mesh {
 
         // This may be a mess, but it is my contained mess...
         // Everything here is the best way to use my limited rust knowledge to automate this tedious task
-        let attributes = {
+        let attributes = if shared {
+            Some(builder.create_vector::<f32>(&[]))
+        } else {
             let mut uv_iter: VertexAttributeIterator<_, 2> =
                 VertexAttributeIterator::new(self.uvs.iter());
             let target_iter = self
@@ -245,6 +404,48 @@ impl Mesh {
             Some(builder.create_vector(&attributes_vec))
         };
 
+        let lods = if self.lods.is_empty() || shared {
+            None
+        } else {
+            let lod_buffer: Vec<_> = self
+                .lods
+                .iter()
+                .map(|lod| lod.to_flatbuffer(builder))
+                .collect();
+            Some(builder.create_vector(&lod_buffer))
+        };
+
+        let variants = (!self.variants.is_empty() && !shared).then(|| {
+            let variants: Vec<_> = self
+                .variants
+                .iter()
+                .map(|variant| {
+                    let texture = variant.texture.to_flatbuffer(builder);
+                    let material = variant.material.to_flatbuffer(builder, true);
+                    flatbuffer::MaterialVariant::create(
+                        builder,
+                        &flatbuffer::MaterialVariantArgs {
+                            variant: variant.variant,
+                            texture: Some(texture),
+                            material: Some(material),
+                        },
+                    )
+                })
+                .collect();
+            builder.create_vector(&variants)
+        });
+
+        let material = self.material.to_flatbuffer(builder, !shared);
+
+        let instances = (!self.instances.is_empty()).then(|| {
+            let instances: Vec<_> = self
+                .instances
+                .iter()
+                .map(|instance| instance.to_flatbuffer())
+                .collect();
+            builder.create_vector(&instances)
+        });
+
         flatbuffer::Mesh::create(
             builder,
             &MeshArgs {
@@ -260,8 +461,21 @@ impl Mesh {
                 uvs,
                 layout_type: Some(&layout),
                 attributes,
-                texture: Some(texture),
+                texture,
                 properties: Some(properties),
+                lods,
+                bounds: self.bounds.to_flatbuffer().as_ref(),
+                bounding_sphere: Some(&self.bounding_sphere.to_flatbuffer()),
+                matrix: Some(&self.rest_matrix.to_flatbuffer()),
+                transform_is_lossy: bake::is_lossy(&self.rest_matrix),
+                path: Some(path),
+                instance_of: self
+                    .instance_of
+                    .and_then(|node| i32::try_from(node).ok())
+                    .unwrap_or(-1),
+                instances,
+                variants,
+                material: Some(material),
             },
         )
     }
@@ -1,12 +1,16 @@
 use crate::SceneTree;
+use crate::SimpleFlatbufferConversion;
+use crate::bounds::Aabb;
 use crate::renpy_interop::NodeID;
+use cgmath::Matrix4;
 use gltf_for_renpy_flatbuffer as flatbuffer;
-use gltf_loader::utils::DecomposedTransform;
+use gltf_loader::utils::{CoordinateConvention, DecomposedTransform};
 use nohash_hasher::IntSet;
 
 pub type ObjectId = usize;
 
 pub mod empty;
+pub mod material;
 pub mod mesh;
 
 pub mod property;
@@ -20,6 +24,70 @@ pub enum GltfObject {
 }
 
 impl GltfObject {
+    /// Local transform of the default pose as a matrix (in renpy coords)
+    pub fn rest_matrix(&self) -> &Matrix4<f32> {
+        match &self {
+            GltfObject::Empty(_, empty) => &empty.rest_matrix,
+            GltfObject::Mesh(_, mesh) => &mesh.rest_matrix,
+        }
+    }
+
+    /// Replaces the local transform of the default pose, `matrix` being in glTF coords
+    pub fn set_rest_transform(&mut self, matrix: Matrix4<f32>, convention: &CoordinateConvention) {
+        let transform = DecomposedTransform::convert_from_gltf(gltf::scene::Transform::Matrix {
+            matrix: matrix.into(),
+        })
+        .to_converted_coords(convention, false);
+        let rest_matrix = convention.convert_matrix(matrix);
+
+        match self {
+            GltfObject::Empty(_, empty) => {
+                empty.transform = transform;
+                empty.rest_matrix = rest_matrix;
+            }
+            GltfObject::Mesh(_, mesh) => {
+                mesh.default_transform = transform;
+                mesh.rest_matrix = rest_matrix;
+            }
+        }
+    }
+
+    pub fn animations(&self) -> &[crate::AnimationSet] {
+        match &self {
+            GltfObject::Empty(_, empty) => &empty.animations,
+            GltfObject::Mesh(_, mesh) => &mesh.animations,
+        }
+    }
+
+    pub fn id(&self) -> ObjectId {
+        match &self {
+            GltfObject::Empty(_, empty) => empty.id,
+            GltfObject::Mesh(_, mesh) => mesh.id,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match &self {
+            GltfObject::Empty(_, empty) => &empty.name,
+            GltfObject::Mesh(_, mesh) => &mesh.name,
+        }
+    }
+
+    /// Name of the glTF node the object comes from, the one users gave it in their editor
+    pub fn node_name(&self) -> Option<&str> {
+        match &self {
+            GltfObject::Empty(_, empty) => empty.node_name.as_deref(),
+            GltfObject::Mesh(_, mesh) => mesh.node_name.as_deref(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        match &self {
+            GltfObject::Empty(_, empty) => &empty.path,
+            GltfObject::Mesh(_, mesh) => &mesh.path,
+        }
+    }
+
     pub fn is_same_id(&self, other_id: usize) -> bool {
         match &self {
             GltfObject::Empty(_, empty) => empty.id == other_id,
@@ -36,6 +104,17 @@ pub struct RenpyScene {
     pub properties: crate::Properties,
     pub mesh_indexes: Vec<NodeID>,
     pub empty_indexes: Vec<NodeID>,
+    // Index of the scene in the file
+    pub index: usize,
+    pub is_default: bool,
+    // Outfits, hairstyles... that renpy can show or hide by name
+    pub groups: Vec<crate::groups::VisibilityGroup>,
+    // Names of the material variants, meshes refer to them by index
+    pub variants: Vec<String>,
+    // Scene space bounds of the meshes in the default pose
+    pub bounds: Aabb,
+    // Only computed when asked for since every keyframe has to be evaluated
+    pub animated_bounds: Option<Aabb>,
 }
 
 pub(crate) fn convert_scene_to_flatbuffer<'a>(
@@ -64,7 +143,52 @@ pub(crate) fn convert_scene_to_flatbuffer<'a>(
     let mut temp_nodes: Vec<flatbuffers::WIPOffset<gltf_for_renpy_flatbuffer::Node<'_>>> =
         Vec::new();
 
-    for object in old_scene.objects.nodes {
+    let parents = old_scene.objects.parents();
+    let world_matrices = old_scene
+        .objects
+        .world_matrices(|node| *node.value.rest_matrix());
+    let evaluation_order = builder.create_vector(&old_scene.objects.evaluation_order());
+
+    // Flatbuffers compares the keys byte by byte which is the same as how rust sorts strings
+    let mut names: Vec<(&String, &Vec<NodeID>)> = old_scene.objects.index.by_name.iter().collect();
+    names.sort();
+    let names: Vec<_> = names
+        .into_iter()
+        .map(|(name, indices)| {
+            let name = builder.create_string(name);
+            let indices = builder.create_vector(indices);
+            flatbuffer::NodeName::create(
+                builder,
+                &flatbuffer::NodeNameArgs {
+                    name: Some(name),
+                    indices: Some(indices),
+                },
+            )
+        })
+        .collect();
+    let node_names = builder.create_vector(&names);
+
+    let groups: Vec<_> = old_scene
+        .groups
+        .iter()
+        .map(|group| group.to_flatbuffer(builder))
+        .collect();
+    let groups = builder.create_vector(&groups);
+
+    let variants: Vec<_> = old_scene
+        .variants
+        .iter()
+        .map(|variant| builder.create_string(variant))
+        .collect();
+    let variants = builder.create_vector(&variants);
+
+    for ((object, parent), world_matrix) in old_scene
+        .objects
+        .nodes
+        .into_iter()
+        .zip(parents)
+        .zip(world_matrices)
+    {
         let children = builder.create_vector(&object.children);
 
         let (object_type, object) = match object.value {
@@ -84,6 +208,10 @@ pub(crate) fn convert_scene_to_flatbuffer<'a>(
                 children: Some(children),
                 object_type,
                 object: Some(object),
+                parent: parent
+                    .and_then(|parent| i32::try_from(parent).ok())
+                    .unwrap_or(-1),
+                world_matrix: Some(&world_matrix.to_flatbuffer()),
             },
         ));
     }
@@ -102,6 +230,17 @@ pub(crate) fn convert_scene_to_flatbuffer<'a>(
             root_nodes: Some(root_nodes),
             model_index: mesh_index,
             empty_index,
+            bounds: old_scene.bounds.to_flatbuffer().as_ref(),
+            animated_bounds: old_scene
+                .animated_bounds
+                .and_then(|bounds| bounds.to_flatbuffer())
+                .as_ref(),
+            evaluation_order: Some(evaluation_order),
+            node_names: Some(node_names),
+            index: u32::try_from(old_scene.index).unwrap_or(u32::MAX),
+            is_default: old_scene.is_default,
+            groups: Some(groups),
+            variants: Some(variants),
         },
     )
 }
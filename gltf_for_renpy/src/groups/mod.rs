@@ -0,0 +1,185 @@
+// Visibility groups so renpy can switch outfits, hairstyles and accessories by name
+// A node joins a group through its extras (`"group": "outfit_school"` or `"group": ["outfit_school", "glasses"]`)
+
+use std::collections::{BTreeMap, HashMap};
+
+use gltf_for_renpy_flatbuffer as flatbuffer;
+use serde::Deserialize;
+
+use crate::SceneTree;
+use crate::renpy_interop::NodeID;
+
+/// How groups are read from the file
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct GroupOptions {
+    /// Extra property holding the group name (or a list of names), checked on the node and on the mesh it uses
+    pub key: String,
+
+    /// Groups named `<set><separator><name>` are mutually exclusive with the other groups of the same set
+    /// (with `_`, `outfit_school` and `outfit_casual` are both in `outfit`). None, the default, to not make any set
+    /// so names like `hair_long` stay plain groups.
+    pub exclusive_separator: Option<String>,
+}
+
+impl Default for GroupOptions {
+    fn default() -> Self {
+        Self {
+            key: "group".to_owned(),
+            exclusive_separator: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VisibilityGroup {
+    pub name: String,
+    // Tagged nodes and everything under them, sorted
+    pub nodes: Vec<NodeID>,
+    pub exclusive_set: Option<String>,
+    // Indexes of the other groups of the set
+    pub exclusive_with: Vec<u32>,
+}
+
+/// The groups a loaded object was tagged with
+pub fn object_groups(object: &gltf_loader::SceneObject, key: &str) -> Vec<String> {
+    let extras = match object {
+        gltf_loader::SceneObject::Root => return Vec::new(),
+        gltf_loader::SceneObject::Mesh(model) => vec![model.node_extras(), model.mesh_extras()],
+        gltf_loader::SceneObject::Empties(empty) => vec![&empty.extras],
+    };
+
+    let mut groups: Vec<String> = extras
+        .into_iter()
+        .flatten()
+        .filter_map(|extras| extras.get(key))
+        .flat_map(|value| parse_groups(value))
+        .collect();
+    groups.sort_unstable();
+    groups.dedup();
+    groups
+}
+
+// Extras are turned into json strings by the loader so `"outfit"` still has its quotes
+fn parse_groups(value: &str) -> Vec<String> {
+    match gltf::json::deserialize::from_str::<gltf::json::Value>(value) {
+        Ok(gltf::json::Value::String(name)) => vec![name],
+        Ok(gltf::json::Value::Array(names)) => names
+            .into_iter()
+            .filter_map(|name| match name {
+                gltf::json::Value::String(name) => Some(name),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+    .into_iter()
+    .filter(|name| !name.is_empty())
+    .collect()
+}
+
+/// Builds the group table out of the nodes tagged while loading, sorted by name.
+/// Children of a tagged node are part of its groups too.
+pub fn build_groups(
+    tree: &SceneTree,
+    tagged: &[(NodeID, Vec<String>)],
+    options: &GroupOptions,
+) -> Vec<VisibilityGroup> {
+    let mut members: BTreeMap<&str, Vec<NodeID>> = BTreeMap::new();
+
+    for (node, groups) in tagged {
+        let subtree = subtree(tree, *node);
+        for group in groups {
+            members.entry(group).or_default().extend(&subtree);
+        }
+    }
+
+    let mut groups: Vec<VisibilityGroup> = members
+        .into_iter()
+        .map(|(name, mut nodes)| {
+            nodes.sort_unstable();
+            nodes.dedup();
+
+            let exclusive_set = options.exclusive_separator.as_ref().and_then(|separator| {
+                name.split_once(separator.as_str())
+                    .map(|(set, _)| set.to_owned())
+                    .filter(|set| !set.is_empty())
+            });
+
+            VisibilityGroup {
+                name: name.to_owned(),
+                nodes,
+                exclusive_set,
+                exclusive_with: Vec::new(),
+            }
+        })
+        .collect();
+
+    link_sets(&mut groups);
+    groups
+}
+
+/// Fills `exclusive_with` from the set of every group (also needed after the groups are reordered)
+pub fn link_sets(groups: &mut [VisibilityGroup]) {
+    let mut sets: HashMap<String, Vec<u32>> = HashMap::new();
+    for (index, group) in (0_u32..).zip(groups.iter()) {
+        if let Some(set) = &group.exclusive_set {
+            sets.entry(set.clone()).or_default().push(index);
+        }
+    }
+
+    for (index, group) in (0_u32..).zip(groups.iter_mut()) {
+        group.exclusive_with = group
+            .exclusive_set
+            .as_ref()
+            .and_then(|set| sets.get(set))
+            .map(|others| {
+                others
+                    .iter()
+                    .copied()
+                    .filter(|other| *other != index)
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+}
+
+fn subtree(tree: &SceneTree, node: NodeID) -> Vec<NodeID> {
+    let mut nodes = Vec::new();
+    let mut stack = vec![node];
+
+    while let Some(node_id) = stack.pop() {
+        let Some(node) = tree.get_node(node_id) else {
+            continue;
+        };
+        nodes.push(node_id);
+        stack.extend(&node.children);
+    }
+
+    nodes
+}
+
+impl VisibilityGroup {
+    pub fn to_flatbuffer<'a>(
+        &self,
+        builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> flatbuffers::WIPOffset<flatbuffer::VisibilityGroup<'a>> {
+        let name = builder.create_string(&self.name);
+        let nodes = builder.create_vector(&self.nodes);
+        let exclusive_set = self
+            .exclusive_set
+            .as_ref()
+            .map(|set| builder.create_string(set));
+        let exclusive_with = builder.create_vector(&self.exclusive_with);
+
+        flatbuffer::VisibilityGroup::create(
+            builder,
+            &flatbuffer::VisibilityGroupArgs {
+                name: Some(name),
+                nodes: Some(nodes),
+                exclusive_set,
+                exclusive_with: Some(exclusive_with),
+            },
+        )
+    }
+}
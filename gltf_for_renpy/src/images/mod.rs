@@ -2,13 +2,167 @@
 
 use std::sync::Arc;
 
-use gltf_for_renpy_flatbuffer::{ImageNameArgs, RGBAColor};
+use gltf_for_renpy_flatbuffer::{self as flatbuffer, ImageNameArgs, RGBAColor};
+use rayon::prelude::*;
+use serde::Deserialize;
+
+use crate::ExportOptions;
+use gltf_loader::model::{Filter, Sampler, WrapMode};
+
+/// How the embedded textures are prepared
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct TextureOptions {
+    /// Multiply the base color factor into the embedded texels (in linear space).
+    /// When false, or when the texture isn't embedded, the factor is only sent in the material.
+    pub bake_base_color_factor: bool,
+
+    /// Multiply the color of the embedded texels by their alpha for premultiplied blending
+    pub premultiply_alpha: bool,
+
+    /// How the occlusion, metallic-roughness and emissive textures are sent in the material
+    pub pbr_textures: PbrTextures,
+
+    /// How the texels of the embedded images are stored
+    pub encoding: ImageEncoding,
+
+    /// Generate the mip levels of the embedded images, averaging the sRGB colors in linear space
+    pub generate_mipmaps: bool,
+
+    /// How many texels the colors of the base color images get extended into their fully transparent areas,
+    /// so that filtering doesn't bring dark fringes around cutouts. 0 turns it off.
+    pub dilation_radius: u32,
+}
+
+impl Default for TextureOptions {
+    fn default() -> Self {
+        Self {
+            bake_base_color_factor: true,
+            premultiply_alpha: false,
+            pbr_textures: PbrTextures::None,
+            encoding: ImageEncoding::Raw,
+            generate_mipmaps: false,
+            dilation_radius: 0,
+        }
+    }
+}
+
+/// How the textures of the material other than the base color get exported
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum PbrTextures {
+    /// Only the base color texture is sent
+    #[default]
+    None,
+    /// The images as they are in the file (occlusion in red, roughness in green and metallic in blue)
+    Packed,
+    /// One grayscale image per channel (occlusion, metallic and roughness)
+    Channels,
+}
+
+/// How the texels of an embedded image are stored, they get decoded lazily with `decode_image_data`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum ImageEncoding {
+    /// The texels as they are
+    #[default]
+    Raw,
+    /// Lossless and small, but slow to decode
+    Png,
+    /// Lossless and fast to decode. Gray images can't be stored as QOI and stay raw.
+    Qoi,
+    /// The raw texels compressed with zstd
+    Zstd,
+}
+
+impl TryFrom<i8> for ImageEncoding {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ImageEncoding::Raw),
+            1 => Ok(ImageEncoding::Png),
+            2 => Ok(ImageEncoding::Qoi),
+            3 => Ok(ImageEncoding::Zstd),
+            other => Err(anyhow::anyhow!("Unknown image encoding {other}")),
+        }
+    }
+}
+
+fn color_type(channels: u8) -> anyhow::Result<image::ExtendedColorType> {
+    match channels {
+        1 => Ok(image::ExtendedColorType::L8),
+        4 => Ok(image::ExtendedColorType::Rgba8),
+        other => Err(anyhow::anyhow!(
+            "Images with {other} channels aren't embedded"
+        )),
+    }
+}
+
+/// Encodes `pixels` (`channels` bytes per texel), raw data is only copied
+pub fn encode_pixels(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    channels: u8,
+    encoding: ImageEncoding,
+) -> anyhow::Result<Vec<u8>> {
+    use image::ImageEncoder;
+
+    let color_type = color_type(channels)?;
+    let mut encoded = Vec::new();
+    match encoding {
+        ImageEncoding::Raw => encoded = pixels.to_vec(),
+        ImageEncoding::Png => image::codecs::png::PngEncoder::new(&mut encoded)
+            .write_image(pixels, width, height, color_type)?,
+        ImageEncoding::Qoi => image::codecs::qoi::QoiEncoder::new(&mut encoded)
+            .write_image(pixels, width, height, color_type)?,
+        ImageEncoding::Zstd => encoded = zstd::bulk::compress(pixels, 0)?,
+    }
+
+    Ok(encoded)
+}
+
+/// Turns data made by `encode_pixels` back into raw texels with `channels` bytes each
+pub fn decode_pixels(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    channels: u8,
+    encoding: ImageEncoding,
+) -> anyhow::Result<Vec<u8>> {
+    color_type(channels)?;
+    let size = usize::try_from(u64::from(width).saturating_mul(u64::from(height)))?
+        .saturating_mul(usize::from(channels));
+
+    let pixels = match encoding {
+        ImageEncoding::Raw => data.to_vec(),
+        ImageEncoding::Png | ImageEncoding::Qoi => {
+            let format = if encoding == ImageEncoding::Png {
+                image::ImageFormat::Png
+            } else {
+                image::ImageFormat::Qoi
+            };
+            let image = image::load_from_memory_with_format(data, format)?;
+            if channels == 1 {
+                image.into_luma8().into_raw()
+            } else {
+                image.into_rgba8().into_raw()
+            }
+        }
+        ImageEncoding::Zstd => zstd::bulk::decompress(data, size)?,
+    };
+
+    if pixels.len() != size {
+        anyhow::bail!("Decoded image has {} bytes instead of {size}", pixels.len());
+    }
+    Ok(pixels)
+}
 
 #[derive(Clone, Debug)]
 pub enum ImageData {
     ImageName,
     RGBA(Vec<u8>),
     Color([f32; 4]),
+    Gray(Vec<u8>),
 }
 
 // Image data to be sent to the python code later
@@ -18,6 +172,255 @@ pub struct RenpyImage {
     pub data: ImageData,
     pub width: u32,
     pub height: u32,
+    // The base color factor is already in the data
+    pub factor_applied: bool,
+    // The color of the data is multiplied by its alpha
+    pub premultiplied: bool,
+    // None for colors
+    pub sampler: Option<Sampler>,
+    // How the RGBA/Gray data is stored
+    pub encoding: ImageEncoding,
+    // Smaller versions of the RGBA/Gray data, stored like it
+    pub mip_levels: Vec<MipLevel>,
+}
+
+/// A level of the mip chain of an embedded image
+#[derive(Clone, Debug)]
+pub struct MipLevel {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn to_byte(value: f32) -> u8 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    // Clamped to the range of a byte before the cast
+    let byte = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    byte
+}
+
+// sRGB byte to linear value
+fn linear_table() -> [f32; 256] {
+    std::array::from_fn(|value| {
+        u8::try_from(value).map_or(1.0, |value| srgb_to_linear(f32::from(value) / 255.0))
+    })
+}
+
+/// Multiplies sRGB encoded RGBA texels by a linear `factor` and optionally by their own alpha.
+/// The factor is applied in linear space, the alpha is applied to the encoded sRGB values afterwards since that's
+/// what premultiplied blending of sRGB textures works with (half transparent white is 128, not 188).
+pub fn apply_factor(pixels: &mut [u8], factor: [f32; 4], premultiply: bool) {
+    let to_linear = linear_table();
+    let linear = |value: u8| to_linear.get(usize::from(value)).copied().unwrap_or(1.0);
+    let [red, green, blue, alpha] = factor;
+
+    pixels.par_chunks_exact_mut(4).for_each(|pixel| {
+        let [r, g, b, a] = pixel else {
+            return;
+        };
+
+        let new_alpha = f32::from(*a) / 255.0 * alpha;
+        let coverage = if premultiply {
+            new_alpha.clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        *r = to_byte(linear_to_srgb(linear(*r) * red) * coverage);
+        *g = to_byte(linear_to_srgb(linear(*g) * green) * coverage);
+        *b = to_byte(linear_to_srgb(linear(*b) * blue) * coverage);
+        *a = to_byte(new_alpha);
+    });
+}
+
+/// Extends the colors of the RGBA texels that aren't fully transparent into the fully transparent ones,
+/// up to `radius` texels away. Each step gives the transparent texels touching colored ones the average
+/// color of those neighbours, their alpha stays at 0.
+pub fn dilate(pixels: &mut [u8], width: u32, height: u32, radius: u32) {
+    let width = usize::try_from(width).unwrap_or_default();
+    let height = usize::try_from(height).unwrap_or_default();
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    let mut colored: Vec<bool> = pixels
+        .chunks_exact(4)
+        .map(|texel| texel.get(3).is_some_and(|&alpha| alpha > 0))
+        .collect();
+
+    for _ in 0..radius {
+        let filled: Vec<Option<[u8; 3]>> = (0..colored.len())
+            .into_par_iter()
+            .map(|index| {
+                if colored.get(index) != Some(&false) {
+                    return None;
+                }
+
+                let (x, y) = (index.rem_euclid(width), index.div_euclid(width));
+                let mut sum = [0_u32; 3];
+                let mut count = 0_u32;
+                for neighbour_y in
+                    y.saturating_sub(1)..=y.saturating_add(1).min(height.saturating_sub(1))
+                {
+                    for neighbour_x in
+                        x.saturating_sub(1)..=x.saturating_add(1).min(width.saturating_sub(1))
+                    {
+                        let neighbour = neighbour_y
+                            .saturating_mul(width)
+                            .saturating_add(neighbour_x);
+                        let start = neighbour.saturating_mul(4);
+                        if colored.get(neighbour) == Some(&true)
+                            && let Some(texel) = pixels.get(start..start.saturating_add(3))
+                        {
+                            sum.iter_mut().zip(texel).for_each(|(sum, &value)| {
+                                *sum = sum.saturating_add(u32::from(value))
+                            });
+                            count = count.saturating_add(1);
+                        }
+                    }
+                }
+
+                (count > 0).then(|| {
+                    sum.map(|sum| {
+                        let average = sum.saturating_add(count.div_euclid(2)).div_euclid(count);
+                        u8::try_from(average).unwrap_or(u8::MAX)
+                    })
+                })
+            })
+            .collect();
+
+        if filled.iter().all(Option::is_none) {
+            return;
+        }
+        for (index, color) in filled.into_iter().enumerate() {
+            let Some(color) = color else {
+                continue;
+            };
+            let start = index.saturating_mul(4);
+            if let Some(texel) = pixels.get_mut(start..start.saturating_add(3)) {
+                texel.copy_from_slice(&color);
+            }
+            if let Some(colored) = colored.get_mut(index) {
+                *colored = true;
+            }
+        }
+    }
+}
+
+/// Halves an image (`channels` bytes per texel) with a box filter, odd sizes reusing their last row/column.
+/// When `srgb` is set the color channels are averaged in linear space, alpha is always averaged as is.
+pub fn downsample(pixels: &[u8], width: u32, height: u32, channels: u8, srgb: bool) -> MipLevel {
+    let to_linear = linear_table();
+    let channels = usize::from(channels);
+    let row = usize::try_from(width)
+        .unwrap_or_default()
+        .saturating_mul(channels);
+    let texel = |x: u32, y: u32, channel: usize| {
+        let x = usize::try_from(x.min(width.saturating_sub(1))).unwrap_or_default();
+        let y = usize::try_from(y.min(height.saturating_sub(1))).unwrap_or_default();
+        let index = y
+            .saturating_mul(row)
+            .saturating_add(x.saturating_mul(channels))
+            .saturating_add(channel);
+        pixels.get(index).copied().unwrap_or_default()
+    };
+
+    let (new_width, new_height) = (width.div_euclid(2).max(1), height.div_euclid(2).max(1));
+    let data = (0..new_height)
+        .into_par_iter()
+        .flat_map_iter(|y| {
+            (0..new_width).flat_map(move |x| {
+                (0..channels).map(move |channel| {
+                    let (left, top) = (x.saturating_mul(2), y.saturating_mul(2));
+                    let (right, bottom) = (left.saturating_add(1), top.saturating_add(1));
+                    let color = srgb && channel < 3;
+                    let value = |x, y| {
+                        let value = texel(x, y, channel);
+                        if color {
+                            to_linear.get(usize::from(value)).copied().unwrap_or(1.0)
+                        } else {
+                            f32::from(value) / 255.0
+                        }
+                    };
+
+                    let average = (value(left, top)
+                        + value(right, top)
+                        + value(left, bottom)
+                        + value(right, bottom))
+                        / 4.0;
+                    to_byte(if color {
+                        linear_to_srgb(average)
+                    } else {
+                        average
+                    })
+                })
+            })
+        })
+        .collect();
+
+    MipLevel {
+        width: new_width,
+        height: new_height,
+        data,
+    }
+}
+
+/// Every level after the image itself down to 1x1
+pub fn mip_chain(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    channels: u8,
+    srgb: bool,
+) -> Vec<MipLevel> {
+    let mut levels: Vec<MipLevel> = Vec::new();
+    loop {
+        let (source, width, height) = levels.last().map_or((pixels, width, height), |level| {
+            (level.data.as_slice(), level.width, level.height)
+        });
+        if width <= 1 && height <= 1 {
+            return levels;
+        }
+        let level = downsample(source, width, height, channels, srgb);
+        levels.push(level);
+    }
+}
+
+fn sampler_to_flatbuffer(sampler: Sampler) -> flatbuffer::Sampler {
+    let wrap = |mode: WrapMode| match mode {
+        WrapMode::Repeat => flatbuffer::WrapMode::Repeat,
+        WrapMode::MirroredRepeat => flatbuffer::WrapMode::MirroredRepeat,
+        WrapMode::ClampToEdge => flatbuffer::WrapMode::ClampToEdge,
+    };
+    let filter = |filter: Option<Filter>| match filter {
+        None => flatbuffer::TextureFilter::Unset,
+        Some(Filter::Nearest) => flatbuffer::TextureFilter::Nearest,
+        Some(Filter::Linear) => flatbuffer::TextureFilter::Linear,
+    };
+
+    flatbuffer::Sampler::new(
+        wrap(sampler.wrap_s),
+        wrap(sampler.wrap_t),
+        filter(sampler.mag_filter),
+        filter(sampler.min_filter),
+        filter(sampler.mipmap_filter),
+    )
 }
 
 impl RenpyImage {
@@ -25,11 +428,14 @@ impl RenpyImage {
         raw_image: &Option<Arc<image::RgbaImage>>,
         raw_image_name: &Option<std::sync::Arc<String>>,
         factor: &Option<cgmath::Vector4<f32>>,
-        use_embed_textures: bool,
+        sampler: Sampler,
+        options: &ExportOptions,
     ) -> Self {
         let texture_name: String;
         let data: ImageData;
         let image_size: (u32, u32);
+        let mut factor_applied = false;
+        let mut premultiplied = false;
 
         if let Some(image) = &raw_image {
             let raw_name = (*(*raw_image_name).clone().unwrap_or_default()).clone();
@@ -37,9 +443,30 @@ impl RenpyImage {
 
             image_size = image.dimensions();
 
-            if use_embed_textures {
-                let texture: Vec<u8> = image.to_vec();
-                // All factors are preapplied for embeded textures
+            if options.use_embed_textures {
+                let mut texture: Vec<u8> = image.to_vec();
+                // Premultiplying clears the dilated colors again, premultiplied blending doesn't have fringes anyway
+                if options.textures.dilation_radius > 0 {
+                    dilate(
+                        &mut texture,
+                        image_size.0,
+                        image_size.1,
+                        options.textures.dilation_radius,
+                    );
+                }
+
+                let bake_factor = options.textures.bake_base_color_factor
+                    && factor
+                        .is_some_and(|factor| factor != cgmath::Vector4::new(1.0, 1.0, 1.0, 1.0));
+                premultiplied = options.textures.premultiply_alpha;
+                if bake_factor || premultiplied {
+                    let applied = factor
+                        .filter(|_| bake_factor)
+                        .map_or([1.0; 4], |factor| factor.into());
+                    apply_factor(&mut texture, applied, premultiplied);
+                }
+                factor_applied = bake_factor || factor.is_none();
+
                 data = ImageData::RGBA(texture)
             } else {
                 data = ImageData::ImageName;
@@ -49,7 +476,15 @@ impl RenpyImage {
             image_size = (0, 0);
 
             if let Some(factor) = factor {
-                data = ImageData::Color((*factor).into());
+                let [red, green, blue, alpha]: [f32; 4] = (*factor).into();
+                // The factor is in linear space so no need to convert it
+                premultiplied = options.textures.premultiply_alpha;
+                data = if premultiplied {
+                    ImageData::Color([red * alpha, green * alpha, blue * alpha, alpha])
+                } else {
+                    ImageData::Color([red, green, blue, alpha])
+                };
+                factor_applied = true;
             } else {
                 unimplemented!("Pretty sure this should not be hit but I am not sure tbh");
             }
@@ -62,7 +497,124 @@ impl RenpyImage {
             data,
             width: image_size.0,
             height: image_size.1,
+            factor_applied,
+            premultiplied,
+            sampler: raw_image.is_some().then_some(sampler),
+            encoding: ImageEncoding::Raw,
+            mip_levels: Vec::new(),
         }
+        .with_mip_levels(&options.textures, true)
+        .encode(options.textures.encoding)
+    }
+
+    // Textures other than the base color are sent as they are, without factors
+    fn material_texture(
+        name: &Option<Arc<String>>,
+        size: (u32, u32),
+        sampler: Sampler,
+        srgb: bool,
+        options: &ExportOptions,
+        data: impl FnOnce() -> ImageData,
+    ) -> Self {
+        RenpyImage {
+            name: name.as_deref().cloned().unwrap_or_default(),
+            data: if options.use_embed_textures {
+                data()
+            } else {
+                ImageData::ImageName
+            },
+            width: size.0,
+            height: size.1,
+            factor_applied: false,
+            premultiplied: false,
+            sampler: Some(sampler),
+            encoding: ImageEncoding::Raw,
+            mip_levels: Vec::new(),
+        }
+        .with_mip_levels(&options.textures, srgb)
+        .encode(options.textures.encoding)
+    }
+
+    // Generates the mip levels of the embedded texels when asked to
+    fn with_mip_levels(mut self, options: &TextureOptions, srgb: bool) -> Self {
+        if !options.generate_mipmaps {
+            return self;
+        }
+
+        self.mip_levels = match &self.data {
+            ImageData::RGBA(pixels) => mip_chain(pixels, self.width, self.height, 4, srgb),
+            ImageData::Gray(pixels) => mip_chain(pixels, self.width, self.height, 1, false),
+            ImageData::ImageName | ImageData::Color(_) => Vec::new(),
+        };
+        self
+    }
+
+    // Encodes the embedded texels and their mip levels, they are all kept raw if that fails
+    fn encode(mut self, encoding: ImageEncoding) -> Self {
+        let (channels, pixels) = match &mut self.data {
+            ImageData::RGBA(pixels) => (4, pixels),
+            ImageData::Gray(pixels) => (1, pixels),
+            ImageData::ImageName | ImageData::Color(_) => return self,
+        };
+        // QOI only holds RGB(A)
+        if encoding == ImageEncoding::Raw || (encoding == ImageEncoding::Qoi && channels == 1) {
+            return self;
+        }
+
+        let encoded = std::iter::once((pixels.as_slice(), self.width, self.height))
+            .chain(
+                self.mip_levels
+                    .iter()
+                    .map(|level| (level.data.as_slice(), level.width, level.height)),
+            )
+            .map(|(pixels, width, height)| encode_pixels(pixels, width, height, channels, encoding))
+            .collect::<anyhow::Result<Vec<_>>>();
+
+        if let Ok(mut encoded) = encoded {
+            let levels = encoded.split_off(1);
+            if let Some(data) = encoded.pop() {
+                *pixels = data;
+            }
+            self.mip_levels
+                .iter_mut()
+                .zip(levels)
+                .for_each(|(level, data)| level.data = data);
+            self.encoding = encoding;
+        }
+        self
+    }
+
+    /// An RGB texture of the material (sent as RGBA when embedded), `srgb` being false for data like metallic-roughness
+    pub fn load_rgb_image(
+        image: &image::RgbImage,
+        name: &Option<Arc<String>>,
+        sampler: Sampler,
+        srgb: bool,
+        options: &ExportOptions,
+    ) -> Self {
+        Self::material_texture(name, image.dimensions(), sampler, srgb, options, || {
+            ImageData::RGBA(
+                image
+                    .pixels()
+                    .flat_map(|pixel| {
+                        let [red, green, blue] = pixel.0;
+                        [red, green, blue, u8::MAX]
+                    })
+                    .collect(),
+            )
+        })
+    }
+
+    /// A single channel texture of the material, `name` being the image the channel comes from
+    pub fn load_gray_image(
+        image: &image::GrayImage,
+        name: &Option<Arc<String>>,
+        sampler: Sampler,
+        options: &ExportOptions,
+    ) -> Self {
+        Self::material_texture(name, image.dimensions(), sampler, false, options, || {
+            ImageData::Gray(image.to_vec())
+        })
     }
 
     pub fn to_flatbuffer<'a>(
@@ -95,6 +647,19 @@ impl RenpyImage {
                     .as_union_value(),
                 );
             }
+            ImageData::Gray(image_data) => {
+                data_type = super::flatbuffer::ImageData::Gray;
+                let gray_data = builder.create_vector(image_data);
+                data = Some(
+                    super::flatbuffer::GrayImageData::create(
+                        builder,
+                        &super::flatbuffer::GrayImageDataArgs {
+                            data: Some(gray_data),
+                        },
+                    )
+                    .as_union_value(),
+                );
+            }
             ImageData::Color(color) => {
                 data_type = super::flatbuffer::ImageData::Color;
                 data = Some(
@@ -109,6 +674,25 @@ impl RenpyImage {
             }
         }
 
+        let mip_levels = (!self.mip_levels.is_empty()).then(|| {
+            let levels: Vec<_> = self
+                .mip_levels
+                .iter()
+                .map(|level| {
+                    let data = builder.create_vector(&level.data);
+                    flatbuffer::MipLevel::create(
+                        builder,
+                        &flatbuffer::MipLevelArgs {
+                            width: level.width,
+                            height: level.height,
+                            data: Some(data),
+                        },
+                    )
+                })
+                .collect();
+            builder.create_vector(&levels)
+        });
+
         super::flatbuffer::Image::create(
             builder,
             &super::flatbuffer::ImageArgs {
@@ -117,6 +701,16 @@ impl RenpyImage {
                 data,
                 width: self.width,
                 height: self.height,
+                factor_applied: self.factor_applied,
+                premultiplied: self.premultiplied,
+                sampler: self.sampler.map(sampler_to_flatbuffer).as_ref(),
+                encoding: match self.encoding {
+                    ImageEncoding::Raw => flatbuffer::ImageEncoding::Raw,
+                    ImageEncoding::Png => flatbuffer::ImageEncoding::Png,
+                    ImageEncoding::Qoi => flatbuffer::ImageEncoding::Qoi,
+                    ImageEncoding::Zstd => flatbuffer::ImageEncoding::Zstd,
+                },
+                mip_levels,
             },
         )
     }
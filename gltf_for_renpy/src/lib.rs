@@ -9,7 +9,7 @@
 #![deny(clippy::cast_precision_loss)] // Detect when casting might lose precision
 #![deny(clippy::integer_division)] // Highlight potential bugs from integer division truncation
 #![deny(clippy::arithmetic_side_effects)] // Detect arithmetic operations with potential side effects
-#![deny(clippy::unchecked_duration_subtraction)] // Ensure duration subtraction won't cause underflow
+#![deny(clippy::unchecked_time_subtraction)] // Ensure duration subtraction won't cause underflow
 
 // Unwraps
 #![warn(clippy::unwrap_used)] // Discourage using .unwrap() which can cause panics
@@ -32,14 +32,23 @@
 // Unsafe code detection
 #![deny(unnecessary_transmutes)]
 
+use cgmath::{Matrix4, SquareMatrix};
 use gltf_for_renpy_flatbuffer as flatbuffer;
 
 pub mod animations;
+pub mod bounds;
+pub mod composition;
+pub mod export_rules;
 pub mod gltf_objects;
+pub mod groups;
 pub mod images;
+pub mod mesh_processing;
+pub mod node_path;
+pub mod options;
 pub mod renpy_interop;
 
 use animations::*;
+use export_rules::ExcludedChildren;
 use gltf_loader::{self};
 use gltf_objects::{
     GltfObject,
@@ -48,12 +57,13 @@ use gltf_objects::{
     property::{Properties, Property},
 };
 use images::*;
+pub use options::ExportOptions;
 use renpy_interop::*;
 
 use gltf_loader::Scene;
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ffi::{CStr, c_char, c_void},
     hash::{DefaultHasher, Hash, Hasher},
     path::Path,
@@ -93,6 +103,8 @@ pub enum ResultCode {
     DatabaseInsertionFailure = -6,
     DatabaseExtractionFailure = -7,
     DatabaseTransactionFailure = -8,
+    InvalidOptions = -9,
+    ImageDecodingFailure = -10,
 }
 
 impl std::fmt::Display for ResultCode {
@@ -101,6 +113,20 @@ impl std::fmt::Display for ResultCode {
     }
 }
 
+/// Reads the json options given to the C functions, a null pointer just uses the defaults
+///
+/// # Safety
+///
+/// `options` has to be null or point to a nul terminated string
+unsafe fn options_from_json(options: *const c_char) -> anyhow::Result<ExportOptions> {
+    if options.is_null() {
+        return Ok(ExportOptions::default());
+    }
+
+    let raw_options = unsafe { CStr::from_ptr(options) };
+    ExportOptions::from_json(raw_options.to_str()?)
+}
+
 fn get_from_cache(db_path: &str, model_path: &str) -> anyhow::Result<ImmutableRenpyList<u8>> {
     #[cfg(feature = "rocksdb")]
     match DB::open_default(db_path) {
@@ -154,7 +180,6 @@ fn get_from_cache(db_path: &str, model_path: &str) -> anyhow::Result<ImmutableRe
     }
 }
 
-// TODO: Consider using Mesh Optimizer to speed up rendering?
 // TODO: Switch To ASSIMP so that we can use any format instead of only GLTF
 //  We probably use gltf as a base
 //  Only problem is that right now ASSIMP does not support rust :/
@@ -265,6 +290,22 @@ pub unsafe fn save_all_to_cache(
     db_path: *const c_char,
     model_paths: *const *const c_char,
     model_path_length: usize,
+) -> *const GLTFResult<bool> {
+    unsafe {
+        save_all_to_cache_with_options(db_path, model_paths, model_path_length, std::ptr::null())
+    }
+}
+
+/// # Safety
+///
+/// Same as `save_all_to_cache`, but the options are given as a json object like in `load_file_with_options`
+/// (ex: `{"textures": {"encoding": "Qoi"}}`). The textures are always embedded in the cache.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn save_all_to_cache_with_options(
+    db_path: *const c_char,
+    model_paths: *const *const c_char,
+    model_path_length: usize,
+    options: *const c_char,
 ) -> *const GLTFResult<bool> {
     if db_path.is_null() {
         return GLTFResult::error(
@@ -273,6 +314,15 @@ pub unsafe fn save_all_to_cache(
         );
     }
 
+    let options = gltf_try!(
+        unsafe { options_from_json(options) },
+        ResultCode::InvalidOptions
+    );
+    let options = ExportOptions {
+        use_embed_textures: true,
+        ..options
+    };
+
     let raw_db_path = unsafe { CStr::from_ptr(db_path) };
 
     let db_path = raw_db_path.to_str();
@@ -304,7 +354,7 @@ pub unsafe fn save_all_to_cache(
                             model_path.hash(&mut hasher);
 
                             let model = gltf_try!(
-                                load_scene_list(path, true),
+                                load_scene_list(path, &options),
                                 ResultCode::BadFileProcessing
                             );
 
@@ -376,7 +426,7 @@ pub unsafe fn save_all_to_cache(
 
                         // Actually loads the model like normal
                         let model = gltf_try!(
-                            load_scene_list(model_path, true),
+                            load_scene_list(model_path, &options),
                             ResultCode::BadFileProcessing
                         );
 
@@ -404,8 +454,13 @@ pub unsafe fn save_all_to_cache(
     GLTFResult::error(ResultCode::InvalidPath, "The path contained could not be converted in Rust. This is likely because it did not contain valid UFT-8 characters.".to_string())
 }
 
-fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow::Result<Vec<u8>> {
-    let loaded_file = gltf_loader::load(path);
+/// Loads every scene of the file and converts them into the renpy representation
+pub fn load_renpy_scenes<T: AsRef<Path>>(
+    path: T,
+    options: &ExportOptions,
+) -> anyhow::Result<Vec<gltf_objects::RenpyScene>> {
+    let loaded_file =
+        gltf_loader::load_scenes(path, &options.coordinate_convention, &options.scenes);
 
     let scenes: Vec<Scene> = match loaded_file {
         Ok(value) => value,
@@ -424,19 +479,78 @@ fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow:
         let mut node_mapping: HashMap<ego_tree::NodeId, NodeID> = HashMap::default();
         let mut empty_index = Vec::new();
         let mut mesh_index = Vec::new();
+        let mut paths = node_path::PathBuilder::new(&scene_name);
+        // Nodes with groups in their extras
+        let mut tagged: Vec<(NodeID, Vec<String>)> = Vec::new();
+        // Objects left out along with their children
+        let mut dropped: HashSet<ego_tree::NodeId> = HashSet::default();
+
+        // Paths are handed out before filtering so they stay the same whatever the rules are
+        let object_paths: HashMap<ego_tree::NodeId, String> = scene
+            .objects
+            .root()
+            .descendants()
+            .filter_map(|object| Some((object.id(), paths.path_for(object.value())?)))
+            .collect();
+        let excluded = options
+            .export_rules
+            .excluded_objects(&scene.objects, &object_paths);
+        // Transforms (in glTF coords) of the objects left out, for the children that move up
+        let mut reparent_matrices: HashMap<ego_tree::NodeId, Matrix4<f32>> = HashMap::default();
 
         // Depth first search of the scene tree
         for object in scene.objects.root().descendants() {
-            let value = match object.value() {
+            let Some(path) = object_paths.get(&object.id()).cloned() else {
+                continue;
+            };
+
+            let parent = object.parent().map(|node| node.id());
+            if parent.is_some_and(|parent| dropped.contains(&parent)) {
+                dropped.insert(object.id());
+                continue;
+            }
+            let reparent_matrix = parent.and_then(|parent| reparent_matrices.get(&parent).copied());
+
+            if excluded.contains(&object.id()) {
+                match options.export_rules.excluded_children {
+                    ExcludedChildren::Drop => {
+                        dropped.insert(object.id());
+                    }
+                    ExcludedChildren::Reparent => {
+                        // Children look up this object and end up under its closest exported parent instead
+                        if let Some(parent_id) =
+                            parent.and_then(|parent| node_mapping.get(&parent).copied())
+                        {
+                            node_mapping.insert(object.id(), parent_id);
+                        }
+
+                        let local = local_matrix(object.value());
+                        // Float matrices can't overflow like integers so the lint doesn't matter here
+                        #[allow(clippy::arithmetic_side_effects)]
+                        let matrix = reparent_matrix.map_or(local, |parent| parent * local);
+                        reparent_matrices.insert(object.id(), matrix);
+                    }
+                }
+                continue;
+            }
+
+            let mut value = match object.value() {
                 gltf_loader::SceneObject::Root => continue,
                 gltf_loader::SceneObject::Mesh(model) => {
-                    Mesh::create(model, scene_name.clone(), use_embed_textures)
+                    Mesh::create(model, scene_name.clone(), path, options)
                 }
                 gltf_loader::SceneObject::Empties(empty) => {
-                    Empty::create(empty, scene_name.clone())
+                    Empty::create(empty, scene_name.clone(), path, options)
                 }
             };
 
+            // Keeps the object where it was with the transforms of the parents left out
+            if let Some(parent_matrix) = reparent_matrix {
+                #[allow(clippy::arithmetic_side_effects)]
+                let matrix = parent_matrix * local_matrix(object.value());
+                value.set_rest_transform(matrix, &options.coordinate_convention);
+            }
+
             match object.parent() {
                 Some(node) => {
                     let tree_index;
@@ -454,6 +568,11 @@ fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow:
                         node_mapping.insert(object.id(), tree_index);
                     }
 
+                    let object_groups = groups::object_groups(object.value(), &options.groups.key);
+                    if !object_groups.is_empty() {
+                        tagged.push((tree_index, object_groups));
+                    }
+
                     match object.value() {
                         // Objects with Parents can't be roots lol
                         gltf_loader::SceneObject::Root => unreachable!(),
@@ -473,6 +592,7 @@ fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow:
         }
 
         let scene_properties = Property::load(scene.extras);
+        let groups = groups::build_groups(&gltf_object, &tagged, &options.groups);
 
         scene_list.push(gltf_objects::RenpyScene {
             name: scene.name.clone().unwrap_or_default(),
@@ -480,14 +600,20 @@ fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow:
             properties: scene_properties,
             mesh_indexes: mesh_index,
             empty_indexes: empty_index,
+            index: scene.index,
+            is_default: scene.is_default,
+            groups,
+            variants: scene.variants,
+            bounds: bounds::Aabb::empty(),
+            animated_bounds: None,
         });
     }
 
     // Scene Graph Post-processing
     // - This is mostly to turn node relationships into indexes for faster processing
     for scene in &mut scene_list {
-        let read_only_scene = scene.to_owned();
-        for obj in &mut scene.objects.nodes {
+        let SceneTree { nodes, index, .. } = &mut scene.objects;
+        for obj in nodes {
             match &mut obj.value {
                 GltfObject::Empty(_, _) => {}
                 GltfObject::Mesh(_, mesh) => {
@@ -498,7 +624,7 @@ fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow:
                                     unreachable!()
                                 }
                                 gltf_loader::utils::GlobalNodeIdentifier::NodeId(old_id) => {
-                                    let new_id = read_only_scene.objects.find_node(*old_id);
+                                    let new_id = index.find_id(*old_id);
                                     if let Ok(new_id) = new_id {
                                         *bone =
                                             gltf_loader::utils::GlobalNodeIdentifier::ObjectIndex(
@@ -515,8 +641,48 @@ fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow:
         }
     }
 
+    for scene in &mut scene_list {
+        if options.bake_lossy_transforms {
+            mesh_processing::bake::bake_lossy_transforms(scene, &options.coordinate_convention);
+        }
+
+        if options.share_geometry {
+            mesh_processing::instancing::link_instances(scene);
+        }
+
+        scene.bounds = bounds::scene_bounds(scene);
+        if options.animated_bounds {
+            scene.animated_bounds = Some(bounds::animated_scene_bounds(scene));
+        }
+    }
+
+    if !options.attachments.is_empty() {
+        composition::attach_files(&mut scene_list, options)?;
+    }
+
+    Ok(scene_list)
+}
+
+// Transform of the object relative to its parent, in glTF coords
+fn local_matrix(object: &gltf_loader::SceneObject) -> Matrix4<f32> {
+    match object {
+        gltf_loader::SceneObject::Root => Matrix4::identity(),
+        gltf_loader::SceneObject::Mesh(model) => model.matrix(),
+        gltf_loader::SceneObject::Empties(empty) => empty.matrix(),
+    }
+}
+
+fn load_scene_list<T: AsRef<Path>>(path: T, options: &ExportOptions) -> anyhow::Result<Vec<u8>> {
+    let scene_list = load_renpy_scenes(path, options)?;
+
     let mut builder = flatbuffers::FlatBufferBuilder::new();
 
+    let default_scene = scene_list
+        .iter()
+        .position(|scene| scene.is_default)
+        .and_then(|position| i32::try_from(position).ok())
+        .unwrap_or(-1);
+
     let scene_list = scene_list
         .into_iter()
         .map(|old_scene| gltf_objects::convert_scene_to_flatbuffer(old_scene, &mut builder))
@@ -528,6 +694,7 @@ fn load_scene_list<T: AsRef<Path>>(path: T, use_embed_textures: bool) -> anyhow:
         &mut builder,
         &flatbuffer::ScenesArgs {
             scenes: Some(scene_list),
+            default_scene,
         },
     );
 
@@ -556,8 +723,13 @@ pub unsafe extern "C" fn load_file(
     let file_path = raw_file_path.to_str();
 
     if let Ok(path) = file_path {
+        let options = ExportOptions {
+            use_embed_textures,
+            ..Default::default()
+        };
+
         let result = gltf_try!(
-            load_scene_list(path, use_embed_textures),
+            load_scene_list(path, &options),
             ResultCode::BadFileProcessing
         );
         let rv = ImmutableRenpyList::from(result);
@@ -567,3 +739,230 @@ pub unsafe extern "C" fn load_file(
 
     GLTFResult::error(ResultCode::InvalidPath, "The path contained could not be converted in Rust. This is likely because it did not contain valid UFT-8 characters.".to_string())
 }
+
+/// # Safety
+///
+/// Same as `load_file`, but only one scene is loaded: the one called `scene_name` when it isn't null,
+/// else the one at `scene_index`, else (negative index) the file's default scene
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn load_file_scene(
+    file_path: *const c_char,
+    scene_name: *const c_char,
+    scene_index: i32,
+    use_embed_textures: bool,
+) -> *const GLTFResult<ImmutableRenpyList<u8>> {
+    if file_path.is_null() {
+        return GLTFResult::error(
+            ResultCode::NullPath,
+            "The path that was given was a null pointer.".to_string(),
+        );
+    }
+
+    let scenes = if !scene_name.is_null() {
+        let raw_name = unsafe { CStr::from_ptr(scene_name) };
+        let name = gltf_try!(raw_name.to_str(), ResultCode::InvalidOptions);
+        gltf_loader::SceneSelection::Names(vec![name.to_owned()])
+    } else if let Ok(index) = usize::try_from(scene_index) {
+        gltf_loader::SceneSelection::Indices(vec![index])
+    } else {
+        gltf_loader::SceneSelection::Default
+    };
+
+    let raw_file_path = unsafe { CStr::from_ptr(file_path) };
+
+    if let Ok(path) = raw_file_path.to_str() {
+        let options = ExportOptions {
+            scenes,
+            use_embed_textures,
+            ..Default::default()
+        };
+
+        let result = gltf_try!(
+            load_scene_list(path, &options),
+            ResultCode::BadFileProcessing
+        );
+        let rv = ImmutableRenpyList::from(result);
+
+        return GLTFResult::ok(rv);
+    }
+
+    GLTFResult::error(ResultCode::InvalidPath, "The path contained could not be converted in Rust. This is likely because it did not contain valid UFT-8 characters.".to_string())
+}
+
+/// # Safety
+///
+/// Same as `load_file`, but the options are given as a json object (ex: `{"optimize_meshes": true}`)
+/// A null pointer for the options just uses the defaults
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn load_file_with_options(
+    file_path: *const c_char,
+    options: *const c_char,
+) -> *const GLTFResult<ImmutableRenpyList<u8>> {
+    if file_path.is_null() {
+        return GLTFResult::error(
+            ResultCode::NullPath,
+            "The path that was given was a null pointer.".to_string(),
+        );
+    }
+
+    let options = gltf_try!(
+        unsafe { options_from_json(options) },
+        ResultCode::InvalidOptions
+    );
+
+    let raw_file_path = unsafe { CStr::from_ptr(file_path) };
+
+    if let Ok(path) = raw_file_path.to_str() {
+        let result = gltf_try!(
+            load_scene_list(path, &options),
+            ResultCode::BadFileProcessing
+        );
+
+        return GLTFResult::ok(ImmutableRenpyList::from(result));
+    }
+
+    GLTFResult::error(ResultCode::InvalidPath, "The path contained could not be converted in Rust. This is likely because it did not contain valid UFT-8 characters.".to_string())
+}
+
+/// # Safety
+///
+/// Same as `load_file_with_options`, but `attachment_path` gets loaded too and its default scene is put under the node
+/// matching `target` (a node path or a glob pattern like `**/Hand_L`) so it follows that node around.
+/// The options can also hold more attachments (see `composition::Attachment`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn load_file_with_attachment(
+    file_path: *const c_char,
+    attachment_path: *const c_char,
+    target: *const c_char,
+    options: *const c_char,
+) -> *const GLTFResult<ImmutableRenpyList<u8>> {
+    if file_path.is_null() || attachment_path.is_null() {
+        return GLTFResult::error(
+            ResultCode::NullPath,
+            "The path that was given was a null pointer.".to_string(),
+        );
+    }
+
+    if target.is_null() {
+        return GLTFResult::error(
+            ResultCode::InvalidOptions,
+            "The target node that was given was a null pointer.".to_string(),
+        );
+    }
+
+    let mut options = gltf_try!(
+        unsafe { options_from_json(options) },
+        ResultCode::InvalidOptions
+    );
+
+    let raw_target = unsafe { CStr::from_ptr(target) };
+    let target = gltf_try!(raw_target.to_str(), ResultCode::InvalidOptions);
+
+    let raw_attachment_path = unsafe { CStr::from_ptr(attachment_path) };
+    let attachment_path = gltf_try!(raw_attachment_path.to_str(), ResultCode::InvalidPath);
+
+    options.attachments.insert(
+        0,
+        composition::Attachment {
+            file: attachment_path.to_owned(),
+            target: target.to_owned(),
+            offset: composition::Offset::default(),
+        },
+    );
+
+    let raw_file_path = unsafe { CStr::from_ptr(file_path) };
+
+    if let Ok(path) = raw_file_path.to_str() {
+        let result = gltf_try!(
+            load_scene_list(path, &options),
+            ResultCode::BadFileProcessing
+        );
+
+        return GLTFResult::ok(ImmutableRenpyList::from(result));
+    }
+
+    GLTFResult::error(ResultCode::InvalidPath, "The path contained could not be converted in Rust. This is likely because it did not contain valid UFT-8 characters.".to_string())
+}
+
+/// Computes the renpy offset/zoom/perspective that fits the given bounds (from the `bounds` of a scene or mesh) in the viewport
+/// `fill` is how much of the viewport the model should take (1.0 touches the edges)
+///
+/// The zoom is 0 when the bounds or the viewport are empty
+#[unsafe(no_mangle)]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn compute_framing(
+    min_x: f32,
+    min_y: f32,
+    min_z: f32,
+    max_x: f32,
+    max_y: f32,
+    max_z: f32,
+    viewport_width: f32,
+    viewport_height: f32,
+    fill: f32,
+) -> bounds::framing::Framing {
+    let bounds = bounds::Aabb {
+        min: cgmath::Vector3::new(min_x, min_y, min_z),
+        max: cgmath::Vector3::new(max_x, max_y, max_z),
+    };
+
+    bounds::framing::frame_bounds(&bounds, viewport_width, viewport_height, fill).unwrap_or(
+        bounds::framing::Framing {
+            offset: [0.0; 3],
+            zoom: 0.0,
+            perspective: [0.0; 3],
+        },
+    )
+}
+
+/// Checks a node path (the `path` of a mesh or empty) against a glob pattern like `**/Hand_*`
+/// See `node_path::glob_match` for what the pattern can hold
+///
+/// # Safety
+/// Both pointers have to be null or valid null terminated strings
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn match_node_path(pattern: *const c_char, path: *const c_char) -> bool {
+    if pattern.is_null() || path.is_null() {
+        return false;
+    }
+
+    let (pattern, path) = unsafe { (CStr::from_ptr(pattern), CStr::from_ptr(path)) };
+    match (pattern.to_str(), path.to_str()) {
+        (Ok(pattern), Ok(path)) => node_path::glob_match(pattern, path),
+        _ => false,
+    }
+}
+
+/// Decodes the data of an embedded `Image` (see `images::ImageEncoding`) into raw texels,
+/// `channels` being 4 for RGBA data and 1 for gray data. The result is freed with `free_scene_list`.
+///
+/// # Safety
+/// `data` has to be null or point to `length` bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn decode_image_data(
+    data: *const u8,
+    length: usize,
+    encoding: i8,
+    width: u32,
+    height: u32,
+    channels: u8,
+) -> *const GLTFResult<ImmutableRenpyList<u8>> {
+    if data.is_null() {
+        return GLTFResult::error(
+            ResultCode::ImageDecodingFailure,
+            "The image data that was given was a null pointer.".to_string(),
+        );
+    }
+
+    let data = unsafe { std::slice::from_raw_parts(data, length) };
+    let encoding = gltf_try!(
+        images::ImageEncoding::try_from(encoding),
+        ResultCode::ImageDecodingFailure
+    );
+    let pixels = gltf_try!(
+        images::decode_pixels(data, width, height, channels, encoding),
+        ResultCode::ImageDecodingFailure
+    );
+
+    GLTFResult::ok(ImmutableRenpyList::from(pixels))
+}
@@ -0,0 +1,102 @@
+use cgmath::{InnerSpace, Matrix, Matrix3, Matrix4, Point3, SquareMatrix, Transform};
+use gltf_loader::utils::{CoordinateConvention, DecomposedTransform};
+
+use crate::gltf_objects::{GltfObject, RenpyScene, mesh::Mesh};
+
+// How far from perpendicular the axes of a matrix can be before it counts as shear
+const SHEAR_EPSILON: f32 = 1e-4;
+
+/// The matrix axes are not perpendicular so it can't be turned into translation/rotation/scale
+pub fn has_shear(matrix: &Matrix4<f32>) -> bool {
+    let (x, y, z) = (
+        matrix.x.truncate(),
+        matrix.y.truncate(),
+        matrix.z.truncate(),
+    );
+
+    [(x, y), (y, z), (z, x)].iter().any(|(a, b)| {
+        let lengths = a.magnitude() * b.magnitude();
+        lengths > 0.0 && (a.dot(*b) / lengths).abs() > SHEAR_EPSILON
+    })
+}
+
+/// The matrix flips the object inside out (negative scale on an odd number of axes)
+pub fn is_mirrored(matrix: &Matrix4<f32>) -> bool {
+    matrix.determinant() < 0.0
+}
+
+/// A transform that renpy can't show with just the TRS values
+pub fn is_lossy(matrix: &Matrix4<f32>) -> bool {
+    has_shear(matrix) || is_mirrored(matrix)
+}
+
+/// Moves the rest transform of the mesh into its points so the mesh itself doesn't have a transform anymore.
+/// Triangles get their winding flipped when the transform mirrors the mesh so the faces still point outward.
+///
+/// This should only be used on meshes without children (since they would lose the transform),
+/// without a skeleton (the skin already places those vertices), without animations and without GPU instances (the node transform applies after them).
+pub fn bake_transform(mesh: &mut Mesh, convention: &CoordinateConvention) {
+    let matrix = mesh.rest_matrix;
+    let linear = Matrix3::from_cols(
+        matrix.x.truncate(),
+        matrix.y.truncate(),
+        matrix.z.truncate(),
+    );
+
+    let mut points = Vec::with_capacity(mesh.vertexes.len());
+    for point in mesh.vertexes.chunks_exact(3) {
+        if let [x, y, z] = point {
+            let point = matrix.transform_point(Point3::new(*x, *y, *z));
+            points.extend_from_slice(&[point.x, point.y, point.z]);
+        }
+    }
+    mesh.vertexes = points;
+
+    // Normals need the inverse transpose so they stay perpendicular to sheared faces
+    let normal_matrix = Matrix4::from(linear.invert().unwrap_or(linear).transpose());
+
+    // Offsets only go through the rotation/scale/shear part
+    for shape in mesh
+        .morph_targets
+        .iter_mut()
+        .flat_map(|target| &mut target.blend_shapes)
+    {
+        shape.position = matrix.transform_vector(shape.position);
+        shape.normal = normal_matrix.transform_vector(shape.normal);
+    }
+
+    if is_mirrored(&matrix) {
+        flip_winding(&mut mesh.triangles);
+        for lod in &mut mesh.lods {
+            flip_winding(&mut lod.triangles);
+        }
+    }
+
+    mesh.rest_matrix = Matrix4::identity();
+    mesh.default_transform = DecomposedTransform::default().to_converted_coords(convention, false);
+    mesh.update_bounds();
+}
+
+fn flip_winding(triangles: &mut [u32]) {
+    for triangle in triangles.chunks_exact_mut(3) {
+        triangle.swap(1, 2);
+    }
+}
+
+/// Bakes every mesh of the scene with a lossy transform that can be baked (see `bake_transform`)
+pub fn bake_lossy_transforms(scene: &mut RenpyScene, convention: &CoordinateConvention) {
+    for node in &mut scene.objects.nodes {
+        let GltfObject::Mesh(_, mesh) = &mut node.value else {
+            continue;
+        };
+
+        if node.children.is_empty()
+            && mesh.skeleton.is_none()
+            && mesh.animations.is_empty()
+            && mesh.instances.is_empty()
+            && is_lossy(&mesh.rest_matrix)
+        {
+            bake_transform(mesh, convention);
+        }
+    }
+}
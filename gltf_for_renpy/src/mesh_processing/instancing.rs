@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use crate::gltf_objects::{GltfObject, RenpyScene, mesh::Mesh};
+use crate::renpy_interop::NodeID;
+
+/// Points every mesh that has the same geometry as an earlier mesh of the scene to that mesh (`instance_of`)
+/// so the geometry only gets sent once.
+///
+/// Meshes from the same glTF mesh are only linked when the geometry still matches after processing
+/// (a baked transform for example makes it different).
+pub fn link_instances(scene: &mut RenpyScene) {
+    let mut sources: HashMap<(usize, usize), Vec<NodeID>> = HashMap::new();
+
+    for index in 0..scene.objects.nodes.len() {
+        #[allow(clippy::cast_possible_truncation)]
+        // Truncation is fine since we will realistically not go over this... hopefully?
+        let node_id = index as NodeID;
+
+        let Some(GltfObject::Mesh(_, mesh)) = scene.objects.get_value(node_id) else {
+            continue;
+        };
+
+        let candidates = sources.entry(mesh.geometry_key).or_default();
+        let source = candidates.iter().copied().find(|candidate| {
+            matches!(
+                scene.objects.get_value(*candidate),
+                Some(GltfObject::Mesh(_, other)) if same_geometry(mesh, other)
+            )
+        });
+
+        match source {
+            Some(source) => {
+                if let Some(GltfObject::Mesh(_, mesh)) = scene
+                    .objects
+                    .nodes
+                    .get_mut(index)
+                    .map(|node| &mut node.value)
+                {
+                    mesh.instance_of = Some(source);
+                }
+            }
+            None => candidates.push(node_id),
+        }
+    }
+}
+
+fn same_geometry(mesh: &Mesh, other: &Mesh) -> bool {
+    mesh.vertexes == other.vertexes
+        && mesh.triangles == other.triangles
+        && mesh.uvs == other.uvs
+        && mesh.bone_indexes == other.bone_indexes
+        && mesh.bone_weights == other.bone_weights
+        && mesh
+            .lods
+            .iter()
+            .map(|lod| &lod.triangles)
+            .eq(other.lods.iter().map(|lod| &lod.triangles))
+        && mesh.texture.name == other.texture.name
+        && mesh.material.texture_names() == other.material.texture_names()
+        && mesh
+            .variants
+            .iter()
+            .map(|variant| (variant.variant, &variant.texture.name))
+            .eq(other
+                .variants
+                .iter()
+                .map(|variant| (variant.variant, &variant.texture.name)))
+        && mesh.morph_targets.len() == other.morph_targets.len()
+        && mesh
+            .morph_targets
+            .iter()
+            .zip(&other.morph_targets)
+            .all(|(a, b)| a.name == b.name && a.blend_shapes == b.blend_shapes)
+}
@@ -0,0 +1,203 @@
+use gltf_for_renpy_flatbuffer as flatbuffer;
+
+use crate::Mesh;
+
+use super::is_valid_triangle_list;
+
+/// A simplified index buffer that reuses the vertices of the full mesh
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshLod {
+    pub triangles: Vec<u32>,
+    /// Distance (in model units) between the simplified and the original surface
+    pub error: f32,
+    /// The ratio of `lod_levels` this level was made for
+    pub target_ratio: f32,
+    /// The ratio of the triangles of the full mesh that were kept
+    pub achieved_ratio: f32,
+}
+
+impl MeshLod {
+    pub fn to_flatbuffer<'a>(
+        &self,
+        builder: &mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> flatbuffers::WIPOffset<flatbuffer::MeshLod<'a>> {
+        let triangles = builder.create_vector(&self.triangles);
+
+        flatbuffer::MeshLod::create(
+            builder,
+            &flatbuffer::MeshLodArgs {
+                triangles: Some(triangles),
+                error: self.error,
+                target_ratio: self.target_ratio,
+                achieved_ratio: self.achieved_ratio,
+            },
+        )
+    }
+}
+
+/// Builds one LOD for each triangle ratio in `levels` with quadric error simplification.
+///
+/// UV seams are kept since meshopt only lets seam edges collapse along the seam,
+/// and the uvs/bone weights are part of the error so skinned areas don't get merged with the wrong bones.
+/// Vertices are never moved, so every LOD still uses the exact skin and morph data of the full mesh.
+///
+/// Levels that can't remove any more triangles (because of `max_error`) are skipped,
+/// so each LOD keeps the ratio it was made for next to the one it reached.
+pub fn generate_lods(
+    mesh: &Mesh,
+    levels: &[f32],
+    max_error: f32,
+    optimize_cache: bool,
+) -> Vec<MeshLod> {
+    let vertex_count = mesh.vertex_count();
+
+    if mesh.triangles.is_empty() || !is_valid_triangle_list(&mesh.triangles, vertex_count) {
+        return Vec::new();
+    }
+
+    let positions = meshopt::typed_to_bytes(&mesh.vertexes);
+    let Ok(adapter) =
+        meshopt::VertexDataAdapter::new(positions, std::mem::size_of::<[f32; 3]>(), 0)
+    else {
+        return Vec::new();
+    };
+
+    let (attributes, attribute_weights) = simplify_attributes(mesh, vertex_count);
+    let attribute_stride = std::mem::size_of::<f32>().saturating_mul(attribute_weights.len());
+
+    // meshopt gives back the error relative to the mesh size
+    let scale = meshopt::simplify_scale(&adapter);
+
+    let mut lods: Vec<MeshLod> = Vec::with_capacity(levels.len());
+    let mut previous_count = mesh.triangles.len();
+
+    for level in levels {
+        let target_count = target_index_count(mesh.triangles.len(), *level);
+
+        let mut error = 0.0;
+        let mut triangles = if attribute_weights.is_empty() {
+            meshopt::simplify(
+                &mesh.triangles,
+                &adapter,
+                target_count,
+                max_error,
+                meshopt::SimplifyOptions::None,
+                Some(&mut error),
+            )
+        } else {
+            simplify_with_attributes(
+                &mesh.triangles,
+                &adapter,
+                &attributes,
+                &attribute_weights,
+                attribute_stride,
+                target_count,
+                max_error,
+                &mut error,
+            )
+        };
+
+        if triangles.is_empty() || triangles.len() >= previous_count {
+            continue;
+        }
+        previous_count = triangles.len();
+
+        if optimize_cache {
+            triangles = meshopt::optimize_vertex_cache(&triangles, vertex_count);
+        }
+
+        lods.push(MeshLod {
+            achieved_ratio: index_ratio(triangles.len(), mesh.triangles.len()),
+            triangles,
+            error: error * scale,
+            target_ratio: *level,
+        });
+    }
+
+    lods
+}
+
+// meshopt only wraps the attribute simplification with locks, no vertex is locked here so they're left null
+#[allow(clippy::too_many_arguments)]
+fn simplify_with_attributes(
+    indices: &[u32],
+    vertices: &meshopt::VertexDataAdapter<'_>,
+    attributes: &[f32],
+    attribute_weights: &[f32],
+    attribute_stride: usize,
+    target_count: usize,
+    max_error: f32,
+    error: &mut f32,
+) -> Vec<u32> {
+    let mut result: Vec<u32> = vec![0; indices.len()];
+    let index_count = unsafe {
+        meshopt::ffi::meshopt_simplifyWithAttributes(
+            result.as_mut_ptr(),
+            indices.as_ptr(),
+            indices.len(),
+            vertices.pos_ptr(),
+            vertices.vertex_count,
+            vertices.vertex_stride,
+            attributes.as_ptr(),
+            attribute_stride,
+            attribute_weights.as_ptr(),
+            attribute_weights.len(),
+            std::ptr::null(),
+            target_count,
+            max_error,
+            meshopt::SimplifyOptions::None.bits(),
+            error,
+        )
+    };
+    result.truncate(index_count);
+    result
+}
+
+// Interleaves the uvs and bone weights so meshopt can take them into account
+fn simplify_attributes(mesh: &Mesh, vertex_count: usize) -> (Vec<f32>, Vec<f32>) {
+    let uvs = (mesh.uvs.len() == vertex_count.saturating_mul(2)).then_some(&mesh.uvs);
+    let weights =
+        (mesh.bone_weights.len() == vertex_count.saturating_mul(4)).then_some(&mesh.bone_weights);
+
+    let mut attribute_weights = Vec::new();
+    if uvs.is_some() {
+        attribute_weights.extend_from_slice(&[1.0; 2]);
+    }
+    if weights.is_some() {
+        attribute_weights.extend_from_slice(&[1.0; 4]);
+    }
+
+    let mut attributes = Vec::with_capacity(vertex_count.saturating_mul(attribute_weights.len()));
+    for vertex in 0..vertex_count {
+        if let Some(uv) = uvs.and_then(|uvs| uvs.chunks_exact(2).nth(vertex)) {
+            attributes.extend_from_slice(uv);
+        }
+        if let Some(weight) = weights.and_then(|weights| weights.chunks_exact(4).nth(vertex)) {
+            attributes.extend_from_slice(weight);
+        }
+    }
+
+    (attributes, attribute_weights)
+}
+
+// Rounds down to a whole number of triangles
+fn target_index_count(index_count: usize, ratio: f32) -> usize {
+    let triangle_count = index_count.checked_div(3).unwrap_or_default();
+
+    // Meshes don't have anywhere near enough triangles for the float conversions to matter
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let target = (triangle_count as f32 * ratio.clamp(0.0, 1.0)).floor() as usize;
+
+    target.saturating_mul(3)
+}
+
+fn index_ratio(count: usize, full_count: usize) -> f32 {
+    // Same as above, the counts are small enough for f32
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = count as f32 / full_count.max(1) as f32;
+    ratio
+}
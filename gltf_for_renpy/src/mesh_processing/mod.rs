@@ -0,0 +1,59 @@
+// Passes that rewrite the geometry of a mesh before it gets sent to renpy
+
+pub mod bake;
+pub mod instancing;
+pub mod lod;
+pub mod optimize;
+pub mod weld;
+
+/// Marks a vertex that is not used anymore in a remap table
+pub const REMOVED_VERTEX: u32 = u32::MAX;
+
+/// Reorders a per-vertex stream where each vertex takes `stride` values.
+/// The vertex at `old` ends up at `remap[old]` and vertices mapped to `REMOVED_VERTEX` are dropped.
+///
+/// Streams that don't have a value for every vertex (like a mesh without uvs) are returned untouched.
+pub fn remap_stream<T: Copy + Default>(
+    values: &[T],
+    stride: usize,
+    remap: &[u32],
+    new_vertex_count: usize,
+) -> Vec<T> {
+    if stride == 0 || values.len() != remap.len().saturating_mul(stride) {
+        return values.to_vec();
+    }
+
+    let mut result = vec![T::default(); new_vertex_count.saturating_mul(stride)];
+    for (vertex, new_index) in values.chunks_exact(stride).zip(remap) {
+        if *new_index == REMOVED_VERTEX {
+            continue;
+        }
+
+        let start = (*new_index as usize).saturating_mul(stride);
+        if let Some(destination) = result.get_mut(start..start.saturating_add(stride)) {
+            destination.copy_from_slice(vertex);
+        }
+    }
+
+    result
+}
+
+/// Applies the remap table to every per-vertex stream of the mesh so that they all stay in sync
+pub fn remap_mesh(mesh: &mut crate::Mesh, remap: &[u32], new_vertex_count: usize) {
+    mesh.vertexes = remap_stream(&mesh.vertexes, 3, remap, new_vertex_count);
+    mesh.uvs = remap_stream(&mesh.uvs, 2, remap, new_vertex_count);
+    mesh.bone_indexes = remap_stream(&mesh.bone_indexes, 4, remap, new_vertex_count);
+    mesh.bone_weights = remap_stream(&mesh.bone_weights, 4, remap, new_vertex_count);
+
+    for target in &mut mesh.morph_targets {
+        target.blend_shapes = remap_stream(&target.blend_shapes, 1, remap, new_vertex_count);
+    }
+}
+
+/// Checks that the triangle list can be safely handed to meshopt (which asserts instead of returning errors)
+pub fn is_valid_triangle_list(triangles: &[u32], vertex_count: usize) -> bool {
+    triangles.len().is_multiple_of(3)
+        && triangles
+            .iter()
+            .all(|index| (*index as usize) < vertex_count)
+}
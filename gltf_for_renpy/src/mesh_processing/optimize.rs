@@ -0,0 +1,81 @@
+use crate::Mesh;
+
+use super::{REMOVED_VERTEX, is_valid_triangle_list, remap_mesh};
+
+// Same cache size that meshopt uses for its own analysis examples
+const CACHE_SIZE: u32 = 16;
+
+// How much the overdraw pass can make the vertex cache worse (5%)
+const OVERDRAW_THRESHOLD: f32 = 1.05;
+
+/// Vertex cache numbers from before and after the optimization pass
+/// ACMR: Average cache miss ratio (transformed vertices per triangle, lower is better, 0.5 is the best you can get)
+/// ATVR: Average transformed vertex ratio (transformed vertices per vertex, 1.0 is the best you can get)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VertexCacheStats {
+    pub acmr_before: f32,
+    pub acmr_after: f32,
+    pub atvr_before: f32,
+    pub atvr_after: f32,
+}
+
+/// Reorders the triangles for vertex cache locality (and overdraw) then reorders the vertices in the order they are used
+/// Every per-vertex stream of the mesh gets moved with the vertices
+///
+/// Returns None if the mesh could not be optimized (not a triangle list, bad indexes, ...)
+pub fn optimize_mesh(mesh: &mut Mesh) -> Option<VertexCacheStats> {
+    let vertex_count = mesh.vertex_count();
+
+    if mesh.triangles.is_empty() || !is_valid_triangle_list(&mesh.triangles, vertex_count) {
+        return None;
+    }
+
+    let before = meshopt::analyze_vertex_cache(&mesh.triangles, vertex_count, CACHE_SIZE, 0, 0);
+
+    let mut triangles = meshopt::optimize_vertex_cache(&mesh.triangles, vertex_count);
+
+    let positions = meshopt::typed_to_bytes(&mesh.vertexes);
+    if let Ok(adapter) =
+        meshopt::VertexDataAdapter::new(positions, std::mem::size_of::<[f32; 3]>(), 0)
+    {
+        meshopt::optimize_overdraw_in_place(&mut triangles, &adapter, OVERDRAW_THRESHOLD);
+    }
+
+    let (remap, new_vertex_count) = vertex_fetch_remap(&triangles, vertex_count);
+    for index in &mut triangles {
+        if let Some(new_index) = remap.get(*index as usize) {
+            *index = *new_index;
+        }
+    }
+
+    remap_mesh(mesh, &remap, new_vertex_count);
+    mesh.triangles = triangles;
+
+    let after = meshopt::analyze_vertex_cache(&mesh.triangles, new_vertex_count, CACHE_SIZE, 0, 0);
+
+    Some(VertexCacheStats {
+        acmr_before: before.acmr,
+        acmr_after: after.acmr,
+        atvr_before: before.atvr,
+        atvr_after: after.atvr,
+    })
+}
+
+/// Numbers the vertices in the order the triangles first use them
+/// Vertices that are never used get dropped
+// I don't use meshopt's version since the rust binding cuts the remap table short when there are unused vertices
+fn vertex_fetch_remap(triangles: &[u32], vertex_count: usize) -> (Vec<u32>, usize) {
+    let mut remap = vec![REMOVED_VERTEX; vertex_count];
+    let mut next_vertex: u32 = 0;
+
+    for index in triangles {
+        if let Some(new_index) = remap.get_mut(*index as usize)
+            && *new_index == REMOVED_VERTEX
+        {
+            *new_index = next_vertex;
+            next_vertex = next_vertex.saturating_add(1);
+        }
+    }
+
+    (remap, next_vertex as usize)
+}
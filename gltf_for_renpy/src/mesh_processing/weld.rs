@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::Mesh;
+
+use super::{is_valid_triangle_list, remap_mesh};
+
+/// Merges the vertices that have the same position, uv, normal, skin and morph data and rebuilds `triangles` to point to them.
+/// Meshes without an index buffer (triangle soup) get one made for them first.
+///
+/// Two vertices are merged when each of their values is within `epsilon` of the other (bone indexes have to be equal).
+/// The positions are bucketed in cells of `epsilon` and only the neighbouring cells get searched, a vertex being
+/// merged with the first vertex it matches. Triangles that end up with the same vertex twice are removed.
+///
+/// `normals` is per-vertex (3 floats each) since the mesh itself doesn't keep them
+pub fn weld_vertices(mesh: &mut Mesh, normals: &[f32], epsilon: f32) {
+    let vertex_count = mesh.vertex_count();
+    if vertex_count == 0 {
+        return;
+    }
+
+    if mesh.triangles.is_empty() {
+        if !vertex_count.is_multiple_of(3) {
+            return;
+        }
+        mesh.triangles = (0..vertex_count)
+            .map(|index| u32::try_from(index).unwrap_or_default())
+            .collect();
+    }
+
+    if !is_valid_triangle_list(&mesh.triangles, vertex_count) {
+        return;
+    }
+
+    let epsilon = if epsilon > 0.0 { epsilon } else { f32::EPSILON };
+
+    // Welded vertices of each cell, by their new index
+    let mut cells: HashMap<[i64; 3], Vec<u32>> = HashMap::with_capacity(vertex_count);
+    // The first vertex of each welded vertex, whose values are kept
+    let mut kept: Vec<usize> = Vec::with_capacity(vertex_count);
+    let mut remap: Vec<u32> = Vec::with_capacity(vertex_count);
+
+    for vertex in 0..vertex_count {
+        let cell = position_cell(mesh, vertex, epsilon);
+
+        let existing = neighbour_cells(cell).find_map(|cell| {
+            cells.get(&cell)?.iter().copied().find(|welded| {
+                kept.get(*welded as usize)
+                    .is_some_and(|other| same_vertex(mesh, normals, *other, vertex, epsilon))
+            })
+        });
+
+        let index = existing.unwrap_or_else(|| {
+            let index = u32::try_from(kept.len()).unwrap_or(u32::MAX);
+            kept.push(vertex);
+            cells.entry(cell).or_default().push(index);
+            index
+        });
+        remap.push(index);
+    }
+
+    let new_vertex_count = kept.len();
+    if new_vertex_count == vertex_count {
+        return;
+    }
+
+    let mut triangles = Vec::with_capacity(mesh.triangles.len());
+    for triangle in mesh.triangles.chunks_exact(3) {
+        let welded: Vec<u32> = triangle
+            .iter()
+            .filter_map(|index| remap.get(*index as usize).copied())
+            .collect();
+
+        if let [a, b, c] = welded[..]
+            && a != b
+            && b != c
+            && a != c
+        {
+            triangles.extend_from_slice(&welded);
+        }
+    }
+
+    remap_mesh(mesh, &remap, new_vertex_count);
+    mesh.triangles = triangles;
+}
+
+// The grid cell (of size `epsilon`) of the position of the vertex
+fn position_cell(mesh: &Mesh, vertex: usize, epsilon: f32) -> [i64; 3] {
+    let mut cell = [0; 3];
+    for (cell, value) in cell.iter_mut().zip(vertex_slice(&mesh.vertexes, 3, vertex)) {
+        // Big values just saturate which is fine for bucketing
+        #[allow(clippy::cast_possible_truncation)]
+        let index = (value / epsilon).floor() as i64;
+        *cell = index;
+    }
+    cell
+}
+
+// The cell and the 26 around it, which hold every position within `epsilon` of the cell
+fn neighbour_cells(cell: [i64; 3]) -> impl Iterator<Item = [i64; 3]> {
+    let [x, y, z] = cell;
+    (-1..=1_i64).flat_map(move |dx| {
+        (-1..=1_i64).flat_map(move |dy| {
+            (-1..=1_i64).map(move |dz| {
+                [
+                    x.saturating_add(dx),
+                    y.saturating_add(dy),
+                    z.saturating_add(dz),
+                ]
+            })
+        })
+    })
+}
+
+// Every attribute of both vertices within epsilon of each other (bone indexes are compared as is)
+fn same_vertex(mesh: &Mesh, normals: &[f32], first: usize, second: usize, epsilon: f32) -> bool {
+    let close = |values: &[f32], stride: usize| {
+        let (first, second) = (
+            vertex_slice(values, stride, first),
+            vertex_slice(values, stride, second),
+        );
+        first.len() == second.len()
+            && first
+                .iter()
+                .zip(second)
+                .all(|(a, b)| (a - b).abs() <= epsilon)
+    };
+
+    close(&mesh.vertexes, 3)
+        && close(&mesh.uvs, 2)
+        && close(normals, 3)
+        && close(&mesh.bone_weights, 4)
+        && vertex_slice(&mesh.bone_indexes, 4, first) == vertex_slice(&mesh.bone_indexes, 4, second)
+        && mesh.morph_targets.iter().all(|target| {
+            match (
+                target.blend_shapes.get(first),
+                target.blend_shapes.get(second),
+            ) {
+                (Some(first), Some(second)) => [
+                    (first.position.x, second.position.x),
+                    (first.position.y, second.position.y),
+                    (first.position.z, second.position.z),
+                    (first.normal.x, second.normal.x),
+                    (first.normal.y, second.normal.y),
+                    (first.normal.z, second.normal.z),
+                ]
+                .iter()
+                .all(|(a, b)| (a - b).abs() <= epsilon),
+                (first, second) => first.is_none() && second.is_none(),
+            }
+        })
+}
+
+// Values of one vertex in a stream, empty if the stream doesn't have that vertex
+fn vertex_slice<T>(values: &[T], stride: usize, vertex: usize) -> &[T] {
+    values.chunks_exact(stride).nth(vertex).unwrap_or_default()
+}
@@ -0,0 +1,154 @@
+// Paths like `Scene/Armature/Hips/Spine` that give every node a name that's unique within the file
+// Meshes get an extra segment for the primitive, so `Scene/Body/0` is the first primitive of the `Body` node
+
+use std::collections::HashMap;
+
+/// Splits the path segments
+pub const SEPARATOR: char = '/';
+
+/// The path segment for a glTF node.
+/// Nodes without a name get `#<node index>`, and the index is also added when a sibling already took the name
+/// so paths stay the same between exports of the same file.
+pub fn segment(name: Option<&str>, node_index: usize, sibling_names: &[String]) -> String {
+    let Some(name) = name.filter(|name| !name.is_empty()) else {
+        return format!("#{node_index}");
+    };
+
+    // A slash in the name would look like another level
+    let name = name.replace(SEPARATOR, "_");
+    if sibling_names.contains(&name) {
+        format!("{name}#{node_index}")
+    } else {
+        name
+    }
+}
+
+/// Adds a segment at the end of a path
+pub fn join(path: &str, segment: &str) -> String {
+    format!("{path}{SEPARATOR}{segment}")
+}
+
+/// Checks a path against a glob pattern.
+///
+/// - `*` matches any part of a single segment (`Hand_*` matches `Hand_L` but not `Hand/L`)
+/// - `?` matches any single character except the separator
+/// - `**` as a whole segment matches any number of segments (even none)
+///
+/// The pattern has to match the whole path, scene name included (`*/Hand_*` for root nodes
+/// called `Hand_something`, `**/Hand_*` for any of them).
+pub fn glob_match(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<&str> = pattern.split(SEPARATOR).collect();
+    let path: Vec<&str> = path.split(SEPARATOR).collect();
+    match_segments(&pattern, &path)
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match (pattern.split_first(), path.split_first()) {
+        (None, None) => true,
+        (Some((&"**", rest)), _) => {
+            // Try skipping 0, 1, 2... segments
+            (0..=path.len()).any(|skip| {
+                path.get(skip..)
+                    .is_some_and(|path| match_segments(rest, path))
+            })
+        }
+        (Some((segment_pattern, pattern_rest)), Some((segment, path_rest))) => {
+            match_segment(segment_pattern, segment) && match_segments(pattern_rest, path_rest)
+        }
+        _ => false,
+    }
+}
+
+// Classic wildcard matching with backtracking to the last star
+fn match_segment(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0_usize, 0_usize);
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p = p.saturating_add(1);
+            }
+            Some(c) if *c == '?' || Some(c) == text.get(t) => {
+                p = p.saturating_add(1);
+                t = t.saturating_add(1);
+            }
+            _ => match star {
+                // Let the star eat one more character
+                Some((star_p, star_t)) => {
+                    p = star_p.saturating_add(1);
+                    t = star_t.saturating_add(1);
+                    star = Some((star_p, t));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern
+        .get(p..)
+        .is_some_and(|rest| rest.iter().all(|c| *c == '*'))
+}
+
+/// Hands out the paths while going through the loaded scene tree (parents have to come before their children)
+pub struct PathBuilder {
+    scene: String,
+    // Path of every glTF node seen so far, the primitives of a mesh all share the same node
+    node_paths: HashMap<usize, String>,
+    // Segments already used under each path
+    used_segments: HashMap<String, Vec<String>>,
+}
+
+impl PathBuilder {
+    pub fn new(scene_name: &str) -> Self {
+        PathBuilder {
+            scene: scene_name.replace(SEPARATOR, "_"),
+            node_paths: HashMap::new(),
+            used_segments: HashMap::new(),
+        }
+    }
+
+    /// Path of an object of the scene, None for the root of the tree which isn't exported
+    pub fn path_for(&mut self, object: &gltf_loader::SceneObject) -> Option<String> {
+        let (node_index, parents, name, primitive) = match object {
+            gltf_loader::SceneObject::Root => return None,
+            gltf_loader::SceneObject::Mesh(model) => (
+                model.index(),
+                model.parents().as_slice(),
+                model.node_name(),
+                Some(model.primitive_index()),
+            ),
+            gltf_loader::SceneObject::Empties(empty) => {
+                (empty.id, empty.parent_ids(), empty.name.as_deref(), None)
+            }
+        };
+
+        let node_path = match self.node_paths.get(&node_index) {
+            Some(path) => path.clone(),
+            None => {
+                let parent_path = parents
+                    .last()
+                    .and_then(|parent| self.node_paths.get(parent))
+                    .unwrap_or(&self.scene)
+                    .clone();
+
+                let siblings = self.used_segments.entry(parent_path.clone()).or_default();
+                let segment = segment(name, node_index, siblings);
+                let path = join(&parent_path, &segment);
+                siblings.push(segment);
+
+                self.node_paths.insert(node_index, path.clone());
+                path
+            }
+        };
+
+        Some(match primitive {
+            Some(primitive) => join(&node_path, &primitive.to_string()),
+            None => node_path,
+        })
+    }
+}
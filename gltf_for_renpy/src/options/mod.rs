@@ -0,0 +1,98 @@
+use crate::composition::Attachment;
+use crate::export_rules::ExportRules;
+use crate::groups::GroupOptions;
+use crate::images::TextureOptions;
+use gltf_loader::SceneSelection;
+use gltf_loader::utils::CoordinateConvention;
+use serde::Deserialize;
+
+// Everything that changes how a file gets converted for renpy
+// Python sends this over as a json object so that we don't have to keep the ctypes struct in sync every time something is added
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct ExportOptions {
+    /// Which scenes of the file get converted (ex: `"Default"`, `{"Names": ["Scene"]}` or `{"Indices": [0, 2]}`)
+    pub scenes: SceneSelection,
+
+    /// Which nodes of the scenes get exported (ex: `{"exclude": ["**/COL_*"], "excluded_children": "Drop"}`)
+    pub export_rules: ExportRules,
+
+    /// Where the visibility groups come from (ex: `{"key": "outfit", "exclusive_separator": "_"}`)
+    pub groups: GroupOptions,
+
+    /// Embed the texture data in the flatbuffer instead of only sending the texture name
+    pub use_embed_textures: bool,
+
+    /// How the embedded textures are prepared (ex: `{"premultiply_alpha": true}`)
+    pub textures: TextureOptions,
+
+    /// Merge the vertices that are the same (within `weld_epsilon`) and build an index buffer out of them
+    pub weld_vertices: bool,
+
+    /// How close two vertex values have to be to get merged
+    pub weld_epsilon: f32,
+
+    /// Reorder the triangles and vertices of every mesh for better GPU cache usage
+    pub optimize_meshes: bool,
+
+    /// Triangle ratio for each LOD to generate (ex: `[0.5, 0.25]` for half and a quarter of the triangles)
+    pub lod_levels: Vec<f32>,
+
+    /// The most error allowed when simplifying a LOD, relative to the size of the mesh (1.0 is the whole mesh)
+    pub lod_max_error: f32,
+
+    /// Also compute scene bounds that hold every keyframe of every animation
+    pub animated_bounds: bool,
+
+    /// Move sheared or mirrored transforms into the points of the mesh since renpy can't show them with a transform.
+    /// Only done for meshes that have no children, skeleton, animations or GPU instances, the others get `transform_is_lossy` set instead
+    pub bake_lossy_transforms: bool,
+
+    /// Only send the geometry once for meshes used by more than one node, the other nodes get `instance_of` set instead
+    pub share_geometry: bool,
+
+    /// Files to graft under nodes of the loaded scenes (ex: `[{"file": "sword.glb", "target": "**/Hand_L"}]`)
+    pub attachments: Vec<Attachment>,
+
+    /// Axes, euler order and scale of the coordinates sent to renpy
+    pub coordinate_convention: CoordinateConvention,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            scenes: SceneSelection::All,
+            export_rules: ExportRules::default(),
+            groups: GroupOptions::default(),
+            use_embed_textures: false,
+            textures: TextureOptions::default(),
+            weld_vertices: false,
+            weld_epsilon: 1e-5,
+            optimize_meshes: false,
+            lod_levels: Vec::new(),
+            // Anything smaller than this tends to stop way before reaching the triangle target
+            lod_max_error: 0.1,
+            animated_bounds: false,
+            bake_lossy_transforms: false,
+            share_geometry: false,
+            attachments: Vec::new(),
+            coordinate_convention: CoordinateConvention::default(),
+        }
+    }
+}
+
+impl ExportOptions {
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let options = gltf::json::deserialize::from_str::<ExportOptions>(json)?;
+
+        // Positions get divided by it to go back to gltf coordinates
+        let units_to_pixels = options.coordinate_convention.units_to_pixels;
+        if units_to_pixels == 0.0 || !units_to_pixels.is_finite() {
+            anyhow::bail!(
+                "units_to_pixels has to be a finite non zero number, not {units_to_pixels}"
+            );
+        }
+
+        Ok(options)
+    }
+}
@@ -1,6 +1,7 @@
 // Most of this can be refactored once I figure out how to make this a python c module
 
 use std::{
+    collections::HashMap,
     ffi::{CString, c_char},
     fmt::{Debug, Display},
     mem,
@@ -8,8 +9,9 @@ use std::{
     ptr::{null_mut, slice_from_raw_parts},
 };
 
-use cgmath::{Matrix, Matrix4};
+use cgmath::{Matrix, Matrix4, SquareMatrix};
 use gltf_loader::utils::RotationTransform;
+use nohash_hasher::IntMap;
 
 use crate::gltf_objects;
 
@@ -251,6 +253,43 @@ pub type NodeID = u32;
 pub struct SceneTree {
     pub nodes: Vec<SceneNode>,
     pub roots: Vec<NodeID>,
+    // Kept up to date by push and push_root, call rebuild_index if nodes gets changed by hand
+    pub index: NodeIndex,
+}
+
+// Hash lookups so that finding a node doesn't mean going through the whole tree every time
+#[derive(Clone, Debug, Default)]
+pub struct NodeIndex {
+    pub by_id: IntMap<gltf_objects::ObjectId, NodeID>,
+    // glTF node name to every node made from a glTF node with that name, in tree order.
+    // There's more than one when names repeat in the file or when a mesh has several primitives
+    pub by_name: HashMap<String, Vec<NodeID>>,
+    pub by_path: HashMap<String, NodeID>,
+}
+
+impl NodeIndex {
+    fn insert(&mut self, node: NodeID, value: &gltf_objects::GltfObject) {
+        self.by_id.entry(value.id()).or_insert(node);
+        if let Some(name) = value.node_name() {
+            self.by_name.entry(name.to_owned()).or_default().push(node);
+        }
+        self.by_path.entry(value.path().to_owned()).or_insert(node);
+    }
+
+    pub fn find_id(&self, find_id: gltf_objects::ObjectId) -> Result<NodeID, NodeNotFoundInTree> {
+        self.by_id.get(&find_id).copied().ok_or(NodeNotFoundInTree)
+    }
+
+    pub fn find_name(&self, name: &str) -> Result<&[NodeID], NodeNotFoundInTree> {
+        self.by_name
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or(NodeNotFoundInTree)
+    }
+
+    pub fn find_path(&self, path: &str) -> Result<NodeID, NodeNotFoundInTree> {
+        self.by_path.get(path).copied().ok_or(NodeNotFoundInTree)
+    }
 }
 
 #[derive(Debug)]
@@ -269,12 +308,14 @@ impl SceneTree {
         SceneTree {
             nodes: Vec::new(),
             roots: Vec::new(),
+            index: NodeIndex::default(),
         }
     }
 
     #[allow(clippy::cast_possible_truncation)]
     pub fn push_root(&mut self, value: gltf_objects::GltfObject) -> NodeID {
         let new_index = self.nodes.len();
+        self.index.insert(new_index as NodeID, &value);
         self.nodes.push(SceneNode::new(value));
         self.roots.push(new_index as NodeID);
 
@@ -297,14 +338,36 @@ impl SceneTree {
 
     // Find Node based off gltf_object node
     pub fn find_node(&self, find_id: usize) -> Result<NodeID, NodeNotFoundInTree> {
+        self.index.find_id(find_id)
+    }
+
+    // Find the nodes made from glTF nodes with this name (ex: `Hand_L`)
+    pub fn find_node_by_name(&self, name: &str) -> Result<&[NodeID], NodeNotFoundInTree> {
+        self.index.find_name(name)
+    }
+
+    // Find Node based off its path (ex: `Scene/Armature/Hips`)
+    pub fn find_node_by_path(&self, path: &str) -> Result<NodeID, NodeNotFoundInTree> {
+        self.index.find_path(path)
+    }
+
+    /// Every node with a path matching the glob pattern (see `node_path::glob_match`), in tree order
+    pub fn query(&self, pattern: &str) -> Vec<NodeID> {
+        (0..)
+            .zip(&self.nodes)
+            .filter(|(_, node)| crate::node_path::glob_match(pattern, node.value.path()))
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Builds the lookup tables again from scratch, only needed after editing `nodes` directly
+    pub fn rebuild_index(&mut self) {
+        self.index = NodeIndex::default();
         for (id, node) in self.nodes.iter().enumerate() {
-            if node.value.is_same_id(find_id) {
-                #[allow(clippy::cast_possible_truncation)]
-                return Ok(id as NodeID);
-            }
+            #[allow(clippy::cast_possible_truncation)]
+            // Truncation is fine since we will realistically not go over this... hopefully?
+            self.index.insert(id as NodeID, &node.value);
         }
-
-        Err(NodeNotFoundInTree)
     }
 
     pub fn push(
@@ -313,6 +376,8 @@ impl SceneTree {
         value: gltf_objects::GltfObject,
     ) -> Result<NodeID, NodeNotFoundInTree> {
         let new_index = self.nodes.len();
+        #[allow(clippy::cast_possible_truncation)]
+        self.index.insert(new_index as NodeID, &value);
         self.nodes.push(SceneNode::new(value));
         match self.nodes.get_mut(root_node as usize) {
             Some(root_node) => {
@@ -329,6 +394,105 @@ impl SceneTree {
         // Truncation is fine since we will realistically not go over this... hopefully?
         Ok(new_index as NodeID)
     }
+
+    /// Multiplies the local matrices from the roots down to get the matrix of every node in scene space
+    /// The result is in the same order as `nodes`
+    pub fn world_matrices(&self, local: impl Fn(&SceneNode) -> Matrix4<f32>) -> Vec<Matrix4<f32>> {
+        let mut world = vec![Matrix4::identity(); self.nodes.len()];
+
+        let mut stack: Vec<(NodeID, Matrix4<f32>)> = self
+            .roots
+            .iter()
+            .rev()
+            .map(|root| (*root, Matrix4::identity()))
+            .collect();
+
+        while let Some((node_id, parent)) = stack.pop() {
+            let Some(node) = self.get_node(node_id) else {
+                continue;
+            };
+
+            // Float matrices can't overflow like integers so the lint doesn't matter here
+            #[allow(clippy::arithmetic_side_effects)]
+            let matrix = parent * local(node);
+            if let Some(slot) = world.get_mut(node_id as usize) {
+                *slot = matrix;
+            }
+
+            stack.extend(node.children.iter().rev().map(|child| (*child, matrix)));
+        }
+
+        world
+    }
+
+    /// Parent of every node (None for roots), in the same order as `nodes`
+    pub fn parents(&self) -> Vec<Option<NodeID>> {
+        let mut parents = vec![None; self.nodes.len()];
+        for (id, node) in self.nodes.iter().enumerate() {
+            for child in &node.children {
+                if let Some(parent) = parents.get_mut(*child as usize) {
+                    #[allow(clippy::cast_possible_truncation)]
+                    // Truncation is fine since we will realistically not go over this... hopefully?
+                    let id = id as NodeID;
+                    *parent = Some(id);
+                }
+            }
+        }
+        parents
+    }
+
+    /// Every node ordered so that a parent always comes before its children
+    /// (depth first from the roots, so it's also the order renpy would walk the tree in)
+    pub fn evaluation_order(&self) -> Vec<NodeID> {
+        let mut order = Vec::with_capacity(self.nodes.len());
+        let mut stack: Vec<NodeID> = self.roots.iter().rev().copied().collect();
+
+        while let Some(node_id) = stack.pop() {
+            let Some(node) = self.get_node(node_id) else {
+                continue;
+            };
+            order.push(node_id);
+            stack.extend(node.children.iter().rev());
+        }
+
+        order
+    }
+
+    /// World matrices of every node with the rest pose, except for the nodes in `overrides` which use the given local matrix instead
+    /// Handy to get where everything ends up for a single frame of an animation
+    pub fn world_matrices_with_overrides(
+        &self,
+        overrides: &HashMap<NodeID, Matrix4<f32>>,
+    ) -> Vec<Matrix4<f32>> {
+        let parents = self.parents();
+        let mut world = vec![Matrix4::identity(); self.nodes.len()];
+
+        for node_id in self.evaluation_order() {
+            let Some(node) = self.get_node(node_id) else {
+                continue;
+            };
+            let local = overrides
+                .get(&node_id)
+                .copied()
+                .unwrap_or(*node.value.rest_matrix());
+            let parent = parents
+                .get(node_id as usize)
+                .copied()
+                .flatten()
+                .and_then(|parent| world.get(parent as usize))
+                .copied()
+                .unwrap_or(Matrix4::identity());
+
+            if let Some(slot) = world.get_mut(node_id as usize) {
+                // Float matrices can't overflow like integers so the lint doesn't matter here
+                #[allow(clippy::arithmetic_side_effects)]
+                let matrix = parent * local;
+                *slot = matrix;
+            }
+        }
+
+        world
+    }
 }
 
 #[derive(Clone, Debug)]
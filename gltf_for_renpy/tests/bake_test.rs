@@ -0,0 +1,104 @@
+mod common;
+
+use cgmath::{Matrix4, SquareMatrix, Vector3};
+use common::with_flatbuffer;
+use gltf_for_renpy::gltf_objects::{GltfObject, RenpyScene, mesh::Mesh};
+use gltf_for_renpy::mesh_processing::bake;
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes};
+
+const PATH: &str = "./tests/Transforms/LossyTransforms.gltf";
+
+// Node ids in the test file
+const MIRRORED: usize = 0;
+const SHEARED: usize = 1;
+const CHILD: usize = 3;
+
+fn mesh(scene: &RenpyScene, id: usize) -> &Mesh {
+    scene
+        .objects
+        .nodes
+        .iter()
+        .find_map(|node| match &node.value {
+            GltfObject::Mesh(_, mesh) if mesh.id == id => Some(mesh.as_ref()),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn lossy_transforms_are_detected() {
+    let scale = Matrix4::from_nonuniform_scale(1.0, 2.0, 3.0);
+    assert!(!bake::is_lossy(&scale));
+    assert!(!bake::is_lossy(&Matrix4::from_angle_z(cgmath::Deg(30.0))));
+
+    let mirrored = Matrix4::from_nonuniform_scale(1.0, -1.0, 1.0);
+    assert!(bake::is_mirrored(&mirrored));
+    assert!(!bake::has_shear(&mirrored));
+
+    let mut sheared = Matrix4::identity();
+    sheared.y.x = 0.5;
+    assert!(bake::has_shear(&sheared));
+    assert!(!bake::is_mirrored(&sheared));
+
+    let scenes = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+    assert!(bake::is_mirrored(&mesh(&scenes[0], MIRRORED).rest_matrix));
+    assert!(bake::has_shear(&mesh(&scenes[0], SHEARED).rest_matrix));
+}
+
+#[test]
+fn baking_moves_the_transform_into_the_points() {
+    let options = ExportOptions {
+        bake_lossy_transforms: true,
+        ..Default::default()
+    };
+    let original = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+    let baked = load_renpy_scenes(PATH, &options).unwrap();
+
+    let mirrored = mesh(&baked[0], MIRRORED);
+    assert_eq!(mirrored.rest_matrix, Matrix4::identity());
+    // x = -x + 2 and y flipped for renpy
+    assert_eq!(
+        mirrored.vertexes,
+        vec![2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, -1.0, 0.0]
+    );
+    // Winding got flipped to keep the face pointing the same way
+    assert_eq!(mirrored.triangles, vec![0, 2, 1]);
+    assert_eq!(mirrored.bounds.min, Vector3::new(1.0, -1.0, 0.0));
+
+    let sheared = mesh(&baked[0], SHEARED);
+    assert_eq!(sheared.rest_matrix, Matrix4::identity());
+    assert_eq!(
+        sheared.vertexes,
+        vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.5, -1.0, 0.0]
+    );
+    assert_eq!(sheared.triangles, mesh(&original[0], SHEARED).triangles);
+
+    // Its parent has the transform, not the mesh itself
+    let child = mesh(&baked[0], CHILD);
+    assert_eq!(child.vertexes, mesh(&original[0], CHILD).vertexes);
+    assert_eq!(child.triangles, vec![0, 1, 2]);
+}
+
+#[test]
+fn matrix_is_serialized() {
+    with_flatbuffer(PATH, Some("{}"), |scenes| {
+        let scene = scenes.scenes().get(0);
+
+        let mut lossy = Vec::new();
+        for node in scene.objects() {
+            if let Some(mesh) = node.object_as_mesh() {
+                assert!(mesh.matrix().is_some());
+                lossy.push((mesh.id() as usize, mesh.transform_is_lossy()));
+            }
+            if let Some(empty) = node.object_as_empties() {
+                assert!(empty.matrix().is_some());
+                lossy.push((empty.id() as usize, empty.transform_is_lossy()));
+            }
+        }
+        lossy.sort();
+        assert_eq!(
+            lossy,
+            vec![(MIRRORED, true), (SHEARED, true), (2, true), (CHILD, false)]
+        );
+    });
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use common::{meshes, with_flatbuffer};
+use gltf_for_renpy::ExportOptions;
+use gltf_for_renpy::gltf_objects::mesh::Mesh;
+use gltf_for_renpy::images::{ImageData, TextureOptions, apply_factor};
+
+// Tinted and Plain share the same 2x1 texture: an orange texel and a half transparent white one
+const PATH: &str = "./tests/BaseColor/BaseColor.gltf";
+
+fn pixels(mesh: &Mesh) -> &Vec<u8> {
+    let ImageData::RGBA(pixels) = &mesh.texture.data else {
+        panic!("The texture should be embedded");
+    };
+    pixels
+}
+
+#[test]
+fn factor_is_applied_in_linear_space() {
+    // Half of linear 1.0 is 188 in sRGB, not 127
+    let mut pixels = vec![255, 128, 0, 255];
+    apply_factor(&mut pixels, [0.5, 1.0, 1.0, 0.5], false);
+    assert_eq!(pixels, vec![188, 128, 0, 128]);
+}
+
+#[test]
+fn alpha_is_premultiplied_in_srgb_space() {
+    // White at half alpha is half of the sRGB value, the factor alone still goes through linear space
+    let mut pixels = vec![255, 255, 255, 128];
+    apply_factor(&mut pixels, [1.0; 4], true);
+    assert_eq!(pixels, vec![128, 128, 128, 128]);
+
+    let mut pixels = vec![255, 255, 255, 255];
+    apply_factor(&mut pixels, [0.5, 1.0, 1.0, 0.25], true);
+    assert_eq!(pixels, vec![47, 64, 64, 64]);
+}
+
+#[test]
+fn factor_does_not_depend_on_texture_sharing() {
+    let options = ExportOptions {
+        use_embed_textures: true,
+        ..Default::default()
+    };
+    let meshes = meshes(PATH, &options);
+    let (tinted, plain) = (&meshes[0], &meshes[1]);
+
+    assert!(tinted.texture.factor_applied);
+    assert_eq!(pixels(tinted), &vec![188, 128, 0, 128, 188, 255, 255, 64]);
+
+    // The shared texture isn't touched by the factor of the other material
+    assert_eq!(pixels(plain), &vec![255, 128, 0, 255, 255, 255, 255, 128]);
+}
+
+#[test]
+fn factor_can_be_sent_separately() {
+    let options = ExportOptions {
+        use_embed_textures: true,
+        textures: TextureOptions {
+            bake_base_color_factor: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let tinted = &meshes(PATH, &options)[0];
+
+    assert!(!tinted.texture.factor_applied);
+    assert_eq!(pixels(tinted), &vec![255, 128, 0, 255, 255, 255, 255, 128]);
+    assert_eq!(tinted.material.base_color_factor.x, 0.5);
+
+    // Textures that aren't embedded can't have it
+    let tinted = &meshes(PATH, &ExportOptions::default())[0];
+    assert!(!tinted.texture.factor_applied);
+}
+
+#[test]
+fn alpha_can_be_premultiplied() {
+    let options = ExportOptions {
+        use_embed_textures: true,
+        textures: TextureOptions {
+            premultiply_alpha: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let meshes = meshes(PATH, &options);
+    let (tinted, plain, flat) = (&meshes[0], &meshes[1], &meshes[2]);
+
+    assert!(plain.texture.premultiplied);
+    assert_eq!(pixels(plain), &vec![255, 128, 0, 255, 128, 128, 128, 128]);
+    assert_eq!(pixels(tinted), &vec![94, 64, 0, 128, 47, 64, 64, 64]);
+
+    // Colors are linear already
+    let ImageData::Color(color) = flat.texture.data else {
+        panic!("Flat has no texture");
+    };
+    assert_eq!(color, [0.5, 0.25, 0.125, 0.5]);
+}
+
+#[test]
+fn factor_is_sent_to_renpy() {
+    with_flatbuffer(PATH, Some(r#"{"use_embed_textures": true}"#), |scenes| {
+        let tinted = scenes
+            .scenes()
+            .get(0)
+            .objects()
+            .get(0)
+            .object_as_mesh()
+            .unwrap();
+
+        assert!(tinted.texture().unwrap().factor_applied());
+        assert!(!tinted.texture().unwrap().premultiplied());
+        assert_eq!(
+            tinted
+                .material()
+                .unwrap()
+                .base_color_factor()
+                .unwrap()
+                .rgba()
+                .iter()
+                .collect::<Vec<f32>>(),
+            vec![0.5, 1.0, 1.0, 0.5]
+        );
+    });
+}
@@ -142,8 +142,8 @@ fn animation_test() {
                                         anim.target_type().variant_name().unwrap(),
                                     );
 
-                                    let mut index = 0_u32;
-                                    for frame in anim.frames().unwrap().iter() {
+                                    for (index, frame) in anim.frames().unwrap().iter().enumerate()
+                                    {
                                         // let value = frame.value().unwrap().rotation().unwrap();
                                         // let value = cgmath::Vector3::new(value.x(), value.y(), value.z());
                                         // if let Some(other) = list.last(){
@@ -155,7 +155,6 @@ fn animation_test() {
                                             frame.time(),
                                             frame.value().unwrap().rotation().unwrap()
                                         );
-                                        index += 1;
                                     }
                                 }
                             }
@@ -311,7 +310,7 @@ fn skeleton_test() {
                                     print!(" {} |", bone.id());
                                 }
                             }
-                            print!("\n");
+                            println!();
                         }
 
                         // if y.object_type() == flatbuffer::Object::Empties {
@@ -0,0 +1,295 @@
+mod common;
+
+use cgmath::{EuclideanSpace, Matrix4, Point3, Transform, Vector3};
+use common::{scene_meshes, with_flatbuffer};
+use gltf_for_renpy::bounds::{self, Aabb, framing};
+use gltf_for_renpy::gltf_objects::mesh::Mesh;
+use gltf_for_renpy::gltf_objects::{GltfObject, RenpyScene};
+use gltf_for_renpy::{ExportOptions, compute_framing, load_renpy_scenes};
+use gltf_loader::utils::GlobalNodeIdentifier;
+
+const EPSILON: f32 = 1e-4;
+
+fn contains(aabb: &Aabb, x: f32, y: f32, z: f32) -> bool {
+    aabb.min.x - EPSILON <= x
+        && x <= aabb.max.x + EPSILON
+        && aabb.min.y - EPSILON <= y
+        && y <= aabb.max.y + EPSILON
+        && aabb.min.z - EPSILON <= z
+        && z <= aabb.max.z + EPSILON
+}
+
+#[test]
+fn mesh_bounds_hold_every_point() {
+    let scenes = load_renpy_scenes(
+        "./tests/TestYukikioModelStylized.glb",
+        &ExportOptions::default(),
+    )
+    .unwrap();
+
+    for scene in &scenes {
+        for mesh in scene_meshes(scene) {
+            assert!(!mesh.bounds.is_empty());
+
+            let sphere = mesh.bounding_sphere;
+            for point in mesh.vertexes.chunks_exact(3) {
+                assert!(contains(&mesh.bounds, point[0], point[1], point[2]));
+
+                let distance = ((point[0] - sphere.center.x).powi(2)
+                    + (point[1] - sphere.center.y).powi(2)
+                    + (point[2] - sphere.center.z).powi(2))
+                .sqrt();
+                assert!(distance <= sphere.radius + EPSILON);
+            }
+        }
+    }
+}
+
+#[test]
+fn scene_bounds_hold_every_point_in_scene_space() {
+    for path in [
+        "./tests/TestCubeModel.glb",
+        "./tests/SkelthalTest/parenting_test.glb",
+    ] {
+        let scenes = load_renpy_scenes(path, &ExportOptions::default()).unwrap();
+
+        for scene in &scenes {
+            assert!(!scene.bounds.is_empty());
+            assert!(scene.animated_bounds.is_none());
+
+            let world = scene
+                .objects
+                .world_matrices(|node| *node.value.rest_matrix());
+
+            for (node, matrix) in scene.objects.nodes.iter().zip(&world) {
+                let GltfObject::Mesh(_, mesh) = &node.value else {
+                    continue;
+                };
+
+                for point in mesh.vertexes.chunks_exact(3) {
+                    let point = matrix.transform_point(Point3::new(point[0], point[1], point[2]));
+                    assert!(contains(&scene.bounds, point.x, point.y, point.z), "{path}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn animated_bounds_hold_default_pose() {
+    let options = ExportOptions {
+        animated_bounds: true,
+        ..Default::default()
+    };
+    let scenes = load_renpy_scenes("./tests/AnimationTest.glb", &options).unwrap();
+
+    for scene in &scenes {
+        let animated = scene.animated_bounds.unwrap();
+        assert_eq!(animated.union(&scene.bounds), animated);
+        assert_eq!(animated, bounds::animated_scene_bounds(scene));
+    }
+}
+
+// Points of a skinned mesh once its joints are posed with `world`
+fn skinned_points(scene: &RenpyScene, mesh: &Mesh, world: &[Matrix4<f32>]) -> Vec<Point3<f32>> {
+    let skeleton = mesh.skeleton.as_ref().unwrap();
+    let joints: Vec<usize> = skeleton
+        .bones
+        .iter()
+        .map(|bone| match bone {
+            GlobalNodeIdentifier::NodeId(id) => scene.objects.find_node(*id).unwrap() as usize,
+            GlobalNodeIdentifier::ObjectIndex(index) => *index,
+            GlobalNodeIdentifier::SceneRoot => panic!("Joints are nodes"),
+        })
+        .collect();
+
+    mesh.vertexes
+        .chunks_exact(3)
+        .zip(mesh.bone_indexes.chunks_exact(4))
+        .zip(mesh.bone_weights.chunks_exact(4))
+        .map(|((point, bones), weights)| {
+            let point = Point3::new(point[0], point[1], point[2]);
+            let mut skinned = Vector3::new(0.0, 0.0, 0.0);
+            for (bone, weight) in bones.iter().zip(weights) {
+                let bone = *bone as usize;
+                let matrix = world[joints[bone]] * skeleton.inverse_bind_matrixes[bone];
+                skinned += matrix.transform_point(point).to_vec() * *weight;
+            }
+            Point3::from_vec(skinned)
+        })
+        .collect()
+}
+
+#[test]
+fn skinned_bounds_follow_the_joints() {
+    let options = ExportOptions {
+        animated_bounds: true,
+        ..Default::default()
+    };
+    // The arm of SkinnedBounds moves its triangle about 10 units away while the mesh node stays put
+    for path in [
+        "./tests/SkelthalTest/Skelthal.glb",
+        "./tests/Skinning/SkinnedBounds.gltf",
+    ] {
+        let scenes = load_renpy_scenes(path, &options).unwrap();
+        let scene = &scenes[0];
+        let animated = scene.animated_bounds.unwrap();
+
+        let rest_world = scene
+            .objects
+            .world_matrices(|node| *node.value.rest_matrix());
+        let mut skinned_meshes = 0;
+        for mesh in scene_meshes(scene)
+            .into_iter()
+            .filter(|mesh| mesh.skeleton.is_some())
+        {
+            skinned_meshes += 1;
+            for point in skinned_points(scene, mesh, &rest_world) {
+                assert!(contains(&scene.bounds, point.x, point.y, point.z));
+            }
+
+            for set in scene
+                .objects
+                .nodes
+                .iter()
+                .flat_map(|node| node.value.animations())
+            {
+                for frame in &set.animation.frames {
+                    let world = scene.objects.world_matrices(|node| {
+                        node.value
+                            .animations()
+                            .iter()
+                            .find(|other| other.name == set.name)
+                            .and_then(|other| {
+                                bounds::sample_frames(
+                                    &other.animation.frames,
+                                    other.animation.interpolation,
+                                    frame.time,
+                                )
+                            })
+                            .unwrap_or(*node.value.rest_matrix())
+                    });
+
+                    for point in skinned_points(scene, mesh, &world) {
+                        assert!(contains(&animated, point.x, point.y, point.z));
+                    }
+                }
+            }
+        }
+        assert!(skinned_meshes > 0);
+    }
+
+    let scenes = load_renpy_scenes("./tests/Skinning/SkinnedBounds.gltf", &options).unwrap();
+    assert!(scenes[0].animated_bounds.unwrap().size().x >= 10.0 - EPSILON);
+}
+
+#[test]
+fn channels_keep_their_interpolation() {
+    let scenes = load_renpy_scenes(
+        "./tests/Skinning/SkinnedBounds.gltf",
+        &ExportOptions::default(),
+    )
+    .unwrap();
+    let arm = scenes[0]
+        .objects
+        .nodes
+        .iter()
+        .find(|node| node.value.name().ends_with("Arm"))
+        .unwrap();
+    let set = &arm.value.animations()[0];
+
+    // The translation is linear while the rotation steps at the second keyframe
+    let halfway =
+        bounds::sample_frames(&set.animation.frames, set.animation.interpolation, 0.5).unwrap();
+    assert!((halfway.w.x.abs() - 5.0).abs() < EPSILON);
+    assert!((halfway.x.x - 1.0).abs() < EPSILON);
+    assert!(halfway.x.y.abs() < EPSILON);
+}
+
+#[test]
+fn bounds_are_serialized() {
+    with_flatbuffer(
+        "tests/AnimationTest.glb",
+        Some(r#"{"animated_bounds": true}"#),
+        |scenes| {
+            for scene in scenes.scenes() {
+                let bounds = scene.bounds().unwrap();
+                let animated = scene.animated_bounds().unwrap();
+                assert!(animated.min().x() <= bounds.min().x());
+                assert!(animated.max().y() >= bounds.max().y());
+
+                for node in scene.objects() {
+                    if let Some(mesh) = node.object_as_mesh() {
+                        assert!(mesh.bounds().is_some());
+                        assert!(mesh.bounding_sphere().unwrap().radius() > 0.0);
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn framing_fits_viewport() {
+    let scenes = load_renpy_scenes(
+        "./tests/TestYukikioModelStylized.glb",
+        &ExportOptions::default(),
+    )
+    .unwrap();
+    let scene_bounds = scenes[0].bounds;
+
+    let framing = framing::frame_bounds(&scene_bounds, 1920.0, 1080.0, 0.9).unwrap();
+
+    let min_x = scene_bounds.min.x * framing.zoom + framing.offset[0];
+    let max_x = scene_bounds.max.x * framing.zoom + framing.offset[0];
+    let min_y = scene_bounds.min.y * framing.zoom + framing.offset[1];
+    let max_y = scene_bounds.max.y * framing.zoom + framing.offset[1];
+
+    // Centered
+    assert!((min_x + max_x).abs() < 0.01);
+    assert!((min_y + max_y).abs() < 0.01);
+
+    // Fits and touches the closest edge
+    assert!(max_x - min_x <= 1920.0 * 0.9 + 0.01);
+    assert!(max_y - min_y <= 1080.0 * 0.9 + 0.01);
+    assert!(
+        (max_x - min_x - 1920.0 * 0.9).abs() < 0.01 || (max_y - min_y - 1080.0 * 0.9).abs() < 0.01
+    );
+
+    // The whole model is between the near and far planes
+    let [near, z_one_one, far] = framing.perspective;
+    let half_depth = (scene_bounds.max.z - scene_bounds.min.z) * framing.zoom / 2.0;
+    assert!(near < z_one_one - half_depth);
+    assert!(far > z_one_one + half_depth);
+}
+
+#[test]
+fn framing_uses_renpy_perspective() {
+    // Flat enough to fit with renpy's own `perspective True`
+    let flat = Aabb {
+        min: Vector3::new(-1.0, -1.0, -0.1),
+        max: Vector3::new(1.0, 1.0, 0.1),
+    };
+    let framing = framing::frame_bounds(&flat, 1920.0, 1080.0, 1.0).unwrap();
+    assert_eq!(framing.perspective, [100.0, 1000.0, 100_000.0]);
+
+    // Deep enough that it would cross the camera plane, the camera moves back instead
+    let deep = Aabb {
+        min: Vector3::new(-1.0, -1.0, -5.0),
+        max: Vector3::new(1.0, 1.0, 5.0),
+    };
+    let framing = framing::frame_bounds(&deep, 1920.0, 1080.0, 1.0).unwrap();
+    let [near, z_one_one, far] = framing.perspective;
+    let half_depth = 10.0 * framing.zoom / 2.0;
+    assert_eq!(near, 100.0);
+    assert!(z_one_one - half_depth > near);
+    assert!(far > z_one_one + half_depth);
+}
+
+#[test]
+fn framing_empty_bounds() {
+    assert!(framing::frame_bounds(&Aabb::empty(), 1920.0, 1080.0, 1.0).is_none());
+
+    let framing = compute_framing(0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1080.0, 1.0);
+    assert_eq!(framing.zoom, 0.0);
+}
@@ -0,0 +1,67 @@
+// Fixtures shared by the integration tests, each test file only uses some of them
+#![allow(dead_code)]
+
+use gltf_for_renpy::gltf_objects::{GltfObject, RenpyScene, mesh::Mesh};
+use gltf_for_renpy::renpy_interop::{GLTFResult, ImmutableRenpyList};
+use gltf_for_renpy::{
+    ExportOptions, ResultCode, free_scene_list, load_file_with_options, load_renpy_scenes,
+};
+use gltf_for_renpy_flatbuffer as flatbuffer;
+use std::ffi::{CString, c_void};
+use std::ptr::{null, slice_from_raw_parts};
+
+/// Meshes of the scene in node order
+pub fn scene_meshes(scene: &RenpyScene) -> Vec<&Mesh> {
+    scene
+        .objects
+        .nodes
+        .iter()
+        .filter_map(|node| match &node.value {
+            GltfObject::Mesh(_, mesh) => Some(mesh.as_ref()),
+            GltfObject::Empty(_, _) => None,
+        })
+        .collect()
+}
+
+/// Meshes of the first scene of the file
+pub fn meshes(path: &str, options: &ExportOptions) -> Vec<Mesh> {
+    let scenes = load_renpy_scenes(path, options).unwrap();
+    scene_meshes(&scenes[0]).into_iter().cloned().collect()
+}
+
+/// Loads the file through the C interface like renpy does, with the json `options` when given,
+/// and hands the flatbuffer to `check` before freeing it
+pub fn with_flatbuffer(path: &str, options: Option<&str>, check: impl FnOnce(flatbuffer::Scenes)) {
+    let file_path = CString::new(path).unwrap();
+    let options = options.map(|options| CString::new(options).unwrap());
+
+    unsafe {
+        check_scene_list(
+            load_file_with_options(
+                file_path.as_ptr(),
+                options.as_ref().map_or(null(), |options| options.as_ptr()),
+            ),
+            check,
+        );
+    }
+}
+
+/// Same as `with_flatbuffer` for the other C functions loading files, `model_list` being what they returned
+///
+/// # Safety
+///
+/// `model_list` has to come from one of the C functions and not be freed yet
+pub unsafe fn check_scene_list(
+    model_list: *const GLTFResult<ImmutableRenpyList<u8>>,
+    check: impl FnOnce(flatbuffer::Scenes),
+) {
+    unsafe {
+        assert!(matches!((*model_list).result_type, ResultCode::Ok));
+
+        let content = &*(*model_list).content;
+        let buffer = slice_from_raw_parts(content.content, content.len);
+        check(flatbuffer::root_as_scenes(&*buffer).unwrap());
+
+        free_scene_list(model_list as *mut c_void);
+    }
+}
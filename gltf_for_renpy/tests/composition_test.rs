@@ -0,0 +1,207 @@
+mod common;
+
+use cgmath::{AbsDiffEq, Vector4};
+use common::check_scene_list;
+use gltf_for_renpy::composition::{self, Attachment, Offset};
+use gltf_for_renpy::{
+    ExportOptions, ResultCode, free_scene_list, load_file_with_attachment, load_renpy_scenes,
+};
+use std::ffi::{CString, c_void};
+use std::ptr::null;
+
+const HOST: &str = "./tests/Filtering/Filtering.gltf";
+const PROP: &str = "./tests/Scenes/MultiScene.gltf";
+
+fn attached(offset: Offset) -> gltf_for_renpy::gltf_objects::RenpyScene {
+    let options = ExportOptions {
+        attachments: vec![Attachment {
+            file: PROP.to_owned(),
+            target: "**/Marker".to_owned(),
+            offset,
+        }],
+        ..Default::default()
+    };
+    load_renpy_scenes(HOST, &options).unwrap().remove(0)
+}
+
+#[test]
+fn attachment_is_grafted_under_target() {
+    let scene = attached(Offset::default());
+    let tree = &scene.objects;
+
+    // Body, COL_Body, Marker, Reference, Prop, Light then the socket and the default scene (Main) of the prop
+    let paths: Vec<&str> = tree.nodes.iter().map(|node| node.value.path()).collect();
+    assert_eq!(
+        paths[6..],
+        [
+            "Scene/Body/COL_Body/Marker/Main",
+            "Scene/Body/COL_Body/Marker/Main/MainTriangle/0",
+            "Scene/Body/COL_Body/Marker/Main/MainEmpty",
+        ]
+    );
+
+    let parents = tree.parents();
+    assert_eq!(parents[6], Some(2));
+    assert_eq!(parents[7], Some(6));
+    assert_eq!(parents[8], Some(6));
+    assert_eq!(tree.roots.len(), 3);
+
+    assert!(scene.mesh_indexes.contains(&7));
+    assert!(scene.empty_indexes.contains(&6));
+    assert!(scene.empty_indexes.contains(&8));
+    assert_eq!(
+        tree.find_node_by_path("Scene/Body/COL_Body/Marker/Main/MainEmpty")
+            .unwrap(),
+        8
+    );
+}
+
+#[test]
+fn offset_moves_the_attachment() {
+    let scene = attached(Offset {
+        translation: [0.0, 2.0, 0.0],
+        ..Default::default()
+    });
+    let world = scene
+        .objects
+        .world_matrices(|node| *node.value.rest_matrix());
+
+    // Marker is at y 1 and y is flipped for renpy
+    assert!(
+        world[7]
+            .w
+            .abs_diff_eq(&Vector4::new(0.0, -3.0, 0.0, 1.0), 1e-5)
+    );
+
+    // The bounds follow
+    assert!(scene.bounds.min.y.abs_diff_eq(&-4.0, 1e-5));
+}
+
+#[test]
+fn attaching_twice_keeps_paths_unique() {
+    let mut scene = attached(Offset::default());
+    let prop = load_renpy_scenes(PROP, &ExportOptions::default()).unwrap();
+    let main = prop.into_iter().find(|scene| scene.is_default).unwrap();
+
+    let socket = composition::attach(
+        &mut scene,
+        "Scene/Body/COL_Body/Marker",
+        main.clone(),
+        &Offset::default(),
+        &ExportOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(socket, 9);
+    assert_eq!(
+        scene.objects.nodes[9].value.path(),
+        "Scene/Body/COL_Body/Marker/Main#9"
+    );
+    assert_eq!(
+        scene.objects.nodes[10].value.path(),
+        "Scene/Body/COL_Body/Marker/Main#9/MainTriangle/0"
+    );
+
+    assert!(
+        composition::attach(
+            &mut scene,
+            "**/Missing",
+            main,
+            &Offset::default(),
+            &ExportOptions::default()
+        )
+        .is_err()
+    );
+
+    let options = ExportOptions {
+        attachments: vec![Attachment {
+            file: PROP.to_owned(),
+            target: "**/Missing".to_owned(),
+            offset: Offset::default(),
+        }],
+        ..Default::default()
+    };
+    assert!(load_renpy_scenes(HOST, &options).is_err());
+}
+
+#[test]
+fn attached_objects_keep_their_own_ids() {
+    let mut scene = attached(Offset::default());
+    let prop = load_renpy_scenes(PROP, &ExportOptions::default()).unwrap();
+    let main = prop.into_iter().find(|scene| scene.is_default).unwrap();
+    let second = composition::attach(
+        &mut scene,
+        "Scene/Light",
+        main,
+        &Offset::default(),
+        &ExportOptions::default(),
+    )
+    .unwrap();
+    let tree = &scene.objects;
+
+    // Every node is found by its own id, the sockets and the two props included
+    for (node_id, node) in tree.nodes.iter().enumerate() {
+        assert_eq!(
+            tree.find_node(node.value.id()).unwrap() as usize,
+            node_id,
+            "{}",
+            node.value.path()
+        );
+    }
+
+    let triangles = tree.query("**/MainTriangle/*");
+    assert_eq!(triangles.len(), 2);
+    assert_ne!(
+        tree.nodes[triangles[0] as usize].value.id(),
+        tree.nodes[triangles[1] as usize].value.id()
+    );
+    assert_ne!(
+        tree.nodes[5].value.id(),
+        tree.nodes[second as usize].value.id()
+    );
+}
+
+#[test]
+fn attach_from_renpy() {
+    let options = ExportOptions::from_json(
+        r#"{"attachments": [{"file": "prop.glb", "target": "**/Hand_L", "offset": {"translation": [0, 1, 0]}}]}"#,
+    )
+    .unwrap();
+    assert_eq!(options.attachments[0].offset.translation, [0.0, 1.0, 0.0]);
+    assert_eq!(options.attachments[0].offset.scale, [1.0; 3]);
+
+    let file_path = CString::new(HOST).unwrap();
+    let attachment_path = CString::new(PROP).unwrap();
+    let target = CString::new("**/Marker").unwrap();
+
+    unsafe {
+        let model_list = load_file_with_attachment(
+            file_path.as_ptr(),
+            attachment_path.as_ptr(),
+            target.as_ptr(),
+            null(),
+        );
+        check_scene_list(model_list, |scenes| {
+            let scene = scenes.scenes().get(0);
+
+            assert_eq!(scene.objects().len(), 9);
+            assert_eq!(scene.objects().get(7).parent(), 6);
+            assert_eq!(
+                scene.objects().get(2).children().iter().collect::<Vec<_>>(),
+                vec![6]
+            );
+        });
+
+        let missing = CString::new("**/Missing").unwrap();
+        let model_list = load_file_with_attachment(
+            file_path.as_ptr(),
+            attachment_path.as_ptr(),
+            missing.as_ptr(),
+            null(),
+        );
+        assert!(matches!(
+            (*model_list).result_type,
+            ResultCode::BadFileProcessing
+        ));
+        free_scene_list(model_list as *mut c_void);
+    }
+}
@@ -0,0 +1,133 @@
+use cgmath::Vector3;
+use gltf_for_renpy::gltf_objects::GltfObject;
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes};
+use gltf_loader::utils::{Axis, CoordinateConvention, Handedness};
+
+fn options(convention: CoordinateConvention) -> ExportOptions {
+    ExportOptions {
+        coordinate_convention: convention,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn convention_is_applied_everywhere() {
+    let convention = CoordinateConvention {
+        up: Axis::Z,
+        handedness: Handedness::Right,
+        units_to_pixels: 100.0,
+        ..Default::default()
+    };
+    let renpy = CoordinateConvention::default();
+
+    let default_scenes = load_renpy_scenes(
+        "./tests/SkelthalTest/Skelthal.glb",
+        &ExportOptions::default(),
+    )
+    .unwrap();
+    let converted_scenes =
+        load_renpy_scenes("./tests/SkelthalTest/Skelthal.glb", &options(convention)).unwrap();
+
+    let mut checked_skeleton = false;
+    for (default_scene, converted_scene) in default_scenes.iter().zip(&converted_scenes) {
+        for (default_node, converted_node) in default_scene
+            .objects
+            .nodes
+            .iter()
+            .zip(&converted_scene.objects.nodes)
+        {
+            // Both are taken back to gltf coords and then moved to the other convention
+            let expected_matrix =
+                convention.convert_matrix(renpy.matrix_to_gltf(*default_node.value.rest_matrix()));
+            assert!(cgmath::AbsDiffEq::abs_diff_eq(
+                &expected_matrix,
+                converted_node.value.rest_matrix(),
+                1e-3
+            ));
+
+            let (GltfObject::Mesh(_, default_mesh), GltfObject::Mesh(_, converted_mesh)) =
+                (&default_node.value, &converted_node.value)
+            else {
+                continue;
+            };
+
+            for (default_point, converted_point) in default_mesh
+                .vertexes
+                .chunks_exact(3)
+                .zip(converted_mesh.vertexes.chunks_exact(3))
+            {
+                let gltf = renpy.point_to_gltf(Vector3::new(
+                    default_point[0],
+                    default_point[1],
+                    default_point[2],
+                ));
+                let expected = convention.convert_point(gltf);
+                assert_eq!(
+                    [expected.x, expected.y, expected.z],
+                    [converted_point[0], converted_point[1], converted_point[2]]
+                );
+            }
+
+            if let (Some(default_skeleton), Some(converted_skeleton)) =
+                (&default_mesh.skeleton, &converted_mesh.skeleton)
+            {
+                for (default_bind, converted_bind) in default_skeleton
+                    .inverse_bind_matrixes
+                    .iter()
+                    .zip(&converted_skeleton.inverse_bind_matrixes)
+                {
+                    let expected = convention.convert_matrix(renpy.matrix_to_gltf(*default_bind));
+                    assert!(cgmath::AbsDiffEq::abs_diff_eq(
+                        &expected,
+                        converted_bind,
+                        1e-3
+                    ));
+                }
+                checked_skeleton = true;
+            }
+
+            for (default_set, converted_set) in default_mesh
+                .animations
+                .iter()
+                .zip(&converted_mesh.animations)
+            {
+                for (default_frame, converted_frame) in default_set
+                    .animation
+                    .frames
+                    .iter()
+                    .zip(&converted_set.animation.frames)
+                {
+                    let translation =
+                        renpy.point_to_gltf(default_frame.value.transformation.translation);
+                    assert_eq!(
+                        convention.convert_point(translation),
+                        converted_frame.value.transformation.translation
+                    );
+                }
+            }
+        }
+    }
+
+    assert!(checked_skeleton);
+}
+
+#[test]
+fn convention_from_json() {
+    let options = ExportOptions::from_json(
+        r#"{"coordinate_convention": {"up": "Z", "euler_order": "XYZ", "units_to_pixels": 100.0}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(options.coordinate_convention.up, Axis::Z);
+    assert_eq!(options.coordinate_convention.units_to_pixels, 100.0);
+    // Anything not given stays at renpy's defaults
+    assert_eq!(options.coordinate_convention.handedness, Handedness::Left);
+
+    // Positions can't be brought back to gltf coordinates with a scale of 0
+    assert!(
+        ExportOptions::from_json(r#"{"coordinate_convention": {"units_to_pixels": 0.0}}"#).is_err()
+    );
+    assert!(
+        ExportOptions::from_json(r#"{"coordinate_convention": {"units_to_pixels": -2.0}}"#).is_ok()
+    );
+}
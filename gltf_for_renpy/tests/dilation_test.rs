@@ -0,0 +1,68 @@
+mod common;
+
+use common::meshes;
+use gltf_for_renpy::ExportOptions;
+use gltf_for_renpy::images::{ImageData, TextureOptions, dilate};
+
+const PATH: &str = "./tests/Dilation/Dilation.gltf";
+
+// A 5x1 strip: an opaque red texel, three fully transparent black ones and a half transparent green one
+fn strands(dilation_radius: u32) -> Vec<u8> {
+    let options = ExportOptions {
+        use_embed_textures: true,
+        textures: TextureOptions {
+            dilation_radius,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let texture = meshes(PATH, &options).remove(0).texture;
+
+    let ImageData::RGBA(pixels) = texture.data else {
+        panic!("The texture should be embedded");
+    };
+    pixels
+}
+
+#[test]
+fn transparent_texels_take_their_neighbours_color() {
+    assert_eq!(
+        strands(0),
+        vec![
+            255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 128
+        ]
+    );
+    assert_eq!(
+        strands(1),
+        vec![
+            255, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 128
+        ]
+    );
+    // The middle texel averages the colors that reached it
+    assert_eq!(
+        strands(2),
+        vec![
+            255, 0, 0, 255, 255, 0, 0, 0, 128, 128, 0, 0, 0, 255, 0, 0, 0, 255, 0, 128
+        ]
+    );
+}
+
+#[test]
+fn dilation_reaches_diagonals() {
+    // 3x3 with a single opaque texel in the corner
+    let mut pixels = vec![0; 3 * 3 * 4];
+    pixels[..4].copy_from_slice(&[10, 20, 30, 255]);
+
+    dilate(&mut pixels, 3, 3, 1);
+    let colored: Vec<bool> = pixels.chunks_exact(4).map(|texel| texel[0] == 10).collect();
+    assert_eq!(
+        colored,
+        vec![true, true, false, true, true, false, false, false, false]
+    );
+    assert!(pixels.chunks_exact(4).skip(1).all(|texel| texel[3] == 0));
+
+    // Images without any color are left alone
+    let mut empty = vec![0; 2 * 2 * 4];
+    dilate(&mut empty, 2, 2, 4);
+    assert!(empty.iter().all(|&value| value == 0));
+}
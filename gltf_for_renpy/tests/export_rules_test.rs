@@ -0,0 +1,234 @@
+use cgmath::{InnerSpace, Matrix4};
+use gltf_for_renpy::export_rules::{ExcludedChildren, ExportRules, ObjectType};
+use gltf_for_renpy::gltf_objects::GltfObject;
+use gltf_for_renpy::renpy_interop::NodeID;
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes};
+use gltf_loader::utils::GlobalNodeIdentifier;
+
+const PATH: &str = "./tests/Filtering/Filtering.gltf";
+
+// (path, parent path) of every exported node
+fn export(export_rules: ExportRules) -> Vec<(String, Option<String>)> {
+    let options = ExportOptions {
+        export_rules,
+        ..Default::default()
+    };
+    let scenes = load_renpy_scenes(PATH, &options).unwrap();
+    let tree = &scenes[0].objects;
+
+    let path = |id: NodeID| tree.nodes[id as usize].value.path().to_owned();
+    tree.nodes
+        .iter()
+        .zip(tree.parents())
+        .map(|(node, parent)| (node.value.path().to_owned(), parent.map(path)))
+        .collect()
+}
+
+fn node(path: &str, parent: Option<&str>) -> (String, Option<String>) {
+    (path.to_owned(), parent.map(str::to_owned))
+}
+
+#[test]
+fn ignored_extra_is_left_out() {
+    // Prop moves up to the root with Reference gone
+    assert_eq!(
+        export(ExportRules {
+            ignore_extra: Some("renpy_ignore".to_owned()),
+            ..Default::default()
+        }),
+        vec![
+            node("Scene/Body/0", None),
+            node("Scene/Body/COL_Body/0", Some("Scene/Body/0")),
+            node("Scene/Body/COL_Body/Marker", Some("Scene/Body/COL_Body/0")),
+            node("Scene/Reference/Prop/0", None),
+            node("Scene/Light", None),
+        ]
+    );
+
+    // Off by default so plain loads keep every node
+    let kept = export(ExportRules::default());
+    assert_eq!(kept.len(), 6);
+    assert_eq!(kept[3], node("Scene/Reference", None));
+    assert_eq!(
+        kept[4],
+        node("Scene/Reference/Prop/0", Some("Scene/Reference"))
+    );
+}
+
+#[test]
+fn children_are_reparented_or_dropped() {
+    let rules = ExportRules {
+        exclude: vec!["**/COL_*".to_owned()],
+        ..Default::default()
+    };
+    assert_eq!(
+        export(rules.clone()),
+        vec![
+            node("Scene/Body/0", None),
+            node("Scene/Body/COL_Body/Marker", Some("Scene/Body/0")),
+            node("Scene/Reference", None),
+            node("Scene/Reference/Prop/0", Some("Scene/Reference")),
+            node("Scene/Light", None),
+        ]
+    );
+
+    assert_eq!(
+        export(ExportRules {
+            excluded_children: ExcludedChildren::Drop,
+            ..rules
+        }),
+        vec![
+            node("Scene/Body/0", None),
+            node("Scene/Reference", None),
+            node("Scene/Reference/Prop/0", Some("Scene/Reference")),
+            node("Scene/Light", None),
+        ]
+    );
+}
+
+#[test]
+fn include_and_type_rules() {
+    assert_eq!(
+        export(ExportRules {
+            include: vec!["Scene/Body/**".to_owned()],
+            ..Default::default()
+        }),
+        vec![
+            node("Scene/Body/0", None),
+            node("Scene/Body/COL_Body/0", Some("Scene/Body/0")),
+            node("Scene/Body/COL_Body/Marker", Some("Scene/Body/COL_Body/0")),
+        ]
+    );
+
+    assert_eq!(
+        export(ExportRules {
+            exclude_types: vec![ObjectType::Mesh],
+            ..Default::default()
+        }),
+        vec![
+            node("Scene/Body/COL_Body/Marker", None),
+            node("Scene/Reference", None),
+            node("Scene/Light", None),
+        ]
+    );
+
+    let options = ExportOptions::from_json(
+        r#"{"export_rules": {"exclude": ["**/COL_*"], "exclude_types": ["Empty"], "excluded_children": "Drop"}}"#,
+    )
+    .unwrap();
+    assert_eq!(options.export_rules.exclude, vec!["**/COL_*".to_owned()]);
+    assert_eq!(options.export_rules.exclude_types, vec![ObjectType::Empty]);
+    assert_eq!(
+        options.export_rules.excluded_children,
+        ExcludedChildren::Drop
+    );
+    // Only on when given
+    assert_eq!(options.export_rules.ignore_extra, None);
+    let options =
+        ExportOptions::from_json(r#"{"export_rules": {"ignore_extra": "renpy_ignore"}}"#).unwrap();
+    assert_eq!(
+        options.export_rules.ignore_extra.as_deref(),
+        Some("renpy_ignore")
+    );
+}
+
+// Same as Filtering but COL_Body is moved, turned and scaled
+const MOVED_HELPER_PATH: &str = "./tests/Filtering/MovedHelper.gltf";
+
+// Matrix of the node in scene space
+fn world_matrix(export_rules: ExportRules, path: &str) -> Matrix4<f32> {
+    let options = ExportOptions {
+        export_rules,
+        ..Default::default()
+    };
+    let scenes = load_renpy_scenes(MOVED_HELPER_PATH, &options).unwrap();
+    let tree = &scenes[0].objects;
+
+    let id = tree.find_node_by_path(path).unwrap();
+    tree.world_matrices(|node| *node.value.rest_matrix())[id as usize]
+}
+
+#[test]
+fn reparented_children_keep_their_place() {
+    let rules = ExportRules {
+        exclude: vec!["**/COL_*".to_owned()],
+        ..Default::default()
+    };
+    let expected = world_matrix(ExportRules::default(), "Scene/Body/COL_Body/Marker");
+    let reparented = world_matrix(rules, "Scene/Body/COL_Body/Marker");
+
+    let expected: [[f32; 4]; 4] = expected.into();
+    let reparented: [[f32; 4]; 4] = reparented.into();
+    for (expected, reparented) in expected.iter().flatten().zip(reparented.iter().flatten()) {
+        assert!(
+            (expected - reparented).abs() < 1e-4,
+            "{expected} != {reparented}"
+        );
+    }
+
+    // The decomposed transform moves along with the matrix
+    let options = ExportOptions {
+        export_rules: ExportRules {
+            exclude: vec!["**/COL_*".to_owned()],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let scenes = load_renpy_scenes(MOVED_HELPER_PATH, &options).unwrap();
+    let tree = &scenes[0].objects;
+    let marker = tree
+        .get_value(
+            tree.find_node_by_path("Scene/Body/COL_Body/Marker")
+                .unwrap(),
+        )
+        .unwrap();
+    let GltfObject::Empty(_, marker) = marker else {
+        panic!("Marker should be an empty");
+    };
+    let offset = marker.transform.translation - marker.rest_matrix.w.truncate();
+    assert!(offset.magnitude() < 1e-4);
+}
+
+#[test]
+fn parents_of_included_nodes_are_kept() {
+    let expected = vec![
+        node("Scene/Body/0", None),
+        node("Scene/Body/COL_Body/0", Some("Scene/Body/0")),
+        node("Scene/Body/COL_Body/Marker", Some("Scene/Body/COL_Body/0")),
+    ];
+    for excluded_children in [ExcludedChildren::Reparent, ExcludedChildren::Drop] {
+        assert_eq!(
+            export(ExportRules {
+                include: vec!["**/Marker".to_owned()],
+                excluded_children,
+                ..Default::default()
+            }),
+            expected
+        );
+    }
+}
+
+#[test]
+fn joints_are_never_left_out() {
+    let options = ExportOptions {
+        export_rules: ExportRules {
+            exclude: vec!["**/Arm".to_owned()],
+            excluded_children: ExcludedChildren::Drop,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let scenes = load_renpy_scenes("./tests/Skinning/SkinnedBounds.gltf", &options).unwrap();
+    let tree = &scenes[0].objects;
+
+    let arm = tree.find_node_by_path("Scene/Arm").unwrap();
+    let body = tree.find_node_by_path("Scene/Body/0").unwrap();
+    let Some(GltfObject::Mesh(_, body)) = tree.get_value(body) else {
+        panic!("Body should be a mesh");
+    };
+    let bones = &body.skeleton.as_ref().unwrap().bones;
+    assert!(matches!(
+        bones.as_slice(),
+        [GlobalNodeIdentifier::ObjectIndex(index)] if *index == arm as usize
+    ));
+}
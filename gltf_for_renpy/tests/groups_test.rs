@@ -0,0 +1,104 @@
+mod common;
+
+use common::with_flatbuffer;
+use gltf_for_renpy::composition::{Attachment, Offset};
+use gltf_for_renpy::groups::{GroupOptions, VisibilityGroup};
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes};
+
+const PATH: &str = "./tests/Groups/Outfits.gltf";
+
+// Groups named `<set>_<name>` are exclusive
+fn exclusive_options() -> ExportOptions {
+    ExportOptions {
+        groups: GroupOptions {
+            exclusive_separator: Some("_".to_owned()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn groups(options: &ExportOptions) -> Vec<VisibilityGroup> {
+    load_renpy_scenes(PATH, options).unwrap().remove(0).groups
+}
+
+fn group(
+    name: &str,
+    nodes: Vec<u32>,
+    set: Option<&str>,
+    exclusive_with: Vec<u32>,
+) -> VisibilityGroup {
+    VisibilityGroup {
+        name: name.to_owned(),
+        nodes,
+        exclusive_set: set.map(str::to_owned),
+        exclusive_with,
+    }
+}
+
+#[test]
+fn groups_come_from_extras() {
+    // Body, School, Shirt, Skirt, Casual, Hoodie, Glasses
+    assert_eq!(
+        groups(&exclusive_options()),
+        vec![
+            // From the mesh extras
+            group("glasses", vec![6], None, vec![]),
+            group("hooded", vec![5], None, vec![]),
+            group("outfit_casual", vec![4, 5], Some("outfit"), vec![3]),
+            group("outfit_school", vec![1, 2, 3], Some("outfit"), vec![2]),
+        ]
+    );
+
+    // Exclusive sets are opt-in
+    assert!(
+        groups(&ExportOptions::default())
+            .iter()
+            .all(|group| group.exclusive_set.is_none() && group.exclusive_with.is_empty())
+    );
+
+    let options = ExportOptions::from_json(r#"{"groups": {"key": "outfit"}}"#).unwrap();
+    assert!(groups(&options).is_empty());
+}
+
+#[test]
+fn attached_groups_are_merged() {
+    let options = ExportOptions {
+        attachments: vec![Attachment {
+            file: PATH.to_owned(),
+            target: "Scene/Body/0".to_owned(),
+            offset: Offset::default(),
+        }],
+        ..exclusive_options()
+    };
+    let groups = groups(&options);
+
+    // The socket is 7 so the attached nodes start at 8
+    assert_eq!(groups.len(), 4);
+    assert_eq!(groups[0].nodes, vec![6, 14]);
+    assert_eq!(groups[3].nodes, vec![1, 2, 3, 9, 10, 11]);
+    assert_eq!(groups[3].exclusive_with, vec![2]);
+}
+
+#[test]
+fn groups_are_sent_to_renpy() {
+    let options = r#"{"groups": {"exclusive_separator": "_"}}"#;
+
+    with_flatbuffer(PATH, Some(options), |scenes| {
+        let scene = scenes.scenes().get(0);
+
+        let groups = scene.groups().unwrap();
+        assert_eq!(groups.len(), 4);
+
+        let school = groups.get(3);
+        assert_eq!(school.name(), "outfit_school");
+        assert_eq!(school.nodes().iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(school.exclusive_set(), Some("outfit"));
+        assert_eq!(
+            school.exclusive_with().unwrap().iter().collect::<Vec<_>>(),
+            vec![2]
+        );
+
+        assert_eq!(groups.get(0).exclusive_set(), None);
+    });
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use cgmath::{AbsDiffEq, Matrix4, SquareMatrix, Vector3};
+use common::with_flatbuffer;
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes};
+use std::collections::HashMap;
+
+const PATH: &str = "./tests/SkelthalTest/parenting_test.glb";
+const SHARED_NAMES_PATH: &str = "./tests/Hierarchy/SharedNames.gltf";
+
+#[test]
+fn parents_come_before_children() {
+    let scenes = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+
+    for scene in &scenes {
+        let tree = &scene.objects;
+        let parents = tree.parents();
+        let order = tree.evaluation_order();
+        assert_eq!(order.len(), tree.nodes.len());
+
+        let position = |id: u32| order.iter().position(|other| *other == id).unwrap();
+        for (id, parent) in parents.iter().enumerate() {
+            match parent {
+                Some(parent) => {
+                    assert!(tree.nodes[*parent as usize].children.contains(&(id as u32)));
+                    assert!(position(*parent) < position(id as u32));
+                }
+                None => assert!(tree.roots.contains(&(id as u32))),
+            }
+        }
+    }
+}
+
+#[test]
+fn overrides_move_children_too() {
+    let scenes = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+    let tree = &scenes[0].objects;
+
+    let rest = tree.world_matrices(|node| *node.value.rest_matrix());
+    assert_eq!(tree.world_matrices_with_overrides(&HashMap::new()), rest);
+
+    let (parent, node) = tree
+        .nodes
+        .iter()
+        .enumerate()
+        .find(|(_, node)| !node.children.is_empty())
+        .unwrap();
+    let child = node.children[0] as usize;
+
+    let offset = Matrix4::from_translation(Vector3::new(10.0, 0.0, 0.0));
+    let local = offset * node.value.rest_matrix();
+    let moved = tree.world_matrices_with_overrides(&HashMap::from([(parent as u32, local)]));
+
+    // Both the parent and the child picked up the extra translation
+    for id in [parent, child] {
+        let expected = rest[id] + Matrix4::from_translation(Vector3::new(10.0, 0.0, 0.0))
+            - Matrix4::identity();
+        assert!(moved[id].abs_diff_eq(&expected, 1e-4), "{id}");
+    }
+}
+
+#[test]
+fn hierarchy_is_serialized() {
+    let scenes = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+    let tree = &scenes[0].objects;
+    let rest = tree.world_matrices(|node| *node.value.rest_matrix());
+
+    with_flatbuffer(PATH, Some("{}"), |scenes| {
+        let scene = scenes.scenes().get(0);
+
+        let order: Vec<u32> = scene.evaluation_order().unwrap().iter().collect();
+        assert_eq!(order, tree.evaluation_order());
+
+        for ((node, parent), world) in scene.objects().iter().zip(tree.parents()).zip(&rest) {
+            assert_eq!(node.parent(), parent.map(|p| p as i32).unwrap_or(-1));
+
+            let path = node
+                .object_as_mesh()
+                .and_then(|mesh| mesh.path())
+                .or_else(|| node.object_as_empties().and_then(|empty| empty.path()));
+            assert!(path.unwrap().starts_with("Scene/"));
+
+            let matrix: [[f32; 4]; 4] = (*world).into();
+            let serialized: Vec<f32> = node.world_matrix().unwrap().mat().iter().collect();
+            assert_eq!(serialized, matrix.as_flattened());
+        }
+    });
+}
+
+#[test]
+fn nodes_are_found_by_id_and_name() {
+    let scenes = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+    let tree = &scenes[0].objects;
+
+    for (index, node) in tree.nodes.iter().enumerate() {
+        assert_eq!(tree.find_node(node.value.id()).unwrap(), index as u32);
+
+        let found = tree
+            .find_node_by_name(node.value.node_name().unwrap())
+            .unwrap();
+        assert!(found.contains(&(index as u32)));
+        for id in found {
+            assert_eq!(
+                tree.nodes[*id as usize].value.node_name(),
+                node.value.node_name()
+            );
+        }
+    }
+    assert!(tree.find_node(usize::MAX).is_err());
+    assert!(tree.find_node_by_name("Not a node").is_err());
+
+    let mut rebuilt = tree.clone();
+    rebuilt.rebuild_index();
+    assert_eq!(rebuilt.index.by_id, tree.index.by_id);
+    assert_eq!(rebuilt.index.by_name, tree.index.by_name);
+}
+
+#[test]
+fn shared_names_keep_every_node() {
+    let scenes = load_renpy_scenes(SHARED_NAMES_PATH, &ExportOptions::default()).unwrap();
+    let tree = &scenes[0].objects;
+    let path = |id: &u32| tree.nodes[*id as usize].value.path();
+
+    // Two nodes named `Hand`, each with both primitives of the mesh
+    let hands: Vec<_> = tree
+        .find_node_by_name("Hand")
+        .unwrap()
+        .iter()
+        .map(path)
+        .collect();
+    assert_eq!(
+        hands,
+        [
+            "Scene/Arm_L/Hand/0",
+            "Scene/Arm_L/Hand/1",
+            "Scene/Arm_R/Hand/0",
+            "Scene/Arm_R/Hand/1"
+        ]
+    );
+
+    let arm: Vec<_> = tree
+        .find_node_by_name("Arm_R")
+        .unwrap()
+        .iter()
+        .map(path)
+        .collect();
+    assert_eq!(arm, ["Scene/Arm_R"]);
+
+    // The display name isn't a node name
+    assert!(tree.find_node_by_name("Scene:Glove:0").is_err());
+}
+
+#[test]
+fn node_names_can_be_binary_searched() {
+    let scenes = load_renpy_scenes(SHARED_NAMES_PATH, &ExportOptions::default()).unwrap();
+    let tree = &scenes[0].objects;
+
+    with_flatbuffer(SHARED_NAMES_PATH, Some("{}"), |scenes| {
+        let scene = scenes.scenes().get(0);
+        let names = scene.node_names().unwrap();
+        assert_eq!(names.len(), tree.index.by_name.len());
+
+        for (name, ids) in &tree.index.by_name {
+            let entry = names
+                .lookup_by_key(name.as_str(), |entry, key| {
+                    entry.key_compare_with_value(key)
+                })
+                .unwrap();
+            let indices: Vec<u32> = entry.indices().iter().collect();
+            assert_eq!(&indices, ids);
+        }
+        assert!(
+            names
+                .lookup_by_key("Hand", |entry, key| entry.key_compare_with_value(key))
+                .is_some_and(|entry| entry.indices().len() == 4)
+        );
+    });
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use common::with_flatbuffer;
+use gltf_for_renpy::gltf_objects::mesh::Mesh;
+use gltf_for_renpy::images::{
+    ImageData, ImageEncoding, PbrTextures, TextureOptions, decode_pixels, encode_pixels,
+};
+use gltf_for_renpy::{ExportOptions, ResultCode, decode_image_data, free_scene_list};
+use gltf_for_renpy_flatbuffer as flatbuffer;
+use std::ffi::c_void;
+use std::ptr::slice_from_raw_parts;
+
+const PATH: &str = "./tests/BaseColor/BaseColor.gltf";
+
+// The untouched texture of Plain: an orange texel and a half transparent white one
+const PLAIN: [u8; 8] = [255, 128, 0, 255, 255, 255, 255, 128];
+
+const ENCODINGS: [ImageEncoding; 4] = [
+    ImageEncoding::Raw,
+    ImageEncoding::Png,
+    ImageEncoding::Qoi,
+    ImageEncoding::Zstd,
+];
+
+fn encoded_meshes(path: &str, encoding: ImageEncoding, pbr_textures: PbrTextures) -> Vec<Mesh> {
+    let options = ExportOptions {
+        use_embed_textures: true,
+        textures: TextureOptions {
+            encoding,
+            pbr_textures,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    common::meshes(path, &options)
+}
+
+#[test]
+fn pixels_survive_every_encoding() {
+    let gray = [0, 64, 128, 255, 1, 2];
+    for encoding in ENCODINGS {
+        let encoded = encode_pixels(&PLAIN, 2, 1, 4, encoding).unwrap();
+        assert_eq!(
+            decode_pixels(&encoded, 2, 1, 4, encoding).unwrap(),
+            PLAIN,
+            "{encoding:?}"
+        );
+
+        if encoding != ImageEncoding::Qoi {
+            let encoded = encode_pixels(&gray, 3, 2, 1, encoding).unwrap();
+            assert_eq!(
+                decode_pixels(&encoded, 3, 2, 1, encoding).unwrap(),
+                gray,
+                "{encoding:?}"
+            );
+        }
+    }
+
+    // The size has to match the image
+    let encoded = encode_pixels(&PLAIN, 2, 1, 4, ImageEncoding::Zstd).unwrap();
+    assert!(decode_pixels(&encoded, 4, 1, 4, ImageEncoding::Zstd).is_err());
+}
+
+#[test]
+fn embedded_textures_are_encoded() {
+    for encoding in ENCODINGS {
+        let plain = &encoded_meshes(PATH, encoding, PbrTextures::None)[1];
+        assert_eq!(plain.texture.encoding, encoding);
+
+        let ImageData::RGBA(data) = &plain.texture.data else {
+            panic!("The texture should be embedded");
+        };
+        assert_eq!(decode_pixels(data, 2, 1, 4, encoding).unwrap(), PLAIN);
+    }
+
+    // Colors are left alone
+    let flat = &encoded_meshes(PATH, ImageEncoding::Png, PbrTextures::None)[2];
+    assert!(matches!(flat.texture.data, ImageData::Color(_)));
+    assert_eq!(flat.texture.encoding, ImageEncoding::Raw);
+}
+
+#[test]
+fn gray_images_stay_raw_with_qoi() {
+    let path = "./tests/PbrTextures/PbrTextures.gltf";
+
+    let material = &encoded_meshes(path, ImageEncoding::Qoi, PbrTextures::Channels)[0].material;
+    let metallic = material.metallic_texture.as_ref().unwrap();
+    assert_eq!(metallic.encoding, ImageEncoding::Raw);
+    assert!(matches!(&metallic.data, ImageData::Gray(data) if data == &vec![30]));
+
+    let material = &encoded_meshes(path, ImageEncoding::Zstd, PbrTextures::Channels)[0].material;
+    let metallic = material.metallic_texture.as_ref().unwrap();
+    assert_eq!(metallic.encoding, ImageEncoding::Zstd);
+}
+
+#[test]
+fn encoded_images_are_decoded_lazily() {
+    let options = r#"{"use_embed_textures": true, "textures": {"encoding": "Zstd"}}"#;
+    with_flatbuffer(PATH, Some(options), |scenes| {
+        let texture = scenes
+            .scenes()
+            .get(0)
+            .objects()
+            .get(1)
+            .object_as_mesh()
+            .unwrap()
+            .texture()
+            .unwrap();
+        assert_eq!(texture.encoding(), flatbuffer::ImageEncoding::Zstd);
+
+        let data = texture.data_as_rgba().unwrap().data().unwrap().bytes();
+        unsafe {
+            let pixels = decode_image_data(
+                data.as_ptr(),
+                data.len(),
+                texture.encoding().0,
+                texture.width(),
+                texture.height(),
+                4,
+            );
+            assert!(matches!((*pixels).result_type, ResultCode::Ok));
+            let decoded = &*(*pixels).content;
+            assert_eq!(&*slice_from_raw_parts(decoded.content, decoded.len), &PLAIN);
+            free_scene_list(pixels as *mut c_void);
+
+            // Unknown encodings are refused
+            let pixels = decode_image_data(data.as_ptr(), data.len(), 12, 2, 1, 4);
+            assert!(matches!(
+                (*pixels).result_type,
+                ResultCode::ImageDecodingFailure
+            ));
+            free_scene_list(pixels as *mut c_void);
+        }
+    });
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use cgmath::{AbsDiffEq, Vector3};
+use common::{meshes, scene_meshes, with_flatbuffer};
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes};
+
+const PATH: &str = "./tests/Instancing/Instancing.gltf";
+
+#[test]
+fn geometry_is_read_once() {
+    let scenes = gltf_loader::load(PATH).unwrap();
+    let models: Vec<_> = scenes[0]
+        .objects
+        .root()
+        .descendants()
+        .filter_map(|node| match node.value() {
+            gltf_loader::SceneObject::Mesh(model) => Some(model),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(models.len(), 3);
+    assert!(models[0].shares_geometry(models[1]));
+    assert!(models[0].shares_geometry(models[2]));
+}
+
+#[test]
+fn gpu_instances_are_loaded() {
+    let scenes = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+    let meshes = scene_meshes(&scenes[0]);
+
+    assert!(meshes[0].instances.is_empty());
+    let crowd = meshes[2];
+    assert_eq!(crowd.instances.len(), 3);
+
+    // y is flipped for renpy
+    assert_eq!(
+        crowd.instances[2].translation,
+        Vector3::new(0.0, -10.0, 0.0)
+    );
+    assert_eq!(crowd.instances[2].scale, Vector3::new(2.0, 2.0, 2.0));
+
+    // Every instance is in the scene bounds (the second one is turned a quarter around z, the last one is at y -10 with a scale of 2)
+    let bounds = scenes[0].bounds;
+    assert!(bounds.max.x.abs_diff_eq(&10.0, 1e-4));
+    assert!(bounds.min.y.abs_diff_eq(&-12.0, 1e-4));
+}
+
+#[test]
+fn shared_geometry_is_written_once() {
+    let options = ExportOptions {
+        share_geometry: true,
+        ..Default::default()
+    };
+    let instance_of: Vec<_> = meshes(PATH, &options)
+        .iter()
+        .map(|mesh| mesh.instance_of)
+        .collect();
+    assert_eq!(instance_of, vec![None, Some(0), Some(0)]);
+
+    // Off by default
+    assert!(
+        meshes(PATH, &ExportOptions::default())
+            .iter()
+            .all(|mesh| mesh.instance_of.is_none())
+    );
+
+    with_flatbuffer(PATH, Some(r#"{"share_geometry": true}"#), |scenes| {
+        let scene = scenes.scenes().get(0);
+        let meshes: Vec<_> = scene
+            .objects()
+            .iter()
+            .filter_map(|node| node.object_as_mesh())
+            .collect();
+
+        assert_eq!(meshes[0].instance_of(), -1);
+        assert_eq!(meshes[0].points().len(), 9);
+        assert!(meshes[0].texture().is_some());
+        assert!(meshes[0].instances().is_none());
+
+        for mesh in &meshes[1..] {
+            assert_eq!(mesh.instance_of(), 0);
+            assert!(mesh.points().is_empty());
+            assert!(mesh.triangles().is_empty());
+            assert!(mesh.texture().is_none());
+        }
+        assert_eq!(meshes[2].instances().unwrap().len(), 3);
+    });
+}
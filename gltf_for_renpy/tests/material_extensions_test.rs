@@ -0,0 +1,92 @@
+mod common;
+
+use cgmath::Vector3;
+use common::{meshes, with_flatbuffer};
+use gltf_for_renpy::ExportOptions;
+use gltf_for_renpy::gltf_objects::material::RenpyMaterial;
+use gltf_for_renpy_flatbuffer as flatbuffer;
+use gltf_loader::model::OutlineWidthMode;
+
+const PATH: &str = "./tests/Materials/Materials.gltf";
+
+fn materials() -> Vec<RenpyMaterial> {
+    meshes(PATH, &ExportOptions::default())
+        .into_iter()
+        .map(|mesh| mesh.material)
+        .collect()
+}
+
+#[test]
+fn khr_material_extensions() {
+    let materials = materials();
+    let (flat, glow, plain) = (&materials[0], &materials[1], &materials[3]);
+
+    assert!(flat.unlit);
+    assert!(!glow.unlit);
+
+    assert_eq!(glow.emissive_factor, Vector3::new(1.0, 0.5, 0.0));
+    assert_eq!(glow.emissive_strength, 4.0);
+
+    assert!(!plain.unlit);
+    assert_eq!(plain.emissive_strength, 1.0);
+    assert!(plain.toon.is_none());
+    assert!(plain.extensions.is_empty());
+}
+
+#[test]
+fn vendor_material_extensions() {
+    let materials = materials();
+    let toon_material = &materials[2];
+    let toon = toon_material.toon.as_ref().unwrap();
+
+    assert_eq!(toon.shade_color_factor, Vector3::new(0.2, 0.1, 0.3));
+    assert_eq!(toon.shading_toony_factor, 0.95);
+    assert_eq!(toon.outline_width_mode, OutlineWidthMode::Screen);
+    assert_eq!(toon.outline_width_factor, 0.01);
+    assert_eq!(toon.rim_color_factor, Vector3::new(1.0, 1.0, 1.0));
+    // Not in the file so the default of the extension
+    assert_eq!(toon.rim_fresnel_power, 5.0);
+
+    // Every extension the loader doesn't know about is passed on as json
+    let names: Vec<&str> = toon_material
+        .extensions
+        .iter()
+        .map(|extension| extension.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["ACME_materials_sparkle", "VRMC_materials_mtoon"]
+    );
+    assert_eq!(toon_material.extensions[0].value, r#"{"amount":2}"#);
+}
+
+#[test]
+fn materials_are_sent_to_renpy() {
+    with_flatbuffer(PATH, None, |scenes| {
+        let scene = scenes.scenes().get(0);
+        let material = |index: usize| {
+            scene
+                .objects()
+                .get(index)
+                .object_as_mesh()
+                .unwrap()
+                .material()
+                .unwrap()
+        };
+
+        assert!(material(0).unlit());
+        assert_eq!(material(1).emissive_strength(), 4.0);
+        assert_eq!(material(1).emissive_factor().unwrap().y(), 0.5);
+
+        let toon = material(2).toon().unwrap();
+        assert_eq!(
+            toon.outline_width_mode(),
+            flatbuffer::OutlineWidthMode::Screen
+        );
+        assert_eq!(toon.shade_color().unwrap().z(), 0.3);
+        assert_eq!(material(2).extensions().unwrap().len(), 2);
+
+        assert!(material(3).toon().is_none());
+        assert_eq!(material(3).name(), Some("Plain"));
+    });
+}
@@ -0,0 +1,284 @@
+mod common;
+
+use common::meshes;
+use gltf_for_renpy::{ExportOptions, gltf_objects::mesh::Mesh, mesh_processing::weld};
+
+fn optimized_options() -> ExportOptions {
+    ExportOptions {
+        optimize_meshes: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn optimize_is_opt_in() {
+    let meshes = meshes("./tests/TestCubeModel.glb", &ExportOptions::default());
+
+    assert!(!meshes.is_empty());
+    for mesh in meshes {
+        assert!(mesh.cache_stats.is_none());
+    }
+}
+
+#[test]
+fn optimize_keeps_streams_in_sync() {
+    for path in [
+        "./tests/TestCubeModel.glb",
+        "./tests/MorphTargets/MorphTargetsTest.glb",
+        "./tests/SkelthalTest/Skelthal.glb",
+    ] {
+        let original = meshes(path, &ExportOptions::default());
+        let optimized = meshes(path, &optimized_options());
+
+        assert_eq!(original.len(), optimized.len());
+        for (before, after) in original.iter().zip(&optimized) {
+            if before.triangles.is_empty() {
+                continue;
+            }
+
+            let stats = after.cache_stats.expect("mesh was not optimized");
+            assert!(stats.acmr_after <= stats.acmr_before, "{path}: {stats:?}");
+
+            let vertex_count = after.vertex_count();
+            assert_eq!(before.triangles.len(), after.triangles.len());
+            assert!(after.triangles.iter().all(|i| (*i as usize) < vertex_count));
+
+            if !before.uvs.is_empty() {
+                assert_eq!(after.uvs.len(), vertex_count * 2);
+            }
+            if !before.bone_weights.is_empty() {
+                assert_eq!(after.bone_indexes.len(), vertex_count * 4);
+                assert_eq!(after.bone_weights.len(), vertex_count * 4);
+            }
+            for target in &after.morph_targets {
+                assert_eq!(target.blend_shapes.len(), vertex_count);
+            }
+
+            // Same triangles (as positions) should still be drawn, just in a different order
+            let corners = |mesh: &Mesh| {
+                let mut corners: Vec<[u32; 3]> = mesh
+                    .triangles
+                    .iter()
+                    .map(|i| {
+                        let i = *i as usize * 3;
+                        [
+                            mesh.vertexes[i].to_bits(),
+                            mesh.vertexes[i + 1].to_bits(),
+                            mesh.vertexes[i + 2].to_bits(),
+                        ]
+                    })
+                    .collect();
+                corners.sort();
+                corners
+            };
+            assert_eq!(corners(before), corners(after));
+        }
+    }
+}
+
+#[test]
+fn optimize_is_deterministic() {
+    let first = meshes("./tests/SkelthalTest/Skelthal.glb", &optimized_options());
+    let second = meshes("./tests/SkelthalTest/Skelthal.glb", &optimized_options());
+
+    for (a, b) in first.iter().zip(&second) {
+        assert_eq!(a.triangles, b.triangles);
+        assert_eq!(a.vertexes, b.vertexes);
+        assert_eq!(a.bone_weights, b.bone_weights);
+    }
+}
+
+#[test]
+fn options_from_json() {
+    let options = ExportOptions::from_json(r#"{"optimize_meshes": true}"#).unwrap();
+    assert!(options.optimize_meshes);
+    assert!(!options.use_embed_textures);
+
+    assert!(ExportOptions::from_json("not json").is_err());
+}
+
+#[test]
+fn lods_get_smaller() {
+    let options = ExportOptions {
+        lod_levels: vec![0.5, 0.25],
+        lod_max_error: 1.0,
+        ..Default::default()
+    };
+    let meshes = meshes("./tests/TestYukikioModelStylized.glb", &options);
+
+    let mut lod_count = 0;
+    for mesh in meshes {
+        let vertex_count = mesh.vertex_count();
+        let mut previous_count = mesh.triangles.len();
+        let mut previous_error = 0.0;
+
+        for lod in &mesh.lods {
+            assert!(lod.triangles.len() % 3 == 0);
+            assert!(lod.triangles.len() < previous_count);
+            assert!(lod.triangles.iter().all(|i| (*i as usize) < vertex_count));
+            assert!(lod.error >= previous_error);
+
+            previous_count = lod.triangles.len();
+            previous_error = lod.error;
+            lod_count += 1;
+        }
+    }
+
+    assert!(lod_count > 0);
+}
+
+#[test]
+fn lods_keep_their_ratio() {
+    // The second 0.5 can't go any lower than the first one so it gets left out
+    let options = ExportOptions {
+        lod_levels: vec![0.5, 0.5, 0.25],
+        lod_max_error: 1.0,
+        ..Default::default()
+    };
+    let meshes = meshes("./tests/TestYukikioModelStylized.glb", &options);
+
+    for mesh in meshes.iter().filter(|mesh| !mesh.lods.is_empty()) {
+        assert!(mesh.lods.len() < 3);
+        assert_eq!(mesh.lods[0].target_ratio, 0.5);
+
+        for lod in &mesh.lods {
+            let ratio = lod.triangles.len() as f32 / mesh.triangles.len() as f32;
+            assert!((lod.achieved_ratio - ratio).abs() < 1e-6);
+        }
+        if let Some(last) = mesh.lods.get(1) {
+            assert_eq!(last.target_ratio, 0.25);
+        }
+    }
+}
+
+// Turns an indexed mesh back into triangle soup like some exporters give us
+fn to_triangle_soup(mesh: &Mesh) -> Mesh {
+    fn expand<T: Copy>(values: &[T], stride: usize, triangles: &[u32]) -> Vec<T> {
+        if values.is_empty() {
+            return Vec::new();
+        }
+        triangles
+            .iter()
+            .flat_map(|i| &values[*i as usize * stride..(*i as usize + 1) * stride])
+            .copied()
+            .collect()
+    }
+
+    let mut soup = mesh.clone();
+    soup.vertexes = expand(&mesh.vertexes, 3, &mesh.triangles);
+    soup.uvs = expand(&mesh.uvs, 2, &mesh.triangles);
+    soup.bone_indexes = expand(&mesh.bone_indexes, 4, &mesh.triangles);
+    soup.bone_weights = expand(&mesh.bone_weights, 4, &mesh.triangles);
+    for target in &mut soup.morph_targets {
+        target.blend_shapes = expand(&target.blend_shapes, 1, &mesh.triangles);
+    }
+    soup.triangles = Vec::new();
+
+    soup
+}
+
+#[test]
+fn weld_rebuilds_index_buffer() {
+    for path in [
+        "./tests/TestCubeModel.glb",
+        "./tests/MorphTargets/MorphTargetsTest.glb",
+        "./tests/SkelthalTest/Skelthal.glb",
+    ] {
+        for mesh in meshes(path, &ExportOptions::default()) {
+            if mesh.triangles.is_empty() {
+                continue;
+            }
+
+            let mut welded = to_triangle_soup(&mesh);
+            weld::weld_vertices(&mut welded, &[], 1e-5);
+
+            let vertex_count = welded.vertex_count();
+            assert!(vertex_count <= mesh.vertex_count(), "{path}");
+            assert_eq!(welded.triangles.len(), mesh.triangles.len(), "{path}");
+            assert!(
+                welded
+                    .triangles
+                    .iter()
+                    .all(|i| (*i as usize) < vertex_count)
+            );
+
+            assert_eq!(
+                welded.uvs.len(),
+                mesh.uvs.len() / mesh.vertex_count() * vertex_count
+            );
+            assert_eq!(
+                welded.bone_weights.len(),
+                mesh.bone_weights.len() / mesh.vertex_count() * vertex_count
+            );
+            for target in &welded.morph_targets {
+                assert_eq!(target.blend_shapes.len(), vertex_count);
+            }
+
+            // Every corner should still have the same data as before welding
+            for (old, new) in mesh.triangles.iter().zip(&welded.triangles) {
+                let (old, new) = (*old as usize, *new as usize);
+                assert_eq!(
+                    mesh.vertexes[old * 3..old * 3 + 3],
+                    welded.vertexes[new * 3..new * 3 + 3]
+                );
+                if !mesh.uvs.is_empty() {
+                    assert_eq!(
+                        mesh.uvs[old * 2..old * 2 + 2],
+                        welded.uvs[new * 2..new * 2 + 2]
+                    );
+                }
+                if !mesh.bone_indexes.is_empty() {
+                    assert_eq!(
+                        mesh.bone_indexes[old * 4..old * 4 + 4],
+                        welded.bone_indexes[new * 4..new * 4 + 4]
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn weld_merges_across_grid_lines() {
+    let meshes = meshes("./tests/TestCubeModel.glb", &ExportOptions::default());
+    let mut clean = to_triangle_soup(&meshes[0]);
+    let mut jittered = clean.clone();
+
+    // Copies of a corner end up 0.8 epsilon apart, on each side of a multiple of epsilon
+    let epsilon = 1e-3;
+    for (index, value) in jittered.vertexes.iter_mut().enumerate() {
+        let offset = if (index / 3) % 2 == 0 { 0.9 } else { 0.1 };
+        *value += offset * epsilon;
+    }
+
+    weld::weld_vertices(&mut clean, &[], epsilon);
+    weld::weld_vertices(&mut jittered, &[], epsilon);
+    assert_eq!(jittered.vertex_count(), clean.vertex_count());
+    assert_eq!(jittered.triangles.len(), clean.triangles.len());
+
+    // Further than epsilon stays apart
+    let mut apart = to_triangle_soup(&meshes[0]);
+    for (index, value) in apart.vertexes.iter_mut().enumerate() {
+        if (index / 3) % 2 == 0 {
+            *value += 2.0 * epsilon;
+        }
+    }
+    weld::weld_vertices(&mut apart, &[], epsilon);
+    assert!(apart.vertex_count() > clean.vertex_count());
+}
+
+#[test]
+fn weld_keeps_different_uvs_apart() {
+    let meshes = meshes("./tests/TestCubeModel.glb", &ExportOptions::default());
+    let mesh = &meshes[0];
+
+    let mut soup = to_triangle_soup(mesh);
+    let mut welded = soup.clone();
+    weld::weld_vertices(&mut welded, &[], 1e-5);
+
+    // Moving a single uv should give that corner its own vertex
+    soup.uvs[0] += 0.5;
+    weld::weld_vertices(&mut soup, &[], 1e-5);
+
+    assert_eq!(soup.vertex_count(), welded.vertex_count() + 1);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::with_flatbuffer;
+use gltf_for_renpy::ExportOptions;
+use gltf_for_renpy::gltf_objects::mesh::Mesh;
+use gltf_for_renpy::images::{ImageEncoding, TextureOptions, decode_pixels, downsample, mip_chain};
+
+const PATH: &str = "./tests/BaseColor/BaseColor.gltf";
+
+fn mipmapped_meshes(generate_mipmaps: bool) -> Vec<Mesh> {
+    let options = ExportOptions {
+        use_embed_textures: true,
+        textures: TextureOptions {
+            generate_mipmaps,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    common::meshes(PATH, &options)
+}
+
+#[test]
+fn colors_are_averaged_in_linear_space() {
+    // Black and white texels with an opaque and a transparent alpha
+    let pixels = [0, 0, 0, 255, 255, 255, 255, 0];
+
+    let level = downsample(&pixels, 2, 1, 4, true);
+    assert_eq!((level.width, level.height), (1, 1));
+    assert_eq!(level.data, vec![188, 188, 188, 128]);
+
+    // Data that isn't a color is averaged as is
+    let level = downsample(&pixels, 2, 1, 4, false);
+    assert_eq!(level.data, vec![128, 128, 128, 128]);
+}
+
+#[test]
+fn chain_goes_down_to_one_texel() {
+    let pixels = vec![200; 5 * 3];
+    let levels = mip_chain(&pixels, 5, 3, 1, false);
+
+    let sizes: Vec<_> = levels
+        .iter()
+        .map(|level| (level.width, level.height))
+        .collect();
+    assert_eq!(sizes, vec![(2, 1), (1, 1)]);
+    assert!(
+        levels
+            .iter()
+            .all(|level| level.data.iter().all(|&value| value == 200))
+    );
+
+    assert!(mip_chain(&[1, 2, 3, 4], 1, 1, 4, true).is_empty());
+}
+
+#[test]
+fn mip_levels_are_optional() {
+    assert!(mipmapped_meshes(false)[1].texture.mip_levels.is_empty());
+
+    let meshes = mipmapped_meshes(true);
+    let plain = &meshes[1].texture;
+    assert_eq!(plain.mip_levels.len(), 1);
+    assert_eq!(plain.mip_levels[0].data, vec![255, 205, 188, 192]);
+
+    // Colors have no levels
+    assert!(meshes[2].texture.mip_levels.is_empty());
+}
+
+#[test]
+fn mip_levels_are_sent_to_renpy() {
+    let options = r#"{"use_embed_textures": true, "textures": {"generate_mipmaps": true, "encoding": "Zstd"}}"#;
+    with_flatbuffer(PATH, Some(options), |scenes| {
+        let texture = scenes
+            .scenes()
+            .get(0)
+            .objects()
+            .get(1)
+            .object_as_mesh()
+            .unwrap()
+            .texture()
+            .unwrap();
+
+        // The levels are encoded like the image
+        let levels = texture.mip_levels().unwrap();
+        assert_eq!(levels.len(), 1);
+        let level = levels.get(0);
+        assert_eq!((level.width(), level.height()), (1, 1));
+        assert_eq!(
+            decode_pixels(level.data().unwrap().bytes(), 1, 1, 4, ImageEncoding::Zstd).unwrap(),
+            vec![255, 205, 188, 192]
+        );
+    });
+}
@@ -0,0 +1,84 @@
+use gltf_for_renpy::node_path::{glob_match, segment};
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes, match_node_path};
+use std::collections::HashSet;
+use std::ffi::CString;
+
+#[test]
+fn glob_patterns() {
+    assert!(glob_match("Scene/Hand_L", "Scene/Hand_L"));
+    assert!(glob_match("*/Hand_*", "Scene/Hand_L"));
+    assert!(!glob_match("*/Hand_*", "Scene/Arm/Hand_L"));
+    assert!(glob_match("**/Hand_*", "Scene/Arm/Hand_L"));
+    assert!(glob_match("**/Hand_*", "Scene/Hand_L"));
+    assert!(glob_match("Scene/**", "Scene/Arm/Hand_L/0"));
+    assert!(glob_match("Scene/**/0", "Scene/Arm/Hand_L/0"));
+    assert!(glob_match("Scene/Arm/Hand_?", "Scene/Arm/Hand_R"));
+    assert!(!glob_match("Scene/Arm/Hand_?", "Scene/Arm/Hand_"));
+    assert!(glob_match("S*e*/*a*d*", "Scene/Hand_L"));
+    assert!(!glob_match("*", "Scene/Hand_L"));
+    assert!(!glob_match("Scene/Hand", "Scene/Hand_L"));
+}
+
+#[test]
+fn segments_are_unique_and_stable() {
+    assert_eq!(segment(Some("Hips"), 3, &[]), "Hips");
+    assert_eq!(segment(None, 3, &[]), "#3");
+    assert_eq!(segment(Some(""), 3, &[]), "#3");
+    assert_eq!(segment(Some("a/b"), 3, &[]), "a_b");
+    assert_eq!(segment(Some("Hips"), 7, &["Hips".to_owned()]), "Hips#7");
+}
+
+#[test]
+fn every_node_gets_a_unique_path() {
+    let scenes = load_renpy_scenes(
+        "./tests/Transforms/LossyTransforms.gltf",
+        &ExportOptions::default(),
+    )
+    .unwrap();
+    let tree = &scenes[0].objects;
+
+    let paths: Vec<&str> = tree.nodes.iter().map(|node| node.value.path()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "Scene/Mirrored/0",
+            "Scene/Sheared/0",
+            "Scene/Parent",
+            "Scene/Parent/Child/0"
+        ]
+    );
+
+    // The mesh names are all the same in this file
+    assert_eq!(tree.nodes[0].value.name(), tree.nodes[1].value.name());
+
+    assert_eq!(tree.find_node_by_path("Scene/Parent/Child/0").unwrap(), 3);
+    assert_eq!(tree.query("Scene/*/0"), vec![0, 1]);
+    assert_eq!(tree.query("**/0"), vec![0, 1, 3]);
+    assert_eq!(tree.query("**/Parent/**"), vec![2, 3]);
+
+    for path in [
+        "./tests/TestYukikioModelStylized.glb",
+        "./tests/SkelthalTest/Skelthal.glb",
+    ] {
+        for scene in load_renpy_scenes(path, &ExportOptions::default()).unwrap() {
+            let paths: HashSet<&str> = scene
+                .objects
+                .nodes
+                .iter()
+                .map(|node| node.value.path())
+                .collect();
+            assert_eq!(paths.len(), scene.objects.nodes.len(), "{path}");
+        }
+    }
+}
+
+#[test]
+fn match_from_renpy() {
+    let pattern = CString::new("**/Hand_*").unwrap();
+    let path = CString::new("Scene/Armature/Hand_L").unwrap();
+
+    unsafe {
+        assert!(match_node_path(pattern.as_ptr(), path.as_ptr()));
+        assert!(!match_node_path(pattern.as_ptr(), std::ptr::null()));
+    }
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::{meshes, with_flatbuffer};
+use gltf_for_renpy::ExportOptions;
+use gltf_for_renpy::gltf_objects::material::RenpyMaterial;
+use gltf_for_renpy::images::{ImageData, PbrTextures, TextureOptions};
+use gltf_for_renpy_flatbuffer as flatbuffer;
+
+const PATH: &str = "./tests/PbrTextures/PbrTextures.gltf";
+
+// The occlusion and metallic-roughness textures share the `orm` image (10, 20, 30)
+fn material(pbr_textures: PbrTextures) -> RenpyMaterial {
+    let options = ExportOptions {
+        use_embed_textures: true,
+        textures: TextureOptions {
+            pbr_textures,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    meshes(PATH, &options).remove(0).material
+}
+
+fn data(texture: &Option<gltf_for_renpy::images::RenpyImage>) -> &ImageData {
+    &texture.as_ref().unwrap().data
+}
+
+#[test]
+fn texture_names_are_kept_apart() {
+    let scenes = gltf_loader::load(PATH).unwrap();
+    let model = scenes[0]
+        .objects
+        .values()
+        .find_map(|object| match object {
+            gltf_loader::SceneObject::Mesh(model) => Some(model.material()),
+            _ => None,
+        })
+        .unwrap();
+
+    assert_eq!(
+        model
+            .pbr
+            .base_color_texture_name
+            .as_deref()
+            .map(String::as_str),
+        Some("orange")
+    );
+    assert_eq!(
+        model
+            .pbr
+            .metallic_roughness_texture_name
+            .as_deref()
+            .map(String::as_str),
+        Some("orm")
+    );
+}
+
+#[test]
+fn pbr_textures_are_off_by_default() {
+    let material = material(PbrTextures::None);
+
+    assert!(material.occlusion_texture.is_none());
+    assert!(material.metallic_roughness_texture.is_none());
+    assert!(material.emissive_texture.is_none());
+
+    // The factors are always there
+    assert_eq!(material.occlusion_strength, 0.5);
+    assert_eq!(material.roughness_factor, 0.5);
+}
+
+#[test]
+fn packed_textures() {
+    let material = material(PbrTextures::Packed);
+
+    assert!(
+        matches!(data(&material.occlusion_texture), ImageData::RGBA(pixels) if pixels == &vec![10, 20, 30, 255])
+    );
+    assert!(
+        matches!(data(&material.metallic_roughness_texture), ImageData::RGBA(pixels) if pixels == &vec![10, 20, 30, 255])
+    );
+    assert!(
+        matches!(data(&material.emissive_texture), ImageData::RGBA(pixels) if pixels == &vec![255, 0, 0, 255])
+    );
+    assert_eq!(
+        material.metallic_roughness_texture.as_ref().unwrap().name,
+        "orm"
+    );
+
+    assert!(material.metallic_texture.is_none());
+    assert!(material.roughness_texture.is_none());
+}
+
+#[test]
+fn channel_textures() {
+    let material = material(PbrTextures::Channels);
+
+    assert!(
+        matches!(data(&material.occlusion_texture), ImageData::Gray(pixels) if pixels == &vec![10])
+    );
+    assert!(
+        matches!(data(&material.roughness_texture), ImageData::Gray(pixels) if pixels == &vec![20])
+    );
+    assert!(
+        matches!(data(&material.metallic_texture), ImageData::Gray(pixels) if pixels == &vec![30])
+    );
+    assert!(material.emissive_texture.is_some());
+
+    assert!(material.metallic_roughness_texture.is_none());
+}
+
+#[test]
+fn channel_textures_have_their_own_names() {
+    let material = material(PbrTextures::Channels);
+
+    assert_eq!(material.metallic_texture.unwrap().name, "orm_metallic");
+    assert_eq!(material.roughness_texture.unwrap().name, "orm_roughness");
+}
+
+#[test]
+fn pbr_textures_are_sent_to_renpy() {
+    let options = r#"{"use_embed_textures": true, "textures": {"pbr_textures": "Channels"}}"#;
+    with_flatbuffer(PATH, Some(options), |scenes| {
+        let material = scenes
+            .scenes()
+            .get(0)
+            .objects()
+            .get(0)
+            .object_as_mesh()
+            .unwrap()
+            .material()
+            .unwrap();
+
+        let metallic = material.metallic_texture().unwrap();
+        assert_eq!(metallic.data_type(), flatbuffer::ImageData::Gray);
+        assert_eq!(
+            metallic.data_as_gray().unwrap().data().unwrap().bytes(),
+            &[30]
+        );
+        assert_eq!(material.occlusion_strength(), 0.5);
+        assert!(material.metallic_roughness_texture().is_none());
+        assert_eq!(material.emissive_texture().unwrap().name(), Some("glow"));
+    });
+}
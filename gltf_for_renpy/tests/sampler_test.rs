@@ -0,0 +1,55 @@
+mod common;
+
+use common::{meshes, with_flatbuffer};
+use gltf_for_renpy::ExportOptions;
+use gltf_for_renpy_flatbuffer as flatbuffer;
+use gltf_loader::model::{Filter, Sampler, WrapMode};
+
+const PATH: &str = "./tests/Samplers/Samplers.gltf";
+
+#[test]
+fn samplers_are_loaded() {
+    let meshes = meshes(PATH, &ExportOptions::default());
+
+    assert_eq!(
+        meshes[0].texture.sampler,
+        Some(Sampler {
+            wrap_s: WrapMode::ClampToEdge,
+            wrap_t: WrapMode::MirroredRepeat,
+            mag_filter: Some(Filter::Nearest),
+            min_filter: Some(Filter::Nearest),
+            mipmap_filter: Some(Filter::Linear),
+        })
+    );
+
+    // Textures without a sampler repeat and leave the filters to renpy
+    assert_eq!(meshes[1].texture.sampler, Some(Sampler::default()));
+}
+
+#[test]
+fn samplers_are_sent_to_renpy() {
+    with_flatbuffer(PATH, None, |scenes| {
+        let scene = scenes.scenes().get(0);
+        let sampler = |index: usize| {
+            *scene
+                .objects()
+                .get(index)
+                .object_as_mesh()
+                .unwrap()
+                .texture()
+                .unwrap()
+                .sampler()
+                .unwrap()
+        };
+
+        let pixel_art = sampler(0);
+        assert_eq!(pixel_art.wrap_s(), flatbuffer::WrapMode::ClampToEdge);
+        assert_eq!(pixel_art.wrap_t(), flatbuffer::WrapMode::MirroredRepeat);
+        assert_eq!(pixel_art.mag_filter(), flatbuffer::TextureFilter::Nearest);
+        assert_eq!(pixel_art.mipmap_filter(), flatbuffer::TextureFilter::Linear);
+
+        let default = sampler(1);
+        assert_eq!(default.wrap_s(), flatbuffer::WrapMode::Repeat);
+        assert_eq!(default.min_filter(), flatbuffer::TextureFilter::Unset);
+    });
+}
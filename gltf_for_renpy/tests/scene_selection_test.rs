@@ -0,0 +1,102 @@
+mod common;
+
+use common::check_scene_list;
+use gltf_for_renpy::{ExportOptions, load_file_scene, load_renpy_scenes};
+use gltf_loader::SceneSelection;
+use std::ffi::CString;
+use std::ptr::null;
+
+const PATH: &str = "./tests/Scenes/MultiScene.gltf";
+
+fn load(scenes: SceneSelection) -> anyhow::Result<Vec<(String, usize, bool)>> {
+    let options = ExportOptions {
+        scenes,
+        ..Default::default()
+    };
+    Ok(load_renpy_scenes(PATH, &options)?
+        .into_iter()
+        .map(|scene| (scene.name, scene.index, scene.is_default))
+        .collect())
+}
+
+#[test]
+fn scenes_are_selected() {
+    assert_eq!(
+        load(SceneSelection::All).unwrap(),
+        vec![
+            ("Intro".to_owned(), 0, false),
+            ("Main".to_owned(), 1, true),
+            ("Credits".to_owned(), 2, false)
+        ]
+    );
+    assert_eq!(
+        load(SceneSelection::Default).unwrap(),
+        vec![("Main".to_owned(), 1, true)]
+    );
+    assert_eq!(
+        load(SceneSelection::Names(vec![
+            "Credits".to_owned(),
+            "Intro".to_owned()
+        ]))
+        .unwrap(),
+        vec![
+            ("Intro".to_owned(), 0, false),
+            ("Credits".to_owned(), 2, false)
+        ]
+    );
+    assert_eq!(
+        load(SceneSelection::Indices(vec![2])).unwrap(),
+        vec![("Credits".to_owned(), 2, false)]
+    );
+
+    assert!(load(SceneSelection::Names(vec!["Missing".to_owned()])).is_err());
+    assert!(load(SceneSelection::Indices(vec![3])).is_err());
+}
+
+#[test]
+fn selected_scene_keeps_its_animations() {
+    let scenes = gltf_loader::load_scenes(
+        PATH,
+        &Default::default(),
+        &SceneSelection::Names(vec!["Credits".to_owned()]),
+    )
+    .unwrap();
+    let animated = scenes[0]
+        .objects
+        .root()
+        .descendants()
+        .any(|node| match node.value() {
+            gltf_loader::SceneObject::Empties(empty) => !empty.animations().is_empty(),
+            _ => false,
+        });
+    assert!(animated);
+
+    let options = ExportOptions::from_json(r#"{"scenes": {"Names": ["Main"]}}"#).unwrap();
+    assert_eq!(
+        options.scenes,
+        SceneSelection::Names(vec!["Main".to_owned()])
+    );
+    let options = ExportOptions::from_json(r#"{"scenes": "Default"}"#).unwrap();
+    assert_eq!(options.scenes, SceneSelection::Default);
+}
+
+#[test]
+fn default_scene_is_marked() {
+    let file_path = CString::new(PATH).unwrap();
+    let name = CString::new("Credits").unwrap();
+
+    unsafe {
+        for (name, index, expected, default_scene) in [
+            (null(), -1, "Main", 0),
+            (null(), 0, "Intro", -1),
+            (name.as_ptr(), 0, "Credits", -1),
+        ] {
+            let model_list = load_file_scene(file_path.as_ptr(), name, index, false);
+            check_scene_list(model_list, |scenes| {
+                assert_eq!(scenes.scenes().len(), 1);
+                assert_eq!(scenes.scenes().get(0).name(), expected);
+                assert_eq!(scenes.default_scene(), default_scene);
+            });
+        }
+    }
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::{scene_meshes, with_flatbuffer};
+use gltf_for_renpy::images::ImageData;
+use gltf_for_renpy::{ExportOptions, load_renpy_scenes};
+use gltf_loader::SceneSelection;
+
+const PATH: &str = "./tests/Variants/Variants.gltf";
+
+#[test]
+fn variants_are_loaded() {
+    let scenes = load_renpy_scenes(PATH, &ExportOptions::default()).unwrap();
+    assert_eq!(scenes[0].variants, vec!["red_uniform", "blue_uniform"]);
+
+    let meshes = scene_meshes(&scenes[0]);
+    assert_eq!(meshes[0].texture.name, "red");
+
+    let variants: Vec<(u32, &str, &str)> = meshes[0]
+        .variants
+        .iter()
+        .map(|variant| {
+            (
+                variant.variant,
+                variant.texture.name.as_str(),
+                variant.material.name.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(variants, vec![(1, "blue", "Blue"), (0, "red", "Red")]);
+
+    // No mapping means the default material for every variant
+    assert!(meshes[1].variants.is_empty());
+}
+
+#[test]
+fn variant_textures_are_kept_when_filtering_scenes() {
+    let options = ExportOptions {
+        scenes: SceneSelection::Default,
+        use_embed_textures: true,
+        ..Default::default()
+    };
+    let scenes = load_renpy_scenes(PATH, &options).unwrap();
+    let blue = &scene_meshes(&scenes[0])[0].variants[0].texture;
+
+    let ImageData::RGBA(pixels) = &blue.data else {
+        panic!("The texture should be embedded");
+    };
+    assert_eq!(pixels, &vec![0, 0, 255, 255]);
+}
+
+#[test]
+fn variants_are_sent_to_renpy() {
+    with_flatbuffer(PATH, None, |scenes| {
+        let scene = scenes.scenes().get(0);
+
+        let names: Vec<&str> = scene.variants().unwrap().iter().collect();
+        assert_eq!(names, vec!["red_uniform", "blue_uniform"]);
+
+        let mesh = scene.objects().get(0).object_as_mesh().unwrap();
+        let variant = mesh.variants().unwrap().get(0);
+        assert_eq!(variant.variant(), 1);
+        assert_eq!(variant.texture().unwrap().name(), Some("blue"));
+        assert_eq!(variant.material().unwrap().name(), Some("Blue"));
+
+        let hat = scene.objects().get(1).object_as_mesh().unwrap();
+        assert!(hat.variants().is_none());
+    });
+}
@@ -383,13 +383,9 @@ impl GLTFAnimation {
                     .iter()
                     .filter(|val| !val.is_finished)
                     .min_by_key(|val| {
-                        OrderedFloat(if let Some(x) = val.peek_time() {
-                            x
-                        } else {
-                            // This theoretically should not be possible since we filtered out finished ones
-                            // but just in case we just return the largest time value so that it's impossible to be the minimum.
-                            f32::MAX
-                        })
+                        // Not having a time theoretically should not be possible since we filtered out finished ones
+                        // but just in case we just return the largest time value so that it's impossible to be the minimum.
+                        OrderedFloat(val.peek_time().unwrap_or(f32::MAX))
                     })
                     .map(|iter| (iter, iter.peek_time().unwrap()))
             }
@@ -51,24 +51,79 @@ pub fn load<P>(path: P) -> anyhow::Result<Vec<Scene>>
 where
     P: AsRef<Path>,
 {
-    // Run gltf
-    let (doc, buffers, images) = gltf::import(&path)?;
+    load_with_convention(path, &utils::CoordinateConvention::default())
+}
+
+/// Same as `load` but the skeleton bind matrices are converted to the given coordinates instead of renpy's
+pub fn load_with_convention<P>(
+    path: P,
+    convention: &utils::CoordinateConvention,
+) -> anyhow::Result<Vec<Scene>>
+where
+    P: AsRef<Path>,
+{
+    load_scenes(path, convention, &SceneSelection::All)
+}
+
+/// Same as `load_with_convention` but only the selected scenes are loaded.
+/// Textures and animations that none of those scenes use are skipped too.
+///
+/// Fails when the selection doesn't match any scene
+pub fn load_scenes<P>(
+    path: P,
+    convention: &utils::CoordinateConvention,
+    selection: &SceneSelection,
+) -> anyhow::Result<Vec<Scene>>
+where
+    P: AsRef<Path>,
+{
+    // Run gltf, the images are decoded below since `gltf::import` only knows about PNG and JPEG
+    let gltf::Gltf {
+        document: doc,
+        blob,
+    } = gltf::Gltf::open(&path)?;
+    let buffers = gltf::import_buffers(&doc, path.as_ref().parent(), blob)?;
 
     // Init data and collection useful for conversion
-    let mut data = GltfData::new(buffers, images, &path);
+    let mut data = GltfData::new(buffers, &path);
+    data.convention = *convention;
+
+    // The spec doesn't say what to show without a default scene so go with the first one
+    let default_scene = doc.default_scene().map(|scene| scene.index());
+    let selected: Vec<gltf::Scene> = doc
+        .scenes()
+        .filter(|scene| selection.includes(scene, default_scene.unwrap_or(0)))
+        .collect();
+    if selected.is_empty() && doc.scenes().len() > 0 {
+        anyhow::bail!("No scene in the file matches {selection:?}");
+    }
+
+    // Nothing to filter when everything is loaded
+    let used_nodes = (*selection != SceneSelection::All).then(|| scene_nodes(&selected));
+    let used_textures = used_nodes.as_ref().map(|nodes| used_textures(&doc, nodes));
 
     // Todo: Look into 'Chili' instead for lower overhead
     //  This can work by creating joining a thread for every 2 textures.
-    let raw_images: Vec<_> = doc
+    let textures: Vec<gltf::Texture> = doc
         .textures()
-        .par_bridge()
-        .map(|tex| (tex.index(), data.load_texture(&tex)))
+        .filter(|tex| {
+            used_textures
+                .as_ref()
+                .is_none_or(|textures| textures.contains(&tex.index()))
+        })
         .collect();
-    data.raw_images.extend(raw_images);
+    data.load_textures(&doc, &textures);
 
     // Convert the animation separately because it's in the document and not in the scene
     let animation_result: Vec<Vec<(usize, GLTFAnimation)>> = doc
         .animations()
+        .filter(|animation| {
+            used_nodes.as_ref().is_none_or(|nodes| {
+                animation
+                    .channels()
+                    .any(|channel| nodes.contains(&channel.target().node().index()))
+            })
+        })
         .par_bridge()
         .map(|animation| GLTFAnimation::load(animation, &data))
         .collect();
@@ -83,16 +138,32 @@ where
         res
     };
 
+    let used_skins = used_nodes.as_ref().map(|nodes| used_skins(&doc, nodes));
     data.skeletons = doc
         .skins()
+        .filter(|skin| {
+            used_skins
+                .as_ref()
+                .is_none_or(|skins| skins.contains(&skin.index()))
+        })
         .par_bridge()
         .map(|skin| Skeleton::load(&skin, &data))
         .collect();
 
+    data.instances = scene::model::load_instances(&doc, &data);
+
+    let variants: Vec<String> = doc
+        .variants()
+        .map(|variants| variants.map(|variant| variant.name().to_owned()).collect())
+        .unwrap_or_default();
+
     // Convert gltf -> easy_gltf
     let mut scenes_res: Vec<Scene> = vec![];
-    for scene in doc.scenes() {
-        scenes_res.push(Scene::load(scene, &mut data));
+    for scene in selected {
+        let mut loaded = Scene::load(scene, &mut data);
+        loaded.is_default = default_scene == Some(loaded.index);
+        loaded.variants = variants.clone();
+        scenes_res.push(loaded);
     }
 
     Ok(scenes_res)
@@ -22,6 +22,8 @@ pub struct Empty {
 
     // The default transform
     pub(crate) static_transform: DecomposedTransform,
+    // The default transform as given in the file (column major)
+    pub(crate) static_matrix: [[f32; 4]; 4],
 
     pub(crate) animations: Vec<GLTFAnimation>,
 }
@@ -34,6 +36,7 @@ impl Empty {
 
         let mut empty = Self {
             static_transform: transform,
+            static_matrix: node.transform().matrix(),
             parent_nodes: parents,
             animations,
             ..Default::default()
@@ -58,11 +61,22 @@ impl Empty {
         &self.static_transform
     }
 
+    /// Returns the transform of the empty as a matrix
+    /// Unlike `transform` it keeps any shear the file had
+    pub fn matrix(&self) -> Matrix4<f32> {
+        Matrix4::from(self.static_matrix)
+    }
+
     /// Returns the parent of the object
     pub fn parents(self) -> Vec<usize> {
         self.parent_nodes
     }
 
+    /// Same as `parents` without taking the empty
+    pub fn parent_ids(&self) -> &[usize] {
+        &self.parent_nodes
+    }
+
     /// Returns position of the origin of the empty    
     pub fn rotation(&self) -> cgmath::Euler<Deg<f32>> {
         match self.static_transform.rotation {
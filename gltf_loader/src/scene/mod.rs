@@ -1,6 +1,7 @@
 mod camera;
 mod empty;
 mod light;
+mod selection;
 
 /// Contains model and material
 /// # Usage
@@ -17,6 +18,8 @@ use ego_tree::Tree;
 pub use empty::Empty;
 pub use light::Light;
 pub use model::{Material, Model};
+pub use selection::SceneSelection;
+pub(crate) use selection::{scene_nodes, used_skins, used_textures};
 
 use gltf::scene::Node;
 
@@ -25,8 +28,14 @@ use gltf::scene::Node;
 pub struct Scene {
     /// Scene name. Requires the `names` feature.
     pub name: Option<String>,
+    /// Index of the scene in the file
+    pub index: usize,
+    /// This is the scene the file wants shown first
+    pub is_default: bool,
     /// Scene extra data. Requires the `extras` feature.
     pub extras: Option<HashMap<String, String>>,
+    /// Names of the material variants of the file (`KHR_materials_variants`), `Model::material_variants` indexes into it
+    pub variants: Vec<String>,
     /// List of models in the scene
     // pub models: Vec<Model>,
     // /// List of cameras in the scene
@@ -42,8 +51,11 @@ impl Default for Scene {
     fn default() -> Self {
         Scene {
             name: Default::default(),
+            index: 0,
+            is_default: false,
             objects: Tree::new(SceneObject::Root),
             extras: Default::default(),
+            variants: Vec::new(),
         }
     }
 }
@@ -78,6 +90,7 @@ impl Scene {
     pub(crate) fn load(gltf_scene: gltf::Scene, data: &mut GltfData) -> Self {
         let mut scene = Self {
             name: gltf_scene.name().map(String::from),
+            index: gltf_scene.index(),
             ..Default::default()
         };
 
@@ -0,0 +1,157 @@
+use crate::utils::GltfData;
+use cgmath::*;
+use std::{collections::HashMap, sync::Arc};
+
+use super::{MorphTarget, Vertex};
+
+/// Vertex data of a primitive. It only depends on the mesh so every node using the same mesh shares it.
+#[derive(Clone, Debug, Default)]
+pub struct Geometry {
+    pub(crate) vertices: Vec<Vertex>,
+    pub(crate) indices: Option<Vec<u32>>,
+
+    pub(crate) morph_targets: Vec<MorphTarget>,
+
+    pub(crate) bone_indexes: Vec<u16>,
+    pub(crate) bone_weights: Vec<f32>,
+
+    pub(crate) has_normals: bool,
+    pub(crate) has_tangents: bool,
+    pub(crate) has_tex_coords: bool,
+}
+
+impl Geometry {
+    // Reads the primitive the first time and hands out the cached one after that
+    pub(crate) fn load(
+        mesh: &gltf::Mesh,
+        primitive_index: usize,
+        primitive: &gltf::Primitive,
+        mesh_extras: &Option<HashMap<String, String>>,
+        data: &mut GltfData,
+    ) -> Arc<Self> {
+        let key = (mesh.index(), primitive_index);
+        if let Some(geometry) = data.geometries.get(&key) {
+            return geometry.clone();
+        }
+
+        let geometry = Arc::new(Self::read(primitive, mesh_extras, data));
+        data.geometries.insert(key, geometry.clone());
+        geometry
+    }
+
+    fn read(
+        primitive: &gltf::Primitive,
+        mesh_extras: &Option<HashMap<String, String>>,
+        data: &GltfData,
+    ) -> Self {
+        let buffers = &data.buffers;
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+
+        let indices = reader
+            .read_indices()
+            .map(|indices| indices.into_u32().collect());
+
+        // Init vertices with the position
+        let mut vertices: Vec<_> = reader
+            .read_positions()
+            .unwrap_or_else(|| panic!("The model primitive doesn't contain positions"))
+            .map(|pos| Vertex {
+                position: Vector3::new(pos[0], pos[1], pos[2]),
+                ..Default::default()
+            })
+            .collect();
+
+        // Fill normals
+        let has_normals = if let Some(normals) = reader.read_normals() {
+            for (i, normal) in normals.enumerate() {
+                vertices[i].normal = normal.into();
+            }
+            true
+        } else {
+            false
+        };
+
+        // Fill tangents
+        let has_tangents = if let Some(tangents) = reader.read_tangents() {
+            for (i, tangent) in tangents.enumerate() {
+                vertices[i].tangent = tangent.into();
+            }
+            true
+        } else {
+            false
+        };
+
+        // Texture coordinates
+        let has_tex_coords = if let Some(tex_coords) = reader.read_tex_coords(0) {
+            for (i, tex_coords) in tex_coords.into_f32().enumerate() {
+                vertices[i].tex_coords = Vector2::from(tex_coords);
+            }
+            true
+        } else {
+            false
+        };
+
+        let mut morph_targets = Vec::new();
+        let mut target_names: Vec<String> = Vec::new();
+
+        // Ugly ass code to get the name of morph targets if it exists
+        if let Some(x) = mesh_extras
+            && let Some(name_array) = x.get("targetNames")
+            && let Ok(gltf::json::Value::Array(target_name)) =
+                gltf::json::deserialize::from_str::<gltf::json::Value>(name_array)
+        {
+            target_names.extend(target_name.iter().map(|x| {
+                if let Some(name) = x.as_str() {
+                    name.to_string()
+                } else {
+                    String::new()
+                }
+            }));
+        }
+
+        for (index, (position, _normal, _tangent)) in reader.read_morph_targets().enumerate() {
+            let mut blend_shapes = Vec::new();
+
+            if let Some(position) = position {
+                blend_shapes.extend(position.map(|pos| Vertex {
+                    position: Vector3::from(pos),
+                    ..Default::default()
+                }));
+            }
+
+            let name = if let Some(name) = target_names.get(index) {
+                name.clone()
+            } else {
+                format!("Key {index}").to_string()
+            };
+
+            morph_targets.push(MorphTarget { name, blend_shapes });
+        }
+
+        let mut bone_indexes = Vec::with_capacity(vertices.len() * 4);
+        let mut bone_weights = Vec::with_capacity(vertices.len() * 4);
+
+        if let Some(joint_sets) = reader.read_joints(0) {
+            for joint_index in joint_sets.into_u16() {
+                bone_indexes.extend_from_slice(&joint_index);
+            }
+        }
+
+        if let Some(joint_sets) = reader.read_weights(0) {
+            for weights in joint_sets.into_f32() {
+                bone_weights.extend_from_slice(&weights);
+            }
+        }
+
+        Geometry {
+            vertices,
+            indices,
+            morph_targets,
+            bone_indexes,
+            bone_weights,
+            has_normals,
+            has_tangents,
+            has_tex_coords,
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::utils::{DecomposedTransform, GltfData, RotationTransform};
+use cgmath::*;
+use gltf::accessor::{DataType, Iter};
+use gltf::animation::util::Rotations;
+use rustc_hash::FxHashMap;
+
+const EXTENSION: &str = "EXT_mesh_gpu_instancing";
+
+/// Reads the per instance transforms of every node using `EXT_mesh_gpu_instancing`
+/// The transforms are relative to the node (the node transform still applies on top)
+pub(crate) fn load_instances(
+    doc: &gltf::Document,
+    data: &GltfData,
+) -> FxHashMap<usize, Vec<DecomposedTransform>> {
+    let mut instances = FxHashMap::default();
+
+    for node in doc.nodes() {
+        let Some(attributes) = node
+            .extension_value(EXTENSION)
+            .and_then(|extension| extension.get("attributes"))
+        else {
+            continue;
+        };
+
+        let accessor = |name: &str| {
+            attributes
+                .get(name)
+                .and_then(|index| index.as_u64())
+                .and_then(|index| doc.accessors().nth(index as usize))
+        };
+
+        let translations = accessor("TRANSLATION").and_then(|accessor| read_vec3(accessor, data));
+        let rotations = accessor("ROTATION").and_then(|accessor| read_rotations(accessor, data));
+        let scales = accessor("SCALE").and_then(|accessor| read_vec3(accessor, data));
+
+        // Every attribute has to have the same count, the spec doesn't allow anything else
+        let count = [
+            translations.as_ref().map(Vec::len),
+            rotations.as_ref().map(Vec::len),
+            scales.as_ref().map(Vec::len),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(0);
+
+        let node_instances = (0..count)
+            .map(|i| DecomposedTransform {
+                translation: translations
+                    .as_ref()
+                    .map_or(Vector3::zero(), |values| Vector3::from(values[i])),
+                rotation: RotationTransform::Quaternion(rotations.as_ref().map_or(
+                    Quaternion::one(),
+                    |values| {
+                        let [x, y, z, w] = values[i];
+                        Quaternion::new(w, x, y, z)
+                    },
+                )),
+                scale: scales
+                    .as_ref()
+                    .map_or(Vector3::new(1.0, 1.0, 1.0), |values| {
+                        Vector3::from(values[i])
+                    }),
+            })
+            .collect();
+
+        instances.insert(node.index(), node_instances);
+    }
+
+    instances
+}
+
+fn read_vec3(accessor: gltf::Accessor, data: &GltfData) -> Option<Vec<[f32; 3]>> {
+    // Quantized translations and scales (KHR_mesh_quantization) aren't supported
+    if accessor.data_type() != DataType::F32 {
+        eprintln!("Warning: {EXTENSION} attribute isn't made of floats and was skipped");
+        return None;
+    }
+
+    let iter: Iter<[f32; 3]> = Iter::new(accessor, |buffer| Some(&data.buffers[buffer.index()]))?;
+    Some(iter.collect())
+}
+
+fn read_rotations(accessor: gltf::Accessor, data: &GltfData) -> Option<Vec<[f32; 4]>> {
+    let get_buffer = |buffer: gltf::Buffer| Some(&data.buffers[buffer.index()].0[..]);
+
+    // Rotations can also be normalized integers
+    let rotations = match accessor.data_type() {
+        DataType::I8 => Rotations::I8(Iter::new(accessor, get_buffer)?),
+        DataType::U8 => Rotations::U8(Iter::new(accessor, get_buffer)?),
+        DataType::I16 => Rotations::I16(Iter::new(accessor, get_buffer)?),
+        DataType::U16 => Rotations::U16(Iter::new(accessor, get_buffer)?),
+        DataType::F32 => Rotations::F32(Iter::new(accessor, get_buffer)?),
+        DataType::U32 => return None,
+    };
+
+    Some(rotations.into_f32().collect())
+}
@@ -1,4 +1,5 @@
-use crate::utils::GltfData;
+use super::Sampler;
+use crate::utils::{GltfData, texture_name};
 use cgmath::*;
 use image::RgbImage;
 use std::sync::Arc;
@@ -10,18 +11,33 @@ pub struct Emissive {
     /// model surface: It defines the color of the light that is emitted from the surface
     pub texture: Option<Arc<RgbImage>>,
 
+    /// The name used for the emissive texture
+    pub texture_name: Option<Arc<String>>,
+
+    /// How the emissive texture is sampled
+    pub sampler: Sampler,
+
     /// The `emissive_factor` contains scaling factors for the red, green and
     /// blue components of this texture.
     pub factor: Vector3<f32>,
+
+    /// Multiplies the emitted light, from `KHR_materials_emissive_strength` (1 when not given)
+    pub strength: f32,
 }
 
 impl Emissive {
     pub(crate) fn load(gltf_mat: &gltf::Material, data: &mut GltfData) -> Self {
+        let texture = gltf_mat.emissive_texture().map(|texture| texture.texture());
         Self {
-            texture: gltf_mat
-                .emissive_texture()
-                .map(|texture| data.load_rgb_image(&texture.texture())),
+            texture: texture
+                .as_ref()
+                .and_then(|texture| data.load_rgb_image(texture)),
+            texture_name: texture
+                .as_ref()
+                .map(|texture| Arc::new(texture_name(texture))),
+            sampler: texture.as_ref().map(Sampler::load).unwrap_or_default(),
             factor: gltf_mat.emissive_factor().into(),
+            strength: gltf_mat.emissive_strength().unwrap_or(1.0),
         }
     }
 }
@@ -30,7 +46,10 @@ impl Default for Emissive {
     fn default() -> Self {
         Self {
             texture: None,
+            texture_name: None,
+            sampler: Sampler::default(),
             factor: Vector3::zero(),
+            strength: 1.0,
         }
     }
 }
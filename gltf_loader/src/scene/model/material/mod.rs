@@ -2,17 +2,23 @@ mod emissive;
 mod normal;
 mod occlusion;
 mod pbr;
+mod sampler;
+mod toon;
 
 use crate::utils::*;
 use cgmath::*;
 use core::ops::Deref;
+use gltf::json::Value;
 use image::{ImageBuffer, Pixel};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 pub use emissive::Emissive;
 pub use normal::NormalMap;
 pub use occlusion::Occlusion;
 pub use pbr::PbrMaterial;
+pub use sampler::{Filter, Sampler, WrapMode};
+pub use toon::{MTOON_EXTENSION, OutlineWidthMode, Toon};
 
 /// Contains material properties of models.
 #[derive(Clone, Debug, Default)]
@@ -35,6 +41,16 @@ pub struct Material {
 
     /// The emissive color of the material.
     pub emissive: Emissive,
+
+    /// The material ignores lighting (`KHR_materials_unlit`)
+    pub unlit: bool,
+
+    /// Toon shading from `VRMC_materials_mtoon`
+    pub toon: Option<Toon>,
+
+    /// Raw json of every extension of the material that isn't read by the loader (vendor extensions for example),
+    /// by extension name. This is where support for more of them can be added without changing the loader.
+    pub extensions: BTreeMap<String, String>,
 }
 
 impl Material {
@@ -163,6 +179,29 @@ impl Material {
         )]
     }
 
+    // Every texture the material points to, the texture infos of its extensions included (`VRMC_materials_mtoon`...)
+    pub(crate) fn texture_indices(gltf_mat: &gltf::Material) -> Vec<usize> {
+        let pbr = gltf_mat.pbr_metallic_roughness();
+        let mut indices: Vec<usize> = [
+            pbr.base_color_texture().map(|info| info.texture()),
+            pbr.metallic_roughness_texture().map(|info| info.texture()),
+            gltf_mat.normal_texture().map(|info| info.texture()),
+            gltf_mat.occlusion_texture().map(|info| info.texture()),
+            gltf_mat.emissive_texture().map(|info| info.texture()),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|texture| texture.index())
+        .collect();
+
+        if let Some(extensions) = gltf_mat.extensions() {
+            extensions
+                .values()
+                .for_each(|value| extension_texture_indices(value, &mut indices));
+        }
+        indices
+    }
+
     pub(crate) fn load(gltf_mat: gltf::Material, data: &mut GltfData) -> Arc<Self> {
         if let Some(material) = data.materials.get(&gltf_mat.index()) {
             return material.clone();
@@ -176,6 +215,17 @@ impl Material {
             normal: NormalMap::load(&gltf_mat, data),
             occlusion: Occlusion::load(&gltf_mat, data),
             emissive: Emissive::load(&gltf_mat, data),
+            unlit: gltf_mat.unlit(),
+            toon: Toon::load(&gltf_mat),
+            extensions: gltf_mat
+                .extensions()
+                .map(|extensions| {
+                    extensions
+                        .iter()
+                        .map(|(name, value)| (name.clone(), value.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default(),
         });
 
         // Add to the collection
@@ -183,3 +233,25 @@ impl Material {
         material
     }
 }
+
+// Texture infos of extensions are the objects under a `...Texture` key, like `shadeMultiplyTexture` or `clearcoatTexture`
+fn extension_texture_indices(value: &Value, indices: &mut Vec<usize>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let index = value
+                    .get("index")
+                    .and_then(Value::as_u64)
+                    .and_then(|index| usize::try_from(index).ok());
+                match index {
+                    Some(index) if key.ends_with("Texture") => indices.push(index),
+                    _ => extension_texture_indices(value, indices),
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter()
+            .for_each(|value| extension_texture_indices(value, indices)),
+        _ => {}
+    }
+}
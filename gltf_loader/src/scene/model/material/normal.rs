@@ -24,8 +24,9 @@ pub struct NormalMap {
 
 impl NormalMap {
     pub(crate) fn load(gltf_mat: &gltf::Material, data: &mut GltfData) -> Option<Self> {
-        gltf_mat.normal_texture().map(|texture| Self {
-            texture: data.load_rgb_image(&texture.texture()),
+        let texture = gltf_mat.normal_texture()?;
+        Some(Self {
+            texture: data.load_rgb_image(&texture.texture())?,
             factor: texture.scale(),
         })
     }
@@ -1,5 +1,6 @@
-use crate::utils::GltfData;
-use image::GrayImage;
+use super::Sampler;
+use crate::utils::{GltfData, texture_name};
+use image::{GrayImage, RgbImage};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
@@ -9,6 +10,16 @@ pub struct Occlusion {
     /// surface that are occluded from light, and thus rendered darker.
     pub texture: Arc<GrayImage>,
 
+    /// The texture as it is in the file, the occlusion is in red
+    /// (the other channels often hold the metallic-roughness texture)
+    pub packed_texture: Arc<RgbImage>,
+
+    /// The name used for the occlusion texture
+    pub texture_name: Arc<String>,
+
+    /// How the occlusion texture is sampled
+    pub sampler: Sampler,
+
     /// The `occlusion_factor` is the occlusion strength to be applied to the
     /// texture value.
     pub factor: f32,
@@ -16,8 +27,12 @@ pub struct Occlusion {
 
 impl Occlusion {
     pub(crate) fn load(gltf_mat: &gltf::Material, data: &mut GltfData) -> Option<Self> {
-        gltf_mat.occlusion_texture().map(|texture| Self {
-            texture: data.load_gray_image(&texture.texture(), 0),
+        let texture = gltf_mat.occlusion_texture()?;
+        Some(Self {
+            texture: data.load_gray_image(&texture.texture(), 0)?,
+            packed_texture: data.load_rgb_image(&texture.texture())?,
+            texture_name: Arc::new(texture_name(&texture.texture())),
+            sampler: Sampler::load(&texture.texture()),
             factor: texture.strength(),
         })
     }
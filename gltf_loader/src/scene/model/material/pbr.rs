@@ -1,6 +1,7 @@
-use crate::utils::GltfData;
+use super::Sampler;
+use crate::utils::{GltfData, texture_name};
 use cgmath::*;
-use image::{GrayImage, RgbaImage};
+use image::{GrayImage, RgbImage, RgbaImage};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
@@ -8,25 +9,36 @@ use std::sync::Arc;
 /// material model from Physically-Based Rendering (PBR) methodology.
 pub struct PbrMaterial {
     /// The `base_color_factor` contains scaling factors for the red, green,
-    /// blue and alpha component of the color. If no texture is used, these
+    /// blue and alpha component of the color (in linear space). If no texture is used, these
     /// values will define the color of the whole object in **RGB** color space.
     pub base_color_factor: Vector4<f32>,
 
     /// The `base_color_texture` is the main texture that will be applied to the
     /// object.
     ///
-    /// The texture contains RGB(A) components in **sRGB** color space.
+    /// The texture contains RGB(A) components in **sRGB** color space, as stored in the file
+    /// (`base_color_factor` isn't applied to it).
     pub base_color_texture: Option<Arc<RgbaImage>>,
 
     /// The name used for the base color texture
     pub base_color_texture_name: Option<Arc<String>>,
 
+    /// How the base color texture is sampled
+    pub base_color_sampler: Sampler,
+
     /// Contains the metalness value
     pub metallic_texture: Option<Arc<GrayImage>>,
 
+    /// The metallic-roughness texture as it is in the file: roughness in green
+    /// and metallic in blue (red is often the occlusion)
+    pub metallic_roughness_texture: Option<Arc<RgbImage>>,
+
     /// The name used for the metallic_roughness texture
     pub metallic_roughness_texture_name: Option<Arc<String>>,
 
+    /// How the metallic-roughness texture is sampled
+    pub metallic_roughness_sampler: Sampler,
+
     /// `metallic_factor` is multiply to the `metallic_texture` value. If no
     /// texture is given, then the factor define the metalness for the whole
     /// object.
@@ -47,36 +59,12 @@ impl PbrMaterial {
             base_color_factor: pbr.base_color_factor().into(),
             ..Default::default()
         };
-        if let Some(texture) = pbr.base_color_texture() {
-            let mut texture_data = data.load_base_color_image(&texture.texture());
-            if let Some(pixels) = std::sync::Arc::get_mut(&mut texture_data) {
-                let base_color_factor = pbr.base_color_factor();
-                if base_color_factor.ne(&[1.0, 1.0, 1.0, 1.0]) {
-                    let factor_slice = base_color_factor.as_slice();
-                    for pixel_data in pixels.pixels_mut() {
-                        let final_color: Vec<u8> = pixel_data
-                            .0
-                            .iter()
-                            .zip(factor_slice.iter())
-                            .map(|(a, b)| ((*a as f32) * b) as u8)
-                            .collect();
-                        pixel_data[0] = final_color[0];
-                        pixel_data[1] = final_color[1];
-                        pixel_data[2] = final_color[2];
-                        pixel_data[3] = final_color[3];
-                    }
-                }
-            }
-
+        if let Some(texture) = pbr.base_color_texture()
+            && let Some(texture_data) = data.load_base_color_image(&texture.texture())
+        {
             material.base_color_texture = Some(texture_data);
-            material.base_color_texture_name = Some(Arc::new(
-                texture
-                    .texture()
-                    .source()
-                    .name()
-                    .unwrap_or_default()
-                    .to_owned(),
-            ));
+            material.base_color_texture_name = Some(Arc::new(texture_name(&texture.texture())));
+            material.base_color_sampler = Sampler::load(&texture.texture());
         }
 
         material.roughness_factor = pbr.roughness_factor();
@@ -84,29 +72,16 @@ impl PbrMaterial {
 
         if let Some(texture) = pbr.metallic_roughness_texture() {
             if material.metallic_factor > 0. {
-                material.metallic_texture = Some(data.load_gray_image(&texture.texture(), 2));
-                material.base_color_texture_name = Some(Arc::new(
-                    texture
-                        .texture()
-                        .source()
-                        .name()
-                        .unwrap_or_default()
-                        .to_owned(),
-                ));
+                material.metallic_texture = data.load_gray_image(&texture.texture(), 2);
             }
 
             if material.roughness_factor > 0. {
-                material.roughness_texture = Some(data.load_gray_image(&texture.texture(), 1));
+                material.roughness_texture = data.load_gray_image(&texture.texture(), 1);
             }
-            let texture_name = Arc::new(
-                texture
-                    .texture()
-                    .source()
-                    .name()
-                    .unwrap_or_default()
-                    .to_owned(),
-            );
+            let texture_name = Arc::new(texture_name(&texture.texture()));
             material.metallic_roughness_texture_name = Some(texture_name);
+            material.metallic_roughness_texture = data.load_rgb_image(&texture.texture());
+            material.metallic_roughness_sampler = Sampler::load(&texture.texture());
         }
 
         material
@@ -119,11 +94,14 @@ impl Default for PbrMaterial {
             base_color_factor: Vector4::new(1., 1., 1., 1.),
             base_color_texture: None,
             base_color_texture_name: None,
+            base_color_sampler: Sampler::default(),
             metallic_factor: 0.,
             metallic_texture: None,
             roughness_factor: 0.,
             roughness_texture: None,
+            metallic_roughness_texture: None,
             metallic_roughness_texture_name: None,
+            metallic_roughness_sampler: Sampler::default(),
         }
     }
 }
@@ -0,0 +1,77 @@
+use gltf::texture::{MagFilter, MinFilter, WrappingMode};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+/// What happens to texture coordinates outside of `[0, 1]`
+pub enum WrapMode {
+    /// The texture is tiled
+    #[default]
+    Repeat,
+    /// The texture is tiled, every other tile being mirrored
+    MirroredRepeat,
+    /// The coordinates are clamped to the edge texels
+    ClampToEdge,
+}
+
+impl From<WrappingMode> for WrapMode {
+    fn from(value: WrappingMode) -> Self {
+        match value {
+            WrappingMode::Repeat => WrapMode::Repeat,
+            WrappingMode::MirroredRepeat => WrapMode::MirroredRepeat,
+            WrappingMode::ClampToEdge => WrapMode::ClampToEdge,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// How texels are sampled
+pub enum Filter {
+    /// Closest texel (pixel art)
+    Nearest,
+    /// Blend of the closest texels
+    Linear,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+/// How a texture is sampled, from the sampler of the glTF texture.
+/// The filters are None when the file leaves them up to the renderer.
+pub struct Sampler {
+    /// Wrapping of the U coordinate
+    pub wrap_s: WrapMode,
+    /// Wrapping of the V coordinate
+    pub wrap_t: WrapMode,
+    /// Filter used when the texture is magnified
+    pub mag_filter: Option<Filter>,
+    /// Filter used inside a mip level when the texture is minified
+    pub min_filter: Option<Filter>,
+    /// Filter used between mip levels, None when mipmaps shouldn't be used (or when the file doesn't say)
+    pub mipmap_filter: Option<Filter>,
+}
+
+impl Sampler {
+    pub(crate) fn load(texture: &gltf::Texture<'_>) -> Self {
+        let sampler = texture.sampler();
+
+        let mag_filter = sampler.mag_filter().map(|filter| match filter {
+            MagFilter::Nearest => Filter::Nearest,
+            MagFilter::Linear => Filter::Linear,
+        });
+        // glTF gives the filter inside a level and between levels in one value
+        let (min_filter, mipmap_filter) = match sampler.min_filter() {
+            None => (None, None),
+            Some(MinFilter::Nearest) => (Some(Filter::Nearest), None),
+            Some(MinFilter::Linear) => (Some(Filter::Linear), None),
+            Some(MinFilter::NearestMipmapNearest) => (Some(Filter::Nearest), Some(Filter::Nearest)),
+            Some(MinFilter::LinearMipmapNearest) => (Some(Filter::Linear), Some(Filter::Nearest)),
+            Some(MinFilter::NearestMipmapLinear) => (Some(Filter::Nearest), Some(Filter::Linear)),
+            Some(MinFilter::LinearMipmapLinear) => (Some(Filter::Linear), Some(Filter::Linear)),
+        };
+
+        Self {
+            wrap_s: sampler.wrap_s().into(),
+            wrap_t: sampler.wrap_t().into(),
+            mag_filter,
+            min_filter,
+            mipmap_filter,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use cgmath::*;
+use gltf::json::Value;
+
+/// Name of the VRM 1.0 toon material extension
+pub const MTOON_EXTENSION: &str = "VRMC_materials_mtoon";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+/// How the outline width is given
+pub enum OutlineWidthMode {
+    /// No outline
+    #[default]
+    None,
+    /// The width is in world units
+    World,
+    /// The width is relative to the screen height
+    Screen,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Toon shading parameters read from `VRMC_materials_mtoon`.
+/// Only the factors are read, the textures of the extension aren't loaded.
+pub struct Toon {
+    /// Color of the shaded side of the model (linear RGB)
+    pub shade_color_factor: Vector3<f32>,
+
+    /// Moves where the shade starts, -1 to 1
+    pub shading_shift_factor: f32,
+
+    /// How sharp the line between lit and shaded is, 0 (smooth) to 1 (sharp)
+    pub shading_toony_factor: f32,
+
+    /// Width of the outline, see `outline_width_mode` for the unit
+    pub outline_width_factor: f32,
+
+    /// How `outline_width_factor` is measured
+    pub outline_width_mode: OutlineWidthMode,
+
+    /// Color of the outline (linear RGB)
+    pub outline_color_factor: Vector3<f32>,
+
+    /// Color of the rim light (linear RGB)
+    pub rim_color_factor: Vector3<f32>,
+
+    /// Sharpness of the rim light
+    pub rim_fresnel_power: f32,
+
+    /// Widens the rim light
+    pub rim_lift_factor: f32,
+
+    /// How much the scene lighting affects the rim light, 0 to 1
+    pub rim_lighting_mix_factor: f32,
+}
+
+impl Default for Toon {
+    // The defaults given by the extension schema
+    fn default() -> Self {
+        Self {
+            shade_color_factor: Vector3::zero(),
+            shading_shift_factor: 0.0,
+            shading_toony_factor: 0.9,
+            outline_width_factor: 0.0,
+            outline_width_mode: OutlineWidthMode::None,
+            outline_color_factor: Vector3::zero(),
+            rim_color_factor: Vector3::zero(),
+            rim_fresnel_power: 5.0,
+            rim_lift_factor: 0.0,
+            rim_lighting_mix_factor: 1.0,
+        }
+    }
+}
+
+impl Toon {
+    pub(crate) fn load(gltf_mat: &gltf::Material) -> Option<Self> {
+        let extension = gltf_mat.extension_value(MTOON_EXTENSION)?;
+        let default = Self::default();
+
+        let float = |key: &str, default: f32| {
+            extension
+                .get(key)
+                .and_then(Value::as_f64)
+                .map_or(default, |value| value as f32)
+        };
+        let color = |key: &str, default: Vector3<f32>| {
+            extension
+                .get(key)
+                .and_then(Value::as_array)
+                .and_then(|values| {
+                    let values: Vec<f32> = values
+                        .iter()
+                        .filter_map(Value::as_f64)
+                        .map(|value| value as f32)
+                        .collect();
+                    Some(Vector3::new(
+                        *values.first()?,
+                        *values.get(1)?,
+                        *values.get(2)?,
+                    ))
+                })
+                .unwrap_or(default)
+        };
+
+        let outline_width_mode = match extension.get("outlineWidthMode").and_then(Value::as_str) {
+            Some("worldCoordinates") => OutlineWidthMode::World,
+            Some("screenCoordinates") => OutlineWidthMode::Screen,
+            _ => OutlineWidthMode::None,
+        };
+
+        Some(Self {
+            shade_color_factor: color("shadeColorFactor", default.shade_color_factor),
+            shading_shift_factor: float("shadingShiftFactor", default.shading_shift_factor),
+            shading_toony_factor: float("shadingToonyFactor", default.shading_toony_factor),
+            outline_width_factor: float("outlineWidthFactor", default.outline_width_factor),
+            outline_width_mode,
+            outline_color_factor: color("outlineColorFactor", default.outline_color_factor),
+            rim_color_factor: color("parametricRimColorFactor", default.rim_color_factor),
+            rim_fresnel_power: float("parametricRimFresnelPowerFactor", default.rim_fresnel_power),
+            rim_lift_factor: float("parametricRimLiftFactor", default.rim_lift_factor),
+            rim_lighting_mix_factor: float("rimLightingMixFactor", default.rim_lighting_mix_factor),
+        })
+    }
+}
@@ -1,3 +1,5 @@
+mod geometry;
+mod instancing;
 mod material;
 mod mode;
 mod skin;
@@ -7,6 +9,8 @@ use crate::{GLTFAnimation, get_extras, utils::*};
 use cgmath::*;
 use std::{collections::HashMap, sync::Arc};
 
+pub use geometry::Geometry;
+pub(crate) use instancing::load_instances;
 pub use material::*;
 pub use mode::*;
 pub use skin::*;
@@ -65,34 +69,37 @@ pub use vertex::*;
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct Model {
+    pub(crate) node_name: Option<String>,
     pub(crate) mesh_name: Option<String>,
+    pub(crate) node_extras: Option<HashMap<String, String>>,
     pub(crate) mesh_extras: Option<HashMap<String, String>>,
     pub(crate) primitive_extras: Option<HashMap<String, String>>,
 
     pub(crate) index: usize,
+    pub(crate) mesh_index: usize,
     pub(crate) primitive_index: usize,
 
-    pub(crate) vertices: Vec<Vertex>,
-    pub(crate) indices: Option<Vec<u32>>,
+    // Shared between every node that uses the same mesh
+    pub(crate) geometry: Arc<Geometry>,
 
-    pub(crate) morph_targets: Vec<MorphTarget>,
     pub(crate) mode: Mode,
     pub(crate) parent_nodes: Vec<usize>,
 
     // The default transform
     pub(crate) static_transform: DecomposedTransform,
+    // The default transform as given in the file (column major)
+    pub(crate) static_matrix: [[f32; 4]; 4],
     pub(crate) default_weights: Vec<f32>,
 
     pub(crate) skeleton: Option<Skeleton>,
-    pub(crate) bone_indexes: Vec<u16>,
-    pub(crate) bone_weights: Vec<f32>,
+
+    // Transforms from EXT_mesh_gpu_instancing, relative to the node
+    pub(crate) instances: Vec<DecomposedTransform>,
 
     pub(crate) material: Arc<Material>,
+    // (variant index, material) from KHR_materials_variants
+    pub(crate) material_variants: Vec<(usize, Arc<Material>)>,
     pub(crate) animations: Vec<GLTFAnimation>,
-
-    pub(crate) has_normals: bool,
-    pub(crate) has_tangents: bool,
-    pub(crate) has_tex_coords: bool,
 }
 
 impl Model {
@@ -105,11 +112,28 @@ impl Model {
         self.mesh_name.as_deref()
     }
 
+    /// Name of the Node the mesh is attached to. Requires the `names` feature.
+    pub fn node_name(&self) -> Option<&str> {
+        self.node_name.as_deref()
+    }
+
     /// Index of the Primitive of the Mesh that this `Model` corresponds to.
     pub fn primitive_index(&self) -> usize {
         self.primitive_index
     }
 
+    /// Index of the Mesh that this `Model` corresponds to.
+    /// Nodes with the same mesh index share the same geometry.
+    pub fn mesh_index(&self) -> usize {
+        self.mesh_index
+    }
+
+    /// Whether both models point to the same geometry (same mesh and primitive in the file)
+    /// The geometry is only read once no matter how many nodes use it
+    pub fn shares_geometry(&self, other: &Model) -> bool {
+        Arc::ptr_eq(&self.geometry, &other.geometry)
+    }
+
     /// Index of the Node that this `Model` corresponds to.
     pub fn index(&self) -> usize {
         self.index
@@ -120,6 +144,11 @@ impl Model {
         &self.parent_nodes
     }
 
+    /// Extra data of the Node the mesh is attached to. Requires the `extras` feature.
+    pub fn node_extras(&self) -> &Option<HashMap<String, String>> {
+        &self.node_extras
+    }
+
     /// Mesh extra data. Requires the `extras` feature.
     pub fn mesh_extras(&self) -> &Option<HashMap<String, String>> {
         &self.mesh_extras
@@ -135,18 +164,24 @@ impl Model {
         self.material.clone()
     }
 
+    /// Material to use instead of `material` for each variant (`KHR_materials_variants`), as
+    /// (index in `Scene::variants`, material). Variants that aren't listed keep the default material.
+    pub fn material_variants(&self) -> &[(usize, Arc<Material>)] {
+        &self.material_variants
+    }
+
     /// List of raw `vertices` of the model. You might have to use the `indices`
     /// to render the model.
     ///
     /// **Note**: If you're not rendering with **OpenGL** you probably want to use
     /// `triangles()`, `lines()` or `points()` instead.
     pub fn vertices(&self) -> &Vec<Vertex> {
-        &self.vertices
+        &self.geometry.vertices
     }
 
     /// Length of raw `vertices` list of the model
     pub fn vertices_len(&self) -> usize {
-        self.vertices.len()
+        self.geometry.vertices.len()
     }
 
     /// Potential list of `indices` to render the model using raw `vertices`.
@@ -154,13 +189,13 @@ impl Model {
     /// **Note**: If you're **not** rendering with **OpenGL** you probably want to use
     /// `triangles()`, `lines()` or `points()` instead.
     pub fn indices(&self) -> Option<&Vec<u32>> {
-        self.indices.as_ref()
+        self.geometry.indices.as_ref()
     }
 
     /// The length of the list of `indices` to render the model using raw `vertices`.
     /// If model drawn with XYZ then the result is 0
     pub fn indices_len(&self) -> usize {
-        match &self.indices {
+        match &self.geometry.indices {
             Some(index_vec) => index_vec.len(),
             None => 0,
         }
@@ -182,34 +217,34 @@ impl Model {
     /// or `TriangleStrip`.
     pub fn triangles(&self) -> Result<Vec<Triangle>, BadMode> {
         let mut triangles = vec![];
-        let indices = (0..self.vertices.len() as u32).collect();
+        let indices = (0..self.geometry.vertices.len() as u32).collect();
         let indices = self.indices().unwrap_or(&indices);
 
         match self.mode {
             Mode::Triangles => {
                 for i in (0..indices.len()).step_by(3) {
                     triangles.push([
-                        self.vertices[indices[i] as usize],
-                        self.vertices[indices[i + 1] as usize],
-                        self.vertices[indices[i + 2] as usize],
+                        self.geometry.vertices[indices[i] as usize],
+                        self.geometry.vertices[indices[i + 1] as usize],
+                        self.geometry.vertices[indices[i + 2] as usize],
                     ]);
                 }
             }
             Mode::TriangleStrip => {
                 for i in 0..(indices.len() - 2) {
                     triangles.push([
-                        self.vertices[indices[i] as usize + i % 2],
-                        self.vertices[indices[i + 1 - i % 2] as usize],
-                        self.vertices[indices[i + 2] as usize],
+                        self.geometry.vertices[indices[i] as usize + i % 2],
+                        self.geometry.vertices[indices[i + 1 - i % 2] as usize],
+                        self.geometry.vertices[indices[i + 2] as usize],
                     ]);
                 }
             }
             Mode::TriangleFan => {
                 for i in 1..(indices.len() - 1) {
                     triangles.push([
-                        self.vertices[indices[0] as usize],
-                        self.vertices[indices[i] as usize],
-                        self.vertices[indices[i + 1] as usize],
+                        self.geometry.vertices[indices[0] as usize],
+                        self.geometry.vertices[indices[i] as usize],
+                        self.geometry.vertices[indices[i + 1] as usize],
                     ]);
                 }
             }
@@ -224,22 +259,22 @@ impl Model {
     /// or `LineStrip`.
     pub fn lines(&self) -> Result<Vec<Line>, BadMode> {
         let mut lines = vec![];
-        let indices = (0..self.vertices.len() as u32).collect();
+        let indices = (0..self.geometry.vertices.len() as u32).collect();
         let indices = self.indices().unwrap_or(&indices);
         match self.mode {
             Mode::Lines => {
                 for i in (0..indices.len()).step_by(2) {
                     lines.push([
-                        self.vertices[indices[i] as usize],
-                        self.vertices[indices[i + 1] as usize],
+                        self.geometry.vertices[indices[i] as usize],
+                        self.geometry.vertices[indices[i + 1] as usize],
                     ]);
                 }
             }
             Mode::LineStrip | Mode::LineLoop => {
                 for i in 0..(indices.len() - 1) {
                     lines.push([
-                        self.vertices[indices[i] as usize],
-                        self.vertices[indices[i + 1] as usize],
+                        self.geometry.vertices[indices[i] as usize],
+                        self.geometry.vertices[indices[i + 1] as usize],
                     ]);
                 }
             }
@@ -247,8 +282,8 @@ impl Model {
         }
         if self.mode == Mode::LineLoop {
             lines.push([
-                self.vertices[indices[0] as usize],
-                self.vertices[indices[indices.len() - 1] as usize],
+                self.geometry.vertices[indices[0] as usize],
+                self.geometry.vertices[indices[indices.len() - 1] as usize],
             ]);
         }
 
@@ -260,7 +295,7 @@ impl Model {
     /// **Note**: This function will return an error if the mode isn't `Points`.
     pub fn points(&self) -> Result<&Vec<Vertex>, BadMode> {
         match self.mode {
-            Mode::Points => Ok(&self.vertices),
+            Mode::Points => Ok(&self.geometry.vertices),
             _ => Err(BadMode { mode: self.mode() }),
         }
     }
@@ -270,6 +305,12 @@ impl Model {
         &self.static_transform
     }
 
+    /// The initial transform as a matrix
+    /// Unlike `transform` it keeps any shear the file had
+    pub fn matrix(&self) -> Matrix4<f32> {
+        Matrix4::from(self.static_matrix)
+    }
+
     /// Animations associated with this model
     pub fn animations(&self) -> &Vec<GLTFAnimation> {
         &self.animations
@@ -285,7 +326,7 @@ impl Model {
     /// **Note**: If this function return `false` all vertices has a normal field
     /// initialized to `zero`.
     pub fn has_normals(&self) -> bool {
-        self.has_normals
+        self.geometry.has_normals
     }
 
     /// Indicate if the vertices contains tangents information.
@@ -293,7 +334,7 @@ impl Model {
     /// **Note**: If this function return `false` all vertices has a tangent field
     /// initialized to `zero`.
     pub fn has_tangents(&self) -> bool {
-        self.has_tangents
+        self.geometry.has_tangents
     }
 
     /// Indicate if the vertices contains texture coordinates information.
@@ -301,12 +342,12 @@ impl Model {
     /// **Note**: If this function return `false` all vertices has a tex_coord field
     /// initialized to `zero`.
     pub fn has_tex_coords(&self) -> bool {
-        self.has_tex_coords
+        self.geometry.has_tex_coords
     }
 
     /// List of final morph target values, they are ordered in the same way as the vertices
     pub fn morph_targets(&self) -> &Vec<MorphTarget> {
-        &self.morph_targets
+        &self.geometry.morph_targets
     }
 
     /// List of weights to use by default for morph targets
@@ -314,6 +355,12 @@ impl Model {
         &self.default_weights
     }
 
+    /// Transforms of every instance of the model (`EXT_mesh_gpu_instancing`), empty when the node isn't instanced.
+    /// They are relative to the node so the node transform is applied after them
+    pub fn instances(&self) -> &[DecomposedTransform] {
+        &self.instances
+    }
+
     /// The skin associated with the model
     pub fn skeleton(&self) -> &Option<Skeleton> {
         &self.skeleton
@@ -321,12 +368,12 @@ impl Model {
 
     /// The skin associated with the model
     pub fn bone_indexes(&self) -> &Vec<u16> {
-        &self.bone_indexes
+        &self.geometry.bone_indexes
     }
 
     /// The skin associated with the model
     pub fn bone_weights(&self) -> &Vec<f32> {
-        &self.bone_weights
+        &self.geometry.bone_weights
     }
     // fn apply_transform_position(pos: [f32; 3], transform: &Matrix4<f32>) -> Vector3<f32> {
     //     let pos = Vector4::new(pos[0], pos[1], pos[2], 1.);
@@ -355,52 +402,7 @@ impl Model {
         decomposed_transform: &DecomposedTransform,
         data: &mut GltfData,
     ) -> Self {
-        let buffers = &data.buffers;
-        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
-
-        let indices = reader
-            .read_indices()
-            .map(|indices| indices.into_u32().collect());
-
-        // Init vertices with the position
-        let mut vertices: Vec<_> = reader
-            .read_positions()
-            .unwrap_or_else(|| panic!("The model primitive doesn't contain positions"))
-            .map(|pos| Vertex {
-                position: Vector3::new(pos[0], pos[1], pos[2]),
-                ..Default::default()
-            })
-            .collect();
-
-        // Fill normals
-        let has_normals = if let Some(normals) = reader.read_normals() {
-            for (i, normal) in normals.enumerate() {
-                vertices[i].normal = normal.into();
-            }
-            true
-        } else {
-            false
-        };
-
-        // Fill tangents
-        let has_tangents = if let Some(tangents) = reader.read_tangents() {
-            for (i, tangent) in tangents.enumerate() {
-                vertices[i].tangent = tangent.into();
-            }
-            true
-        } else {
-            false
-        };
-
-        // Texture coordinates
-        let has_tex_coords = if let Some(tex_coords) = reader.read_tex_coords(0) {
-            for (i, tex_coords) in tex_coords.into_f32().enumerate() {
-                vertices[i].tex_coords = Vector2::from(tex_coords);
-            }
-            true
-        } else {
-            false
-        };
+        let node_extras: Option<HashMap<String, String>> = get_extras!(node);
 
         let mesh_extras: Option<HashMap<String, String>> = get_extras!(mesh);
 
@@ -408,57 +410,7 @@ impl Model {
 
         let animations = data.animations.remove(&node.index()).unwrap_or_default();
 
-        let mut morph_targets = Vec::new();
-        let mut target_names: Vec<String> = Vec::new();
-
-        // Ugly ass code to get the name of morph targets if it exists
-        if let Some(x) = &mesh_extras
-            && let Some(name_array) = x.get("targetNames")
-            && let Ok(gltf::json::Value::Array(target_name)) =
-                gltf::json::deserialize::from_str::<gltf::json::Value>(name_array)
-        {
-            target_names.extend(target_name.iter().map(|x| {
-                if let Some(name) = x.as_str() {
-                    name.to_string()
-                } else {
-                    String::new()
-                }
-            }));
-        }
-
-        for (index, (position, _normal, _tangent)) in reader.read_morph_targets().enumerate() {
-            let mut blend_shapes = Vec::new();
-
-            if let Some(position) = position {
-                blend_shapes.extend(position.map(|pos| Vertex {
-                    position: Vector3::from(pos),
-                    ..Default::default()
-                }));
-            }
-
-            let name = if let Some(name) = target_names.get(index) {
-                name.clone()
-            } else {
-                format!("Key {index}").to_string()
-            };
-
-            morph_targets.push(MorphTarget { name, blend_shapes });
-        }
-
-        let mut bone_indexes = Vec::with_capacity(vertices.len() * 4);
-        let mut bone_weights = Vec::with_capacity(vertices.len() * 4);
-
-        if let Some(joint_sets) = reader.read_joints(0) {
-            for joint_index in joint_sets.into_u16() {
-                bone_indexes.extend_from_slice(&joint_index);
-            }
-        }
-
-        if let Some(joint_sets) = reader.read_weights(0) {
-            for weights in joint_sets.into_f32() {
-                bone_weights.extend_from_slice(&weights);
-            }
-        }
+        let geometry = Geometry::load(mesh, primitive_index, &primitive, &mesh_extras, data);
 
         let default_weights = if let Some(weight_slice) = node.weights() {
             let mut weights = weight_slice.to_vec();
@@ -484,26 +436,39 @@ impl Model {
         };
 
         Model {
+            node_name: node.name().map(String::from),
             mesh_name: mesh.name().map(String::from),
+            node_extras,
             mesh_extras,
             primitive_extras,
             index: node.index(),
+            mesh_index: mesh.index(),
             primitive_index,
-            vertices,
-            indices,
+            geometry,
             parent_nodes: parents,
             static_transform: decomposed_transform.clone(),
-            morph_targets,
+            static_matrix: node.transform().matrix(),
             default_weights,
             material: Material::load(primitive.material(), data),
+            material_variants: primitive
+                .mappings()
+                .flat_map(|mapping| {
+                    let material = Material::load(mapping.material(), data);
+                    mapping
+                        .variants()
+                        .iter()
+                        .map(move |variant| (*variant as usize, material.clone()))
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
             animations,
             mode: primitive.mode().into(),
-            has_normals,
-            has_tangents,
-            has_tex_coords,
             skeleton,
-            bone_indexes,
-            bone_weights,
+            instances: data
+                .instances
+                .get(&node.index())
+                .cloned()
+                .unwrap_or_default(),
         }
     }
 }
@@ -5,10 +5,6 @@ use std::collections::HashMap;
 
 use super::Vertex;
 
-const BIND_CONVERSION_MATRIX: cgmath::Matrix4<f32> = cgmath::Matrix4::new(
-    1.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
-);
-
 /// Skeleton from a GLTF skin
 #[derive(Clone, Debug)]
 pub struct Skeleton {
@@ -47,7 +43,7 @@ impl Skeleton {
             for mat in mats {
                 let mat = cgmath::Matrix4::from(mat);
 
-                bind_matrixes.push(BIND_CONVERSION_MATRIX * mat * BIND_CONVERSION_MATRIX);
+                bind_matrixes.push(data.convention.convert_matrix(mat));
             }
         }
 
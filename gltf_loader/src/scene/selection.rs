@@ -0,0 +1,81 @@
+use rustc_hash::FxHashSet;
+
+use crate::Material;
+use serde::Deserialize;
+
+/// Which scenes of the file get loaded
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum SceneSelection {
+    /// Every scene in the file
+    #[default]
+    All,
+    /// Only the scene the file says to show first (or the first scene when it doesn't say)
+    Default,
+    /// Scenes with one of these names
+    Names(Vec<String>),
+    /// Scenes at these indexes in the file
+    Indices(Vec<usize>),
+}
+
+impl SceneSelection {
+    /// Whether the scene is part of the selection
+    pub fn includes(&self, scene: &gltf::Scene, default_scene: usize) -> bool {
+        match self {
+            SceneSelection::All => true,
+            SceneSelection::Default => scene.index() == default_scene,
+            SceneSelection::Names(names) => scene
+                .name()
+                .is_some_and(|name| names.iter().any(|wanted| wanted == name)),
+            SceneSelection::Indices(indices) => indices.contains(&scene.index()),
+        }
+    }
+}
+
+// Every node that's part of the scenes (children included)
+pub(crate) fn scene_nodes(scenes: &[gltf::Scene]) -> FxHashSet<usize> {
+    let mut nodes = FxHashSet::default();
+    let mut stack: Vec<gltf::Node> = scenes.iter().flat_map(|scene| scene.nodes()).collect();
+
+    while let Some(node) = stack.pop() {
+        if nodes.insert(node.index()) {
+            stack.extend(node.children());
+        }
+    }
+
+    nodes
+}
+
+// Textures used by the materials of the meshes of those nodes
+pub(crate) fn used_textures(doc: &gltf::Document, nodes: &FxHashSet<usize>) -> FxHashSet<usize> {
+    let mut textures = FxHashSet::default();
+
+    let materials = doc
+        .nodes()
+        .filter(|node| nodes.contains(&node.index()))
+        .filter_map(|node| node.mesh())
+        .flat_map(|mesh| {
+            mesh.primitives()
+                .flat_map(|primitive| {
+                    // The materials of every variant too
+                    std::iter::once(primitive.material())
+                        .chain(primitive.mappings().map(|mapping| mapping.material()))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        });
+
+    for material in materials {
+        textures.extend(Material::texture_indices(&material));
+    }
+
+    textures
+}
+
+// Skins of the skinned meshes among those nodes
+pub(crate) fn used_skins(doc: &gltf::Document, nodes: &FxHashSet<usize>) -> FxHashSet<usize> {
+    doc.nodes()
+        .filter(|node| nodes.contains(&node.index()))
+        .filter_map(|node| node.skin())
+        .map(|skin| skin.index())
+        .collect()
+}
@@ -0,0 +1,310 @@
+use cgmath::*;
+use serde::Deserialize;
+
+use super::{DecomposedTransform, RotationTransform, quaterions_to_zyx_euler};
+
+/// An axis of the output space along with its direction
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum Axis {
+    /// +X
+    X,
+    /// -X
+    NegX,
+    /// +Y
+    Y,
+    /// -Y (renpy's up since y goes down the screen)
+    #[default]
+    NegY,
+    /// +Z
+    Z,
+    /// -Z
+    NegZ,
+}
+
+impl Axis {
+    fn index(self) -> usize {
+        match self {
+            Axis::X | Axis::NegX => 0,
+            Axis::Y | Axis::NegY => 1,
+            Axis::Z | Axis::NegZ => 2,
+        }
+    }
+
+    fn sign(self) -> f32 {
+        match self {
+            Axis::X | Axis::Y | Axis::Z => 1.0,
+            Axis::NegX | Axis::NegY | Axis::NegZ => -1.0,
+        }
+    }
+}
+
+/// Handedness of the output space (GLTF is right handed)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum Handedness {
+    /// Same as GLTF
+    Right,
+    /// Renpy flips the y axis, so it's left handed
+    #[default]
+    Left,
+}
+
+/// Order the euler rotations are applied in
+/// `ZYX` means the rotation matrix is `Rz * Ry * Rx` (so x is applied first to the object)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum EulerOrder {
+    /// `Rx * Ry * Rz`
+    XYZ,
+    /// `Rx * Rz * Ry`
+    XZY,
+    /// `Ry * Rx * Rz`
+    YXZ,
+    /// `Ry * Rz * Rx`
+    YZX,
+    /// `Rz * Rx * Ry`
+    ZXY,
+    /// What renpy uses
+    #[default]
+    ZYX,
+}
+
+impl EulerOrder {
+    // Axes from the leftmost matrix to the rightmost
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+        }
+    }
+
+    // XYZ, YZX and ZXY are the even permutations
+    fn parity(self) -> f32 {
+        match self {
+            EulerOrder::XYZ | EulerOrder::YZX | EulerOrder::ZXY => 1.0,
+            EulerOrder::XZY | EulerOrder::YXZ | EulerOrder::ZYX => -1.0,
+        }
+    }
+}
+
+/// How GLTF coordinates (+Y up, right handed, meters) get turned into the output coordinates
+///
+/// GLTF +Y goes to `up`, GLTF +X goes to the first axis `up` doesn't use (so +X unless `up` is on X, then +Y)
+/// and GLTF +Z goes to the last axis, pointing whichever way gives the right `handedness`.
+/// Since only axes are swapped or flipped, converting and going back is exact (minus the float error of `units_to_pixels`).
+///
+/// The default is what renpy expects: y flipped, left handed, ZYX euler and 1 unit per pixel
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct CoordinateConvention {
+    /// Where GLTF's up (+Y) ends up
+    pub up: Axis,
+    /// Handedness of the output coordinates
+    pub handedness: Handedness,
+    /// Order of the euler angles given for transforms
+    pub euler_order: EulerOrder,
+    /// Multiplies every position (points, translations, morph targets)
+    pub units_to_pixels: f32,
+}
+
+impl Default for CoordinateConvention {
+    fn default() -> Self {
+        CoordinateConvention {
+            up: Axis::NegY,
+            handedness: Handedness::Left,
+            euler_order: EulerOrder::ZYX,
+            units_to_pixels: 1.0,
+        }
+    }
+}
+
+impl CoordinateConvention {
+    /// The output axis and the sign for each of the GLTF axes
+    fn basis(&self) -> ([usize; 3], [f32; 3]) {
+        let up = self.up.index();
+        let right = if up == 0 { 1 } else { 0 };
+        let forward = 3_usize.saturating_sub(up).saturating_sub(right);
+
+        let axes = [right, up, forward];
+        let mut signs = [1.0, self.up.sign(), 1.0];
+
+        let wanted = match self.handedness {
+            Handedness::Right => 1.0,
+            Handedness::Left => -1.0,
+        };
+        if determinant(axes, signs) != wanted {
+            signs[2] = -1.0;
+        }
+
+        (axes, signs)
+    }
+
+    /// 1 when the handedness is kept and -1 when it's flipped
+    pub fn determinant(&self) -> f32 {
+        let (axes, signs) = self.basis();
+        determinant(axes, signs)
+    }
+
+    /// Converts a direction (no unit scaling)
+    pub fn convert_direction(&self, direction: Vector3<f32>) -> Vector3<f32> {
+        let (axes, signs) = self.basis();
+        let mut result = Vector3::zero();
+        for ((axis, sign), value) in axes
+            .iter()
+            .zip(signs)
+            .zip(Into::<[f32; 3]>::into(direction))
+        {
+            result[*axis] = sign * value;
+        }
+        result
+    }
+
+    /// Converts a position (or anything measured in units like a translation or morph target offset)
+    pub fn convert_point(&self, point: Vector3<f32>) -> Vector3<f32> {
+        self.convert_direction(point) * self.units_to_pixels
+    }
+
+    /// A scale along the GLTF axes becomes a scale along the output axes
+    pub fn convert_scale(&self, scale: Vector3<f32>) -> Vector3<f32> {
+        let (axes, _) = self.basis();
+        let mut result = Vector3::zero();
+        for (axis, value) in axes.iter().zip(Into::<[f32; 3]>::into(scale)) {
+            result[*axis] = value;
+        }
+        result
+    }
+
+    /// Same rotation seen from the output axes
+    /// The axis of a rotation doesn't flip like a normal vector when the handedness changes, hence the determinant
+    pub fn convert_quaternion(&self, quaternion: Quaternion<f32>) -> Quaternion<f32> {
+        Quaternion::from_sv(
+            quaternion.s,
+            self.convert_direction(quaternion.v) * self.determinant(),
+        )
+    }
+
+    /// Converts a whole matrix (like the skeleton inverse bind matrices)
+    pub fn convert_matrix(&self, matrix: Matrix4<f32>) -> Matrix4<f32> {
+        let (to_output, to_gltf) = self.conversion_matrices();
+        to_output * matrix * to_gltf
+    }
+
+    /// Converts a GLTF transform, the rotation is kept as a quaternion or turned into euler angles with `euler_order`
+    pub fn convert_transform(
+        &self,
+        transform: &mut DecomposedTransform,
+        keep_rotation_format: bool,
+    ) {
+        transform.translation = self.convert_point(transform.translation);
+        transform.scale = self.convert_scale(transform.scale);
+
+        let rotation = self.convert_quaternion(transform.rotation.clone().unwrap_quaternion());
+        transform.rotation = if keep_rotation_format {
+            RotationTransform::Quaternion(rotation)
+        } else {
+            RotationTransform::Euler(self.quaternion_to_euler(rotation))
+        };
+    }
+
+    /// Euler angles (in degrees) of the rotation in `euler_order`
+    pub fn quaternion_to_euler(&self, quaternion: Quaternion<f32>) -> Euler<Deg<f32>> {
+        // The old conversion is kept for renpy's order so the output doesn't change
+        if self.euler_order == EulerOrder::ZYX {
+            return quaterions_to_zyx_euler(quaternion);
+        }
+
+        let matrix = Matrix3::from(quaternion.normalize());
+        let [i, j, k] = self.euler_order.axes();
+        let parity = self.euler_order.parity();
+        // cgmath matrices are indexed [column][row]
+        let at = |row: usize, column: usize| matrix[column][row];
+
+        let mut angles = [0.0_f32; 3];
+        let sin_j = (parity * at(i, k)).clamp(-1.0, 1.0);
+        angles[j] = sin_j.asin();
+
+        if sin_j.abs() < 0.999_999_9 {
+            angles[i] = (-parity * at(j, k)).atan2(at(k, k));
+            angles[k] = (-parity * at(i, j)).atan2(at(i, i));
+        } else {
+            // Gimbal lock, only the sum of the 2 other angles matters so put it all in the first one
+            angles[i] = (parity * at(k, j)).atan2(at(j, j));
+            angles[k] = 0.0;
+        }
+
+        let [x, y, z] = angles.map(|angle| Deg::from(Rad(angle)));
+        Euler { x, y, z }
+    }
+
+    /// Opposite of `quaternion_to_euler`
+    pub fn euler_to_quaternion(&self, euler: Euler<Deg<f32>>) -> Quaternion<f32> {
+        let angles = [euler.x, euler.y, euler.z];
+        let unit_axes = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()];
+
+        self.euler_order
+            .axes()
+            .iter()
+            .map(|axis| Quaternion::from_axis_angle(unit_axes[*axis], angles[*axis]))
+            .fold(Quaternion::one(), |result, rotation| result * rotation)
+    }
+
+    /// Goes back from a converted position to a GLTF one
+    pub fn point_to_gltf(&self, point: Vector3<f32>) -> Vector3<f32> {
+        self.direction_to_gltf(point / self.units_to_pixels)
+    }
+
+    /// Goes back from a converted direction to a GLTF one
+    pub fn direction_to_gltf(&self, direction: Vector3<f32>) -> Vector3<f32> {
+        let (axes, signs) = self.basis();
+        let mut result = Vector3::zero();
+        for (index, (axis, sign)) in axes.iter().zip(signs).enumerate() {
+            result[index] = direction[*axis] * sign;
+        }
+        result
+    }
+
+    /// Goes back from a converted rotation to a GLTF one
+    pub fn quaternion_to_gltf(&self, quaternion: Quaternion<f32>) -> Quaternion<f32> {
+        Quaternion::from_sv(
+            quaternion.s,
+            self.direction_to_gltf(quaternion.v) * self.determinant(),
+        )
+    }
+
+    /// Goes back from a converted matrix to a GLTF one
+    pub fn matrix_to_gltf(&self, matrix: Matrix4<f32>) -> Matrix4<f32> {
+        let (to_output, to_gltf) = self.conversion_matrices();
+        to_gltf * matrix * to_output
+    }
+
+    // Change of basis matrix (scaled) and its inverse
+    fn conversion_matrices(&self) -> (Matrix4<f32>, Matrix4<f32>) {
+        let (axes, signs) = self.basis();
+
+        let mut to_output = Matrix4::identity();
+        let mut to_gltf = Matrix4::identity();
+        for (index, axis) in axes.iter().enumerate() {
+            // Clear out the identity first since the axes can be swapped
+            to_output[index] = Vector4::zero();
+            to_gltf[*axis] = Vector4::zero();
+        }
+        for (index, (axis, sign)) in axes.iter().zip(signs).enumerate() {
+            // [column][row]
+            to_output[index][*axis] = sign * self.units_to_pixels;
+            to_gltf[*axis][index] = sign / self.units_to_pixels;
+        }
+
+        (to_output, to_gltf)
+    }
+}
+
+// Determinant of a signed permutation matrix
+fn determinant(axes: [usize; 3], signs: [f32; 3]) -> f32 {
+    let parity = match axes {
+        [0, 1, 2] | [1, 2, 0] | [2, 0, 1] => 1.0,
+        _ => -1.0,
+    };
+    parity * signs.iter().product::<f32>()
+}
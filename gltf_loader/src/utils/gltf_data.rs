@@ -1,49 +1,62 @@
 // #![allow(missing_docs)]
 
-use crate::model::Skeleton;
+use super::ktx2;
+use crate::model::{Geometry, Skeleton};
 use crate::{GLTFAnimation, Material};
 use base64::Engine;
-use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::engine::general_purpose::STANDARD;
 use gltf::image::Source;
 use image::*;
+use rayon::prelude::*;
 use rustc_hash::FxHashMap;
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+
+/// Extension giving a WebP version of a texture
+pub const WEBP_EXTENSION: &str = "EXT_texture_webp";
+/// Extension giving a KTX2 (Basis Universal) version of a texture
+pub const BASISU_EXTENSION: &str = "KHR_texture_basisu";
 
 /// Helps to simplify the signature of import related functions.
 #[allow(dead_code)]
 pub struct GltfData {
     /// Various buffers in the gltf file
     pub buffers: Vec<gltf::buffer::Data>,
-    // pub images: Vec<gltf::image::Data>,
     /// Base directory of the import path
     pub base_dir: PathBuf,
 
     /// Cached Materials
     pub materials: FxHashMap<Option<usize>, Arc<Material>>,
 
-    /// Cached RGB Image
+    /// Decoded images by image index, textures sharing an image share the decoded one
+    pub images: FxHashMap<usize, DynamicImage>,
+    /// Index of the image used by each texture, the textures that couldn't be decoded aren't there
+    pub texture_images: FxHashMap<usize, usize>,
+
+    /// Cached RGB Image by image index
     pub rgb_images: FxHashMap<usize, Arc<RgbImage>>,
-    /// Cached RGBA Image
+    /// Cached RGBA Image by image index
     pub rgba_images: FxHashMap<usize, Arc<RgbaImage>>,
-    /// Cached Greyscale images
+    /// Cached Greyscale images by image index and channel
     pub gray_images: FxHashMap<(usize, usize), Arc<GrayImage>>,
-    /// Cached Raw Textures
-    pub raw_images: FxHashMap<usize, DynamicImage>,
 
     /// Cached Animations
     pub animations: FxHashMap<usize, Vec<GLTFAnimation>>,
     /// Cached Skeletons
     pub skeletons: FxHashMap<usize, Skeleton>,
+    /// Cached primitive geometry by (mesh, primitive) index
+    pub geometries: FxHashMap<(usize, usize), Arc<Geometry>>,
+    /// Per instance transforms (`EXT_mesh_gpu_instancing`) by node index
+    pub instances: FxHashMap<usize, Vec<super::DecomposedTransform>>,
+
+    /// Coordinates that the skeleton bind matrices get converted to
+    pub convention: super::CoordinateConvention,
 }
 
 impl GltfData {
     /// Create a new context for gltf data
-    pub fn new<P>(
-        buffers: Vec<gltf::buffer::Data>,
-        _images: Vec<gltf::image::Data>,
-        path: P,
-    ) -> Self
+    pub fn new<P>(buffers: Vec<gltf::buffer::Data>, path: P) -> Self
     where
         P: AsRef<Path>,
     {
@@ -51,63 +64,58 @@ impl GltfData {
         base_dir.pop();
         GltfData {
             buffers,
-            // images,
             base_dir,
             materials: Default::default(),
+            images: Default::default(),
+            texture_images: Default::default(),
             rgb_images: Default::default(),
             rgba_images: Default::default(),
             gray_images: Default::default(),
-            raw_images: Default::default(),
             animations: Default::default(),
             skeletons: Default::default(),
+            geometries: Default::default(),
+            instances: Default::default(),
+            convention: Default::default(),
         }
     }
 
-    /// Load an RGB image from a gltf texture object
-    pub fn load_rgb_image(&mut self, texture: &gltf::Texture<'_>) -> Arc<RgbImage> {
-        if let Some(image) = self.rgb_images.get(&texture.index()) {
-            return image.clone();
+    /// Load an RGB image from a gltf texture object, None when the texture wasn't decoded
+    pub fn load_rgb_image(&mut self, texture: &gltf::Texture<'_>) -> Option<Arc<RgbImage>> {
+        let index = *self.texture_images.get(&texture.index())?;
+        if let Some(image) = self.rgb_images.get(&index) {
+            return Some(image.clone());
         }
-        let img = if let Some(image) = self.raw_images.get(&texture.index()) {
-            Arc::new(image.to_rgb8())
-        } else {
-            Arc::new(self.load_texture(texture).to_rgb8())
-        };
+        let img = Arc::new(self.images.get(&index)?.to_rgb8());
 
-        self.rgb_images.insert(texture.index(), img.clone());
-        img
+        self.rgb_images.insert(index, img.clone());
+        Some(img)
     }
 
-    /// Load an RGBA image from a gltf texture object
-    pub fn load_base_color_image(&mut self, texture: &gltf::Texture<'_>) -> Arc<RgbaImage> {
-        if let Some(image) = self.rgba_images.get(&texture.index()) {
-            return image.clone();
+    /// Load an RGBA image from a gltf texture object, None when the texture wasn't decoded
+    pub fn load_base_color_image(&mut self, texture: &gltf::Texture<'_>) -> Option<Arc<RgbaImage>> {
+        let index = *self.texture_images.get(&texture.index())?;
+        if let Some(image) = self.rgba_images.get(&index) {
+            return Some(image.clone());
         }
-        let img = if let Some(image) = self.raw_images.get(&texture.index()) {
-            Arc::new(image.to_rgba8())
-        } else {
-            Arc::new(self.load_texture(texture).to_rgba8())
-        };
+        let img = Arc::new(self.images.get(&index)?.to_rgba8());
 
-        self.rgba_images.insert(texture.index(), img.clone());
-        img
+        self.rgba_images.insert(index, img.clone());
+        Some(img)
     }
 
-    /// Load a greyscale image from a gltf texture object
+    /// Load a greyscale image from a gltf texture object, None when the texture wasn't decoded
     pub fn load_gray_image(
         &mut self,
         texture: &gltf::Texture<'_>,
         channel: usize,
-    ) -> Arc<GrayImage> {
-        if let Some(image) = self.gray_images.get(&(texture.index(), channel)) {
-            return image.clone();
+    ) -> Option<Arc<GrayImage>> {
+        let index = *self.texture_images.get(&texture.index())?;
+        if let Some(image) = self.gray_images.get(&(index, channel)) {
+            return Some(image.clone());
         }
 
-        let img = if let Some(image) = self.raw_images.get(&texture.index()) {
-            Arc::new(image.to_rgba8())
-        } else {
-            Arc::new(self.load_texture(texture).to_rgba8())
-        };
+        // Every channel is taken out of the same RGBA conversion
+        let img = self.load_base_color_image(texture)?;
 
         let mut extract_img = GrayImage::new(img.width(), img.height());
         for (x, y, px) in img.enumerate_pixels() {
@@ -115,54 +123,105 @@ impl GltfData {
         }
 
         let img = Arc::new(extract_img);
-        self.gray_images
-            .insert((texture.index(), channel), img.clone());
-        img
+        self.gray_images.insert((index, channel), img.clone());
+        Some(img)
+    }
+
+    /// Decodes the images of the textures, in parallel and once per image even when textures share it.
+    ///
+    /// The sources of `EXT_texture_webp` and `KHR_texture_basisu` are tried before the standard `source`,
+    /// the first one that decodes is used. A texture that can't be decoded is left out of its materials.
+    pub fn load_textures(&mut self, document: &gltf::Document, textures: &[gltf::Texture<'_>]) {
+        let decoded: Vec<OnceLock<Option<DynamicImage>>> =
+            document.images().map(|_| OnceLock::new()).collect();
+
+        // The lock makes the textures sharing an image wait for the first one to decode it
+        let texture_images: Vec<(usize, usize)> = textures
+            .par_iter()
+            .filter_map(|texture| {
+                texture_images(document, texture)
+                    .into_iter()
+                    .find(|image| {
+                        decoded.get(image.index()).is_some_and(|slot| {
+                            slot.get_or_init(|| self.load_image(image).ok()).is_some()
+                        })
+                    })
+                    .map(|image| (texture.index(), image.index()))
+            })
+            .collect();
+
+        self.texture_images.extend(texture_images);
+        self.images.extend(
+            decoded
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, slot)| Some((index, slot.into_inner()??))),
+        );
     }
 
-    /// Generic Helper function to load an image from a gltf texture object
-    pub fn load_texture(&self, texture: &gltf::Texture<'_>) -> DynamicImage {
-        let g_img = texture.source();
-        let buffers = &self.buffers;
-        match g_img.source() {
-            Source::View { view, mime_type } => {
-                let parent_buffer_data = &buffers[view.buffer().index()].0;
-                let data = &parent_buffer_data[view.offset()..view.offset() + view.length()];
-                let mime_type = mime_type.replace('/', ".");
-                image::load_from_memory_with_format(
-                    data,
-                    ImageFormat::from_path(mime_type).unwrap(),
-                )
-                .unwrap()
+    /// Decodes a gltf image, whatever its source and format (PNG, JPEG, WebP, KTX2...)
+    pub fn load_image(&self, image: &gltf::Image<'_>) -> anyhow::Result<DynamicImage> {
+        let data = self.image_bytes(image)?;
+
+        // The content is trusted over the mime type, exporters get it wrong sometimes
+        if ktx2::is_ktx2(&data) {
+            ktx2::decode(&data)
+        } else {
+            Ok(image::load_from_memory(&data)?)
+        }
+    }
+
+    fn image_bytes<'a>(&'a self, image: &gltf::Image<'_>) -> anyhow::Result<Cow<'a, [u8]>> {
+        match image.source() {
+            Source::View { view, .. } => {
+                let data = self
+                    .buffers
+                    .get(view.buffer().index())
+                    .and_then(|buffer| buffer.0.get(view.offset()..view.offset() + view.length()))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Buffer view {} is out of its buffer", view.index())
+                    })?;
+                Ok(Cow::Borrowed(data))
             }
-            Source::Uri { uri, mime_type } => {
-                if uri.starts_with("data:") {
-                    let encoded = uri.split(',').nth(1).unwrap();
-                    let data = URL_SAFE_NO_PAD.decode(encoded).unwrap();
-                    let mime_type = if let Some(ty) = mime_type {
-                        ty
-                    } else {
-                        uri.split(',')
-                            .next()
-                            .unwrap()
-                            .split(':')
-                            .nth(1)
-                            .unwrap()
-                            .split(';')
-                            .next()
-                            .unwrap()
+            Source::Uri { uri, .. } => {
+                if let Some(data) = uri.strip_prefix("data:") {
+                    let Some((_, encoded)) = data.split_once(',') else {
+                        anyhow::bail!("Data uri of image {} has no data", image.index());
                     };
-                    let mime_type = mime_type.replace('/', ".");
-                    image::load_from_memory_with_format(
-                        &data,
-                        ImageFormat::from_path(mime_type).unwrap(),
-                    )
-                    .unwrap()
+                    Ok(Cow::Owned(STANDARD.decode(encoded)?))
                 } else {
-                    let path = self.base_dir.join(uri);
-                    open(path).unwrap()
+                    Ok(Cow::Owned(std::fs::read(self.base_dir.join(uri))?))
                 }
             }
         }
     }
 }
+
+/// The images a texture can be read from, the extension sources first then the standard `source`
+pub fn texture_images<'a>(
+    document: &'a gltf::Document,
+    texture: &gltf::Texture<'_>,
+) -> Vec<gltf::Image<'a>> {
+    let mut indexes: Vec<usize> = [WEBP_EXTENSION, BASISU_EXTENSION]
+        .into_iter()
+        .filter_map(|extension| texture.extension_value(extension))
+        .filter_map(|extension| extension.get("source")?.as_u64())
+        .filter_map(|index| usize::try_from(index).ok())
+        .collect();
+    indexes.extend(texture.source().map(|image| image.index()));
+    indexes.dedup();
+
+    indexes
+        .into_iter()
+        .filter_map(|index| document.images().nth(index))
+        .collect()
+}
+
+/// Name of the image of a texture, the standard `source` is preferred so the names don't change with the extensions
+pub fn texture_name(texture: &gltf::Texture<'_>) -> String {
+    texture
+        .source()
+        .and_then(|image| image.name())
+        .unwrap_or_default()
+        .to_owned()
+}
@@ -0,0 +1,440 @@
+// Minimal KTX2 reader for the images of `KHR_texture_basisu`
+// Only the first layer/face of the largest mip is read since that's all renpy uses
+// https://registry.khronos.org/KTX/specs/2.0/ktxspec.v2.html
+
+use basis_universal::{TranscodeParameters, Transcoder, TranscoderTextureFormat};
+use image::{DynamicImage, GrayImage, RgbImage, RgbaImage};
+
+const IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+// Identifier, 9 u32 fields, 4 u32 + 2 u64 for the data format/key value/supercompression indexes
+const LEVEL_INDEX_OFFSET: usize = 80;
+
+/// Supercompression applied to the mip levels (`supercompressionScheme` in the header)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Supercompression {
+    /// Levels are stored as is
+    None,
+    /// ETC1S data compressed with BasisLZ
+    BasisLz,
+    /// Zstandard
+    Zstd,
+    /// Zlib (deflate)
+    Zlib,
+    /// Any scheme not in the spec
+    Unknown(u32),
+}
+
+impl From<u32> for Supercompression {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Supercompression::None,
+            1 => Supercompression::BasisLz,
+            2 => Supercompression::Zstd,
+            3 => Supercompression::Zlib,
+            other => Supercompression::Unknown(other),
+        }
+    }
+}
+
+/// The header fields needed to read the base level
+#[derive(Clone, Debug)]
+pub struct Header {
+    /// `VkFormat` of the texels, 0 for Basis Universal data
+    pub vk_format: u32,
+    /// Width of the base level
+    pub width: u32,
+    /// Height of the base level
+    pub height: u32,
+    /// How the levels are supercompressed
+    pub supercompression: Supercompression,
+}
+
+/// Checks the file identifier
+pub fn is_ktx2(data: &[u8]) -> bool {
+    data.starts_with(&IDENTIFIER)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> anyhow::Result<u32> {
+    data.get(offset..offset + 4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or_else(|| anyhow::anyhow!("KTX2 file is truncated"))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> anyhow::Result<usize> {
+    let value = data
+        .get(offset..offset + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| anyhow::anyhow!("KTX2 file is truncated"))?;
+    Ok(usize::try_from(value)?)
+}
+
+/// Reads the header of a KTX2 file
+pub fn read_header(data: &[u8]) -> anyhow::Result<Header> {
+    if !is_ktx2(data) {
+        anyhow::bail!("Not a KTX2 file");
+    }
+
+    Ok(Header {
+        vk_format: read_u32(data, 12)?,
+        width: read_u32(data, 20)?,
+        height: read_u32(data, 24)?.max(1),
+        supercompression: read_u32(data, 44)?.into(),
+    })
+}
+
+// Channel count and whether red and blue are swapped for the 8 bit formats that can be read as is
+fn texel_layout(vk_format: u32) -> Option<(usize, bool)> {
+    match vk_format {
+        // R8_UNORM..=R8_SRGB
+        9..=15 => Some((1, false)),
+        // R8G8_UNORM..=R8G8_SRGB
+        16..=22 => Some((2, false)),
+        // R8G8B8_UNORM..=R8G8B8_SRGB
+        23..=29 => Some((3, false)),
+        // B8G8R8_UNORM..=B8G8R8_SRGB
+        30..=36 => Some((3, true)),
+        // R8G8B8A8_UNORM..=R8G8B8A8_SRGB
+        37..=43 => Some((4, false)),
+        // B8G8R8A8_UNORM..=B8G8R8A8_SRGB
+        44..=50 => Some((4, true)),
+        _ => None,
+    }
+}
+
+/// Decodes the base level of a KTX2 file to an image.
+///
+/// Uncompressed 8 bit formats are read, with or without zlib or zstd supercompression.
+/// Basis Universal payloads are transcoded to RGBA: ETC1S with its BasisLZ supercompression and UASTC as is or with zstd.
+pub fn decode(data: &[u8]) -> anyhow::Result<DynamicImage> {
+    let header = read_header(data)?;
+
+    // Level 0 is the biggest one
+    let offset = read_u64(data, LEVEL_INDEX_OFFSET)?;
+    let length = read_u64(data, LEVEL_INDEX_OFFSET + 8)?;
+    let level = offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| anyhow::anyhow!("KTX2 level is out of the file"))?;
+
+    let level = match header.supercompression {
+        // BasisLZ is undone by the transcoder along with the ETC1S data
+        Supercompression::None | Supercompression::BasisLz => level.to_vec(),
+        Supercompression::Zlib => miniz_oxide::inflate::decompress_to_vec_zlib(level)
+            .map_err(|err| anyhow::anyhow!("Invalid zlib data in KTX2 level: {err}"))?,
+        Supercompression::Zstd => zstd::stream::decode_all(level)
+            .map_err(|err| anyhow::anyhow!("Invalid zstd data in KTX2 level: {err}"))?,
+        other => anyhow::bail!("KTX2 {other:?} supercompression isn't supported"),
+    };
+
+    if header.vk_format == 0 {
+        return transcode_basis(data, &header, &level);
+    }
+    let Some((channels, bgr)) = texel_layout(header.vk_format) else {
+        anyhow::bail!("KTX2 format {} isn't supported", header.vk_format);
+    };
+
+    // Rows aren't padded in KTX2 so the first layer/face is at the start of the level
+    let texels = (header.width as usize)
+        .checked_mul(header.height as usize)
+        .and_then(|count| count.checked_mul(channels))
+        .ok_or_else(|| anyhow::anyhow!("KTX2 image is too big"))?;
+    let Some(texels) = level.get(..texels) else {
+        anyhow::bail!("KTX2 level is smaller than its image");
+    };
+    let mut texels = texels.to_vec();
+    if bgr {
+        texels
+            .chunks_exact_mut(channels)
+            .for_each(|texel| texel.swap(0, 2));
+    }
+
+    let (width, height) = (header.width, header.height);
+    let image = match channels {
+        1 => GrayImage::from_raw(width, height, texels).map(DynamicImage::ImageLuma8),
+        // Red and green, read as RGB with an empty blue channel like a GPU would
+        2 => RgbImage::from_raw(
+            width,
+            height,
+            texels
+                .chunks_exact(2)
+                .flat_map(|texel| [texel[0], texel[1], 0])
+                .collect(),
+        )
+        .map(DynamicImage::ImageRgb8),
+        3 => RgbImage::from_raw(width, height, texels).map(DynamicImage::ImageRgb8),
+        _ => RgbaImage::from_raw(width, height, texels).map(DynamicImage::ImageRgba8),
+    };
+
+    image.ok_or_else(|| anyhow::anyhow!("KTX2 level doesn't match its size"))
+}
+
+// Data format descriptor color models of the Basis Universal payloads
+const KHR_DF_MODEL_ETC1S: u8 = 163;
+const KHR_DF_MODEL_UASTC: u8 = 166;
+// Channel of the first UASTC sample when the texels have alpha
+const KHR_DF_CHANNEL_UASTC_RGBA: u8 = 3;
+
+// Size of the ETC1S image descriptions in the supercompression global data
+const ETC1S_IMAGE_DESC_SIZE: usize = 20;
+const ETC1S_IS_P_FRAME: u32 = 2;
+
+// .basis header fields the transcoder checks
+const BASIS_SIGNATURE: usize = 0x4273;
+const BASIS_VERSION: usize = 0x13;
+const BASIS_HEADER_SIZE: usize = 77;
+const BASIS_SLICE_DESC_SIZE: usize = 23;
+const BASIS_FORMAT_ETC1S: usize = 0;
+const BASIS_FORMAT_UASTC: usize = 1;
+const BASIS_HEADER_FLAG_ETC1S: usize = 1;
+const BASIS_HEADER_FLAG_HAS_ALPHA_SLICES: usize = 4;
+const BASIS_SLICE_HAS_ALPHA: usize = 1;
+const BASIS_SLICE_IS_I_FRAME: usize = 2;
+
+// The parts of the base level that make up a .basis file
+struct BasisPayload<'a> {
+    etc1s: bool,
+    has_alpha: bool,
+    is_i_frame: bool,
+    endpoint_count: usize,
+    endpoints: &'a [u8],
+    selector_count: usize,
+    selectors: &'a [u8],
+    tables: &'a [u8],
+    // The color slice, then the alpha one for ETC1S with alpha
+    slices: Vec<&'a [u8]>,
+}
+
+fn read_u16(data: &[u8], offset: usize) -> anyhow::Result<u16> {
+    data.get(offset..offset + 2)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u16::from_le_bytes)
+        .ok_or_else(|| anyhow::anyhow!("KTX2 file is truncated"))
+}
+
+fn read_u8(data: &[u8], offset: usize) -> anyhow::Result<u8> {
+    data.get(offset)
+        .copied()
+        .ok_or_else(|| anyhow::anyhow!("KTX2 file is truncated"))
+}
+
+fn slice_of(data: &[u8], offset: usize, length: usize) -> anyhow::Result<&[u8]> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| anyhow::anyhow!("KTX2 Basis data is out of the file"))
+}
+
+// Basis Universal data is transcoded to RGBA32 texels, kept as RGB when there's no alpha.
+// KTX2 only rearranges what a .basis file holds so the first image of the base level is repacked into one,
+// that's the only container the transcoder bindings can read.
+fn transcode_basis(data: &[u8], header: &Header, level: &[u8]) -> anyhow::Result<DynamicImage> {
+    // The color model is right after the descriptor block header, the first sample after the 24 bytes of the block
+    let dfd = read_u32(data, 48)? as usize + 4;
+    let payload = match read_u8(data, dfd + 8)? {
+        KHR_DF_MODEL_ETC1S if header.supercompression == Supercompression::BasisLz => {
+            etc1s_payload(data, level)?
+        }
+        KHR_DF_MODEL_UASTC => BasisPayload {
+            etc1s: false,
+            has_alpha: read_u8(data, dfd + 27)? & 0xF == KHR_DF_CHANNEL_UASTC_RGBA,
+            is_i_frame: true,
+            endpoint_count: 0,
+            endpoints: &[],
+            selector_count: 0,
+            selectors: &[],
+            tables: &[],
+            // 16 bytes per block of 4x4 texels, the first layer/face is at the start of the level
+            slices: vec![slice_of(
+                level,
+                0,
+                header.width.div_ceil(4) as usize * header.height.div_ceil(4) as usize * 16,
+            )?],
+        },
+        other => anyhow::bail!(
+            "KTX2 Basis data with color model {other} and {:?} supercompression isn't supported",
+            header.supercompression
+        ),
+    };
+
+    let basis = repack_basis(header, &payload)?;
+    let mut transcoder = Transcoder::new();
+    transcoder
+        .prepare_transcoding(&basis)
+        .map_err(|_| anyhow::anyhow!("Couldn't prepare the Basis data for transcoding"))?;
+    let texels = transcoder
+        .transcode_image_level(
+            &basis,
+            TranscoderTextureFormat::RGBA32,
+            TranscodeParameters::default(),
+        )
+        .map_err(|err| anyhow::anyhow!("Couldn't transcode the Basis data: {err:?}"))?;
+
+    let (width, height) = (header.width, header.height);
+    let image = if payload.has_alpha {
+        RgbaImage::from_raw(width, height, texels).map(DynamicImage::ImageRgba8)
+    } else {
+        RgbImage::from_raw(
+            width,
+            height,
+            texels
+                .chunks_exact(4)
+                .flat_map(|texel| [texel[0], texel[1], texel[2]])
+                .collect(),
+        )
+        .map(DynamicImage::ImageRgb8)
+    };
+
+    image.ok_or_else(|| anyhow::anyhow!("Transcoded KTX2 level doesn't match its size"))
+}
+
+// The codebooks and tables of BasisLZ are in the supercompression global data, followed by one description per image
+// https://github.khronos.org/KTX-Specification/ktxspec.v2.html#basislz_gd
+fn etc1s_payload<'a>(data: &'a [u8], level: &'a [u8]) -> anyhow::Result<BasisPayload<'a>> {
+    let layers = read_u32(data, 32)?.max(1) as usize;
+    let faces = read_u32(data, 36)?.max(1) as usize;
+    let levels = read_u32(data, 40)?.max(1) as usize;
+    let global = slice_of(data, read_u64(data, 64)?, read_u64(data, 72)?)?;
+
+    let endpoints_length = read_u32(global, 4)? as usize;
+    let selectors_length = read_u32(global, 8)? as usize;
+    let tables_length = read_u32(global, 12)? as usize;
+
+    // The first description is the first image of the base level
+    let rgb_slice = slice_of(
+        level,
+        read_u32(global, 24)? as usize,
+        read_u32(global, 28)? as usize,
+    )?;
+    let alpha_slice = slice_of(
+        level,
+        read_u32(global, 32)? as usize,
+        read_u32(global, 36)? as usize,
+    )?;
+
+    let endpoints_offset = 20 + levels * layers * faces * ETC1S_IMAGE_DESC_SIZE;
+    let selectors_offset = endpoints_offset + endpoints_length;
+    let tables_offset = selectors_offset + selectors_length;
+
+    Ok(BasisPayload {
+        etc1s: true,
+        has_alpha: !alpha_slice.is_empty(),
+        is_i_frame: read_u32(global, 20)? & ETC1S_IS_P_FRAME == 0,
+        endpoint_count: read_u16(global, 0)? as usize,
+        endpoints: slice_of(global, endpoints_offset, endpoints_length)?,
+        selector_count: read_u16(global, 2)? as usize,
+        selectors: slice_of(global, selectors_offset, selectors_length)?,
+        tables: slice_of(global, tables_offset, tables_length)?,
+        slices: if alpha_slice.is_empty() {
+            vec![rgb_slice]
+        } else {
+            vec![rgb_slice, alpha_slice]
+        },
+    })
+}
+
+// Lays out a .basis file with a single image: header, slice descriptions, codebooks, tables then slices.
+// The CRCs are left empty since only the checksum validation looks at them.
+fn repack_basis(header: &Header, payload: &BasisPayload) -> anyhow::Result<Vec<u8>> {
+    let slices_offset = BASIS_HEADER_SIZE + payload.slices.len() * BASIS_SLICE_DESC_SIZE;
+    let selectors_offset = slices_offset + payload.endpoints.len();
+    let tables_offset = selectors_offset + payload.selectors.len();
+    let data_offset = tables_offset + payload.tables.len();
+    let total_size = data_offset
+        + payload
+            .slices
+            .iter()
+            .map(|slice| slice.len())
+            .sum::<usize>();
+
+    let mut flags = 0;
+    if payload.etc1s {
+        flags |= BASIS_HEADER_FLAG_ETC1S;
+    }
+    if payload.has_alpha {
+        flags |= BASIS_HEADER_FLAG_HAS_ALPHA_SLICES;
+    }
+
+    let mut basis = Vec::with_capacity(total_size);
+    // Fields are packed little endian integers of 1 to 4 bytes
+    let mut push = |value: usize, bytes: usize| -> anyhow::Result<()> {
+        let value = u32::try_from(value)?;
+        anyhow::ensure!(
+            bytes == 4 || value >> (bytes * 8) == 0,
+            "KTX2 Basis data is too big"
+        );
+        basis.extend_from_slice(&value.to_le_bytes()[..bytes]);
+        Ok(())
+    };
+
+    push(BASIS_SIGNATURE, 2)?;
+    push(BASIS_VERSION, 2)?;
+    push(BASIS_HEADER_SIZE, 2)?;
+    push(0, 2)?; // Header CRC
+    push(total_size - BASIS_HEADER_SIZE, 4)?;
+    push(0, 2)?; // Data CRC
+    push(payload.slices.len(), 3)?;
+    push(1, 3)?; // Images
+    push(
+        if payload.etc1s {
+            BASIS_FORMAT_ETC1S
+        } else {
+            BASIS_FORMAT_UASTC
+        },
+        1,
+    )?;
+    push(flags, 2)?;
+    push(0, 1)?; // 2D texture
+    push(0, 3)?; // Microseconds per frame
+    push(0, 4)?; // Reserved
+    push(0, 4)?; // User data
+    push(0, 4)?;
+    push(payload.endpoint_count, 2)?;
+    push(slices_offset, 4)?;
+    push(payload.endpoints.len(), 3)?;
+    push(payload.selector_count, 2)?;
+    push(selectors_offset, 4)?;
+    push(payload.selectors.len(), 3)?;
+    push(tables_offset, 4)?;
+    push(payload.tables.len(), 4)?;
+    push(BASIS_HEADER_SIZE, 4)?; // Slice descriptions
+    push(0, 4)?; // Extended data
+    push(0, 4)?;
+
+    let mut offset = data_offset;
+    for (index, slice) in payload.slices.iter().enumerate() {
+        // UASTC keeps its alpha in the same slice, ETC1S in a second one
+        let mut slice_flags = 0;
+        if index == 1 || (!payload.etc1s && payload.has_alpha) {
+            slice_flags |= BASIS_SLICE_HAS_ALPHA;
+        }
+        if payload.is_i_frame {
+            slice_flags |= BASIS_SLICE_IS_I_FRAME;
+        }
+
+        push(0, 3)?; // Image
+        push(0, 1)?; // Level
+        push(slice_flags, 1)?;
+        push(header.width as usize, 2)?;
+        push(header.height as usize, 2)?;
+        push(header.width.div_ceil(4) as usize, 2)?;
+        push(header.height.div_ceil(4) as usize, 2)?;
+        push(offset, 4)?;
+        push(slice.len(), 4)?;
+        push(0, 2)?; // Slice CRC
+        offset += slice.len();
+    }
+
+    basis.extend_from_slice(payload.endpoints);
+    basis.extend_from_slice(payload.selectors);
+    basis.extend_from_slice(payload.tables);
+    for slice in &payload.slices {
+        basis.extend_from_slice(slice);
+    }
+
+    Ok(basis)
+}
@@ -1,13 +1,16 @@
+mod convention;
 mod gltf_data;
+mod ktx2;
 
-pub use gltf_data::GltfData;
+pub use convention::{Axis, CoordinateConvention, EulerOrder, Handedness};
+pub use gltf_data::{BASISU_EXTENSION, GltfData, WEBP_EXTENSION, texture_images, texture_name};
 
 use cgmath::*;
 use core::{f32, f64};
 use gltf::json::Value;
 use gltf::scene::Transform;
 use std::collections::HashMap;
-use std::ops::{Add, Mul, Neg};
+use std::ops::{Add, Mul};
 
 /// Rotation Transformation
 #[derive(Clone, Debug)]
@@ -164,40 +167,29 @@ impl DecomposedTransform {
 
     /// Convert gltf transform coords to renpy coords in place
     pub fn as_renpy_coords(&mut self, keep_rotation_format: bool) {
-        self.translation.y = self.translation.y.neg();
-
-        // Rotation has to be changed since renpy uses clockwise ZYX euler angles while GLTF uses counterclockwise quaternions
-        self.rotation = match self.rotation {
-            RotationTransform::Quaternion(quaternion) => {
-                // Apperntly you can just convert quaternion to XYZ by just mutiplying the real part by -1 but something keeps going wrong...
-                let rot = Quaternion::new(
-                    quaternion.s,
-                    -quaternion.v.x,
-                    quaternion.v.y,
-                    -quaternion.v.z,
-                );
-
-                // I still have to convert euler to quaternion since renpy uses zyx and not xyz euler...
-                // Except for animations because I have to spherical lerp lmao
-                if keep_rotation_format {
-                    RotationTransform::Quaternion(rot)
-                } else {
-                    RotationTransform::Euler(quaterions_to_zyx_euler(rot))
-                }
-            }
-            RotationTransform::Euler(euler) => {
-                let rot = euler.into();
+        self.as_converted_coords(&CoordinateConvention::default(), keep_rotation_format);
+    }
 
-                // Renpy uses ZYX (or something similar I think) euler angles instead of XYZ angles we were using so we need to do some simple conversion
-                let mut rot = quaterions_to_zyx_euler(rot);
-                // We have to negate the x and z angles for some reason? (I think it's because renpy uses clockwise instead of counterclockwise angles)
-                rot.x.0 = rot.x.0.neg();
-                rot.z.0 = rot.z.0.neg();
+    /// Convert gltf transform coords to the coords of the convention
+    pub fn to_converted_coords(
+        mut self,
+        convention: &CoordinateConvention,
+        keep_rotation_format: bool,
+    ) -> Self {
+        self.as_converted_coords(convention, keep_rotation_format);
+        self
+    }
 
-                // We use euler because renpy uses euler for everything so it make the python code much simpler
-                RotationTransform::Euler(rot)
-            }
-        };
+    /// Convert gltf transform coords to the coords of the convention in place
+    /// Euler rotations are taken as GLTF (XYZ) angles
+    pub fn as_converted_coords(
+        &mut self,
+        convention: &CoordinateConvention,
+        keep_rotation_format: bool,
+    ) {
+        // I still have to convert euler to quaternion since renpy uses zyx and not xyz euler...
+        // Except for animations because I have to spherical lerp lmao
+        convention.convert_transform(self, keep_rotation_format);
     }
 
     /// Converts a tranform from the gltf crate into this type
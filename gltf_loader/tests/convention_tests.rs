@@ -0,0 +1,192 @@
+use cgmath::{AbsDiffEq, Deg, Euler, InnerSpace, Matrix4, Quaternion, Rotation3, Vector3};
+use gltf_loader::utils::{
+    Axis, CoordinateConvention, DecomposedTransform, EulerOrder, Handedness, RotationTransform,
+    quaterions_to_zyx_euler,
+};
+
+const AXES: [Axis; 6] = [
+    Axis::X,
+    Axis::NegX,
+    Axis::Y,
+    Axis::NegY,
+    Axis::Z,
+    Axis::NegZ,
+];
+
+const ORDERS: [EulerOrder; 6] = [
+    EulerOrder::XYZ,
+    EulerOrder::XZY,
+    EulerOrder::YXZ,
+    EulerOrder::YZX,
+    EulerOrder::ZXY,
+    EulerOrder::ZYX,
+];
+
+fn conventions() -> Vec<CoordinateConvention> {
+    let mut conventions = Vec::new();
+    for up in AXES {
+        for handedness in [Handedness::Left, Handedness::Right] {
+            // Powers of 2 so that the scale doesn't add float error
+            for units_to_pixels in [1.0, 0.5, 128.0] {
+                conventions.push(CoordinateConvention {
+                    up,
+                    handedness,
+                    units_to_pixels,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    conventions
+}
+
+fn test_quaternion() -> Quaternion<f32> {
+    Quaternion::from_axis_angle(Vector3::new(0.3, -0.5, 0.8).normalize(), Deg(63.0))
+}
+
+#[test]
+fn default_matches_renpy() {
+    let convention = CoordinateConvention::default();
+
+    let point = convention.convert_point(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(point, Vector3::new(1.0, -2.0, 3.0));
+
+    let quat = Quaternion::new(0.5609855, 0.4304593, 0.092296, 0.7010574);
+    assert_eq!(
+        convention.convert_quaternion(quat),
+        Quaternion::new(quat.s, -quat.v.x, quat.v.y, -quat.v.z)
+    );
+
+    // Used to be the hard-coded skeleton conversion
+    let flip_y = Matrix4::from_nonuniform_scale(1.0, -1.0, 1.0);
+    let matrix = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from(quat);
+    assert_eq!(convention.convert_matrix(matrix), flip_y * matrix * flip_y);
+
+    assert_eq!(
+        convention.quaternion_to_euler(quat),
+        quaterions_to_zyx_euler(quat)
+    );
+}
+
+#[test]
+fn handedness_is_respected() {
+    for convention in conventions() {
+        let expected = match convention.handedness {
+            Handedness::Right => 1.0,
+            Handedness::Left => -1.0,
+        };
+        assert_eq!(convention.determinant(), expected, "{convention:?}");
+
+        // Up really is up
+        let up = convention.convert_direction(Vector3::unit_y());
+        let axis = match convention.up {
+            Axis::X => Vector3::unit_x(),
+            Axis::NegX => -Vector3::unit_x(),
+            Axis::Y => Vector3::unit_y(),
+            Axis::NegY => -Vector3::unit_y(),
+            Axis::Z => Vector3::unit_z(),
+            Axis::NegZ => -Vector3::unit_z(),
+        };
+        assert_eq!(up, axis, "{convention:?}");
+    }
+}
+
+#[test]
+fn round_trips_are_exact() {
+    let point = Vector3::new(0.123, -4.56, 78.9);
+    let quat = test_quaternion();
+    let matrix = Matrix4::from_translation(point)
+        * Matrix4::from(quat)
+        * Matrix4::from_nonuniform_scale(1.5, 2.0, 0.25);
+
+    for convention in conventions() {
+        assert_eq!(
+            convention.point_to_gltf(convention.convert_point(point)),
+            point,
+            "{convention:?}"
+        );
+        assert_eq!(
+            convention.direction_to_gltf(convention.convert_direction(point)),
+            point,
+            "{convention:?}"
+        );
+        assert_eq!(
+            convention.quaternion_to_gltf(convention.convert_quaternion(quat)),
+            quat,
+            "{convention:?}"
+        );
+        assert_eq!(
+            convention.matrix_to_gltf(convention.convert_matrix(matrix)),
+            matrix,
+            "{convention:?}"
+        );
+    }
+}
+
+#[test]
+fn euler_round_trips() {
+    let quat = test_quaternion();
+
+    for euler_order in ORDERS {
+        let convention = CoordinateConvention {
+            euler_order,
+            ..Default::default()
+        };
+
+        let euler = convention.quaternion_to_euler(quat);
+        let back = convention.euler_to_quaternion(euler);
+
+        // q and -q are the same rotation
+        assert!(
+            back.abs_diff_eq(&quat, 1e-5) || back.abs_diff_eq(&-quat, 1e-5),
+            "{euler_order:?}: {back:?} != {quat:?}"
+        );
+    }
+
+    // Gimbal lock still gives back the same rotation
+    let locked = Quaternion::from(Euler::new(Deg(20.0), Deg(90.0), Deg(0.0)));
+    for euler_order in ORDERS {
+        let convention = CoordinateConvention {
+            euler_order,
+            ..Default::default()
+        };
+        let back = convention.euler_to_quaternion(convention.quaternion_to_euler(locked));
+        let (a, b) = (Matrix4::from(back), Matrix4::from(locked));
+        assert!(a.abs_diff_eq(&b, 1e-3), "{euler_order:?}");
+    }
+}
+
+#[test]
+fn transforms_match_matrices() {
+    let transform = DecomposedTransform {
+        translation: Vector3::new(1.0, -2.0, 3.5),
+        rotation: RotationTransform::Quaternion(test_quaternion()),
+        scale: Vector3::new(1.5, 2.0, 0.25),
+    };
+    let matrix: Matrix4<f32> = transform.clone().into();
+
+    for convention in conventions() {
+        let converted: Matrix4<f32> = transform
+            .clone()
+            .to_converted_coords(&convention, true)
+            .into();
+
+        assert!(
+            converted.abs_diff_eq(&convention.convert_matrix(matrix), 1e-3),
+            "{convention:?}"
+        );
+
+        // Euler output is the same rotation
+        let euler = transform
+            .clone()
+            .to_converted_coords(&convention, false)
+            .rotation
+            .unwrap_euler();
+        let quat = convention.euler_to_quaternion(euler);
+        let expected = convention.convert_quaternion(test_quaternion());
+        assert!(
+            quat.abs_diff_eq(&expected, 1e-5) || quat.abs_diff_eq(&-expected, 1e-5),
+            "{convention:?}"
+        );
+    }
+}
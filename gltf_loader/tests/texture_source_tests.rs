@@ -0,0 +1,149 @@
+use gltf_loader::SceneObject;
+use image::Rgba;
+use std::sync::Arc;
+
+const PATH: &str = "tests/TextureSources/TextureSources.gltf";
+
+// Base color of the top left texel of every mesh, None when it has no texture
+fn base_colors() -> Vec<(String, Option<Rgba<u8>>)> {
+    let scenes =
+        gltf_loader::load(PATH).expect("Textures that can't be decoded shouldn't fail the file");
+
+    scenes[0]
+        .objects
+        .values()
+        .filter_map(|object| match object {
+            SceneObject::Mesh(model) => Some(model),
+            _ => None,
+        })
+        .map(|model| {
+            let material = model.material();
+            (
+                material.name.clone().unwrap_or_default(),
+                material
+                    .pbr
+                    .base_color_texture
+                    .as_ref()
+                    .map(|texture| *texture.get_pixel(0, 0)),
+            )
+        })
+        .collect()
+}
+
+fn base_color(name: &str) -> Option<Rgba<u8>> {
+    base_colors()
+        .into_iter()
+        .find(|(material, _)| material == name)
+        .unwrap_or_else(|| panic!("No material named {name}"))
+        .1
+}
+
+// Every texel of the base color of a mesh
+fn base_color_texels(name: &str) -> Vec<Rgba<u8>> {
+    let scenes = gltf_loader::load(PATH).unwrap();
+    scenes[0]
+        .objects
+        .values()
+        .find_map(|object| match object {
+            SceneObject::Mesh(model) if model.material().name.as_deref() == Some(name) => {
+                model.material().pbr.base_color_texture.clone()
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("No base color texture for {name}"))
+        .pixels()
+        .copied()
+        .collect()
+}
+
+#[test]
+fn webp_source_is_preferred() {
+    assert_eq!(base_color("Webp"), Some(Rgba([255, 0, 0, 255])));
+}
+
+#[test]
+fn ktx2_source_is_decoded() {
+    assert_eq!(base_color("Ktx2"), Some(Rgba([0, 255, 0, 255])));
+
+    // BGR texels with zlib supercompression and no fallback source
+    assert_eq!(base_color("Zlib"), Some(Rgba([0, 255, 255, 255])));
+
+    // R8 texels with zstd supercompression, the search texture of SMAA as shipped by bevy
+    assert_eq!(base_color("Zstd"), Some(Rgba([254, 254, 254, 255])));
+}
+
+#[test]
+fn basis_universal_data_is_transcoded() {
+    // Encoded by basisu 1.16 from an 8x4 image, orange on the left and half transparent blue on the right.
+    // The expected texels are the ones its own KTX2 transcoder gives.
+    let halves = |left: [u8; 4], right: [u8; 4]| -> Vec<Rgba<u8>> {
+        (0..32)
+            .map(|index| Rgba(if index % 8 < 4 { left } else { right }))
+            .collect()
+    };
+
+    assert_eq!(
+        base_color_texels("Etc1s"),
+        halves([255, 128, 5, 255], [0, 64, 253, 128])
+    );
+
+    // UASTC with zstd supercompression
+    assert_eq!(
+        base_color_texels("Uastc"),
+        halves([255, 128, 0, 255], [0, 64, 255, 128])
+    );
+}
+
+#[test]
+fn falls_back_to_the_standard_source() {
+    // The Basis Universal payload has no data to transcode so the png is used
+    assert_eq!(base_color("Basis"), Some(Rgba([0, 0, 255, 255])));
+}
+
+#[test]
+fn broken_image_is_skipped() {
+    assert_eq!(base_color("Broken"), None);
+}
+
+#[test]
+fn shared_images_are_decoded_once() {
+    let scenes = gltf_loader::load(PATH).unwrap();
+    let materials: Vec<_> = scenes[0]
+        .objects
+        .values()
+        .filter_map(|object| match object {
+            SceneObject::Mesh(model) => Some(model.material()),
+            _ => None,
+        })
+        .collect();
+    let material = |name: &str| {
+        materials
+            .iter()
+            .find(|material| material.name.as_deref() == Some(name))
+            .unwrap()
+    };
+
+    // Two textures with the same WebP image
+    let webp = material("Webp").pbr.base_color_texture.clone().unwrap();
+    let copy = material("WebpCopy");
+    assert!(Arc::ptr_eq(
+        &webp,
+        copy.pbr.base_color_texture.as_ref().unwrap()
+    ));
+
+    // The channels come out of the same image too
+    let metallic = copy.pbr.metallic_texture.as_ref().unwrap();
+    let roughness = copy.pbr.roughness_texture.as_ref().unwrap();
+    assert_eq!((metallic.width(), metallic.height()), (2, 2));
+    assert_eq!(metallic.get_pixel(0, 0).0, [0]);
+    assert_eq!(roughness.get_pixel(0, 0).0, [0]);
+
+    // Names come from the standard source
+    assert_eq!(
+        copy.pbr
+            .base_color_texture_name
+            .as_deref()
+            .map(String::as_str),
+        Some("blue")
+    );
+}